@@ -23,20 +23,30 @@ mod mock;
 use frame_benchmarking::benchmarks;
 use frame_support::{
 	pallet_prelude::{DispatchError, DispatchResult},
-	traits::{Currency, Get},
+	traits::{Currency, Get, ReservableCurrency},
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
-use pallet_ajuna_awesome_avatars::{types::*, Config as AvatarsConfig, Pallet as AAvatars, *};
+use pallet_ajuna_awesome_avatars::{
+	migration::stepped::{MigrationProgress, SteppedMigration},
+	types::*,
+	Config as AvatarsConfig, Pallet as AAvatars, *,
+};
+use pallet_ajuna_awesome_avatars_dna::{
+	v2::{write_attribute, AvatarAttr, DNA_LENGTH},
+	ByteConvertible, EquippableItemType, EssenceItemType, ItemType, MaterialItemType, PetItemType,
+};
 use pallet_ajuna_nft_transfer::traits::NftHandler;
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+	traits::{Hash, Saturating, StaticLookup, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
 	BoundedVec,
 };
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 pub struct Pallet<T: Config>(pallet_ajuna_awesome_avatars::Pallet<T>);
 pub trait Config: AvatarsConfig + pallet_nfts::Config + pallet_balances::Config {}
 
+const MAX_SACRIFICES: u32 = 4;
+
 type AccountIdFor<T> = <T as frame_system::Config>::AccountId;
 type AvatarIdOf<T> = <T as frame_system::Config>::Hash;
 type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdFor<T>>>::Balance;
@@ -106,17 +116,32 @@ fn create_seasons<T: Config>(n: usize) -> Result<(), &'static str> {
 				per_period: BlockNumberFor::<T>::from(10_u32),
 				periods: 12,
 				trade_filters: BoundedVec::default(),
+				dissolve_souls: vec![
+					(RarityTier::Common, 1),
+					(RarityTier::Uncommon, 2),
+					(RarityTier::Rare, 4),
+					(RarityTier::Epic, 8),
+					(RarityTier::Legendary, 16),
+					(RarityTier::Mythical, 32),
+				]
+				.try_into()
+				.unwrap(),
+				forge_cooldown: Zero::zero(),
+				mint_limits: Default::default(),
 				fee: Fee {
 					mint: MintFees {
 						one: 550_000_000_000_u64.unique_saturated_into(), // 0.55 BAJU
 						three: 500_000_000_000_u64.unique_saturated_into(), // 0.5 BAJU
 						six: 450_000_000_000_u64.unique_saturated_into(), // 0.45 BAJU
 					},
+					mint_assets: BoundedVec::default(),
 					transfer_avatar: 1_000_000_000_000_u64.unique_saturated_into(), // 1 BAJU
 					buy_minimum: 1_000_000_000_u64.unique_saturated_into(),
 					buy_percent: 1,
 					upgrade_storage: 1_000_000_000_000_u64.unique_saturated_into(), // 1 BAJU
 					prepare_avatar: 5_000_000_000_000_u64.unique_saturated_into(),  // 5 BAJU
+					avatar_deposit: 1_000_000_000_u64.unique_saturated_into(),
+					forge: BoundedVec::default(),
 				},
 				mint_logic: LogicGeneration::First,
 				forge_logic: LogicGeneration::First,
@@ -137,6 +162,10 @@ fn create_avatars<T: Config>(name: &'static str, n: u32) -> Result<(), &'static
 		config.free_mints = n as MintCount;
 	});
 
+	let season = Seasons::<T>::get(CurrentSeasonStatus::<T>::get().season_id).unwrap();
+	let deposits = season.fee.avatar_deposit.saturating_mul(MaxAvatarsPerPlayer::get().into());
+	CurrencyOf::<T>::make_free_balance_be(&player, deposits + CurrencyOf::<T>::minimum_balance());
+
 	GlobalConfigs::<T>::mutate(|config| {
 		config.mint.open = true;
 		config.mint.cooldown = Zero::zero();
@@ -165,6 +194,93 @@ fn create_avatars<T: Config>(name: &'static str, n: u32) -> Result<(), &'static
 	Ok(())
 }
 
+fn dna_v2(
+	item_type: ItemType,
+	item_sub_type: u8,
+	class_types: (u8, u8),
+	rarity: RarityTier,
+	quantity: u8,
+) -> [u8; DNA_LENGTH] {
+	let mut dna = [0; DNA_LENGTH];
+	write_attribute(&mut dna, AvatarAttr::ItemType, item_type.as_byte());
+	write_attribute(&mut dna, AvatarAttr::ItemSubType, item_sub_type);
+	write_attribute(&mut dna, AvatarAttr::ClassType1, class_types.0);
+	write_attribute(&mut dna, AvatarAttr::ClassType2, class_types.1);
+	write_attribute(&mut dna, AvatarAttr::RarityTier, rarity as u8);
+	write_attribute(&mut dna, AvatarAttr::Quantity, quantity);
+	dna
+}
+
+fn create_forge_avatars<T: Config>(
+	name: &'static str,
+	forge_type: ForgeType,
+	leader: [u8; DNA_LENGTH],
+	sacrifices: Vec<[u8; DNA_LENGTH]>,
+) -> Result<(T::AccountId, AvatarIdOf<T>, Vec<AvatarIdOf<T>>), &'static str> {
+	create_avatars::<T>(name, sacrifices.len() as u32 + 1)?;
+
+	let player = account::<T>(name);
+	let season_id = CurrentSeasonStatus::<T>::get().season_id;
+	Seasons::<T>::mutate(season_id, |maybe_season| {
+		if let Some(season) = maybe_season {
+			season.forge_logic = LogicGeneration::Second;
+		}
+	});
+
+	// Forges are charged a fee and progress quests, on top of releasing the deposits of sacrifices.
+	let fee = BalanceOf::<T>::unique_saturated_from(1_000_000_000_u128);
+	Seasons::<T>::mutate(season_id, |maybe_season| {
+		if let Some(season) = maybe_season {
+			season.fee.forge = vec![(forge_type, ForgeFee::Currency(fee))].try_into().unwrap();
+		}
+	});
+	let _ = CurrencyOf::<T>::deposit_creating(&player, fee);
+	create_quests::<T>(QuestCondition::Forge { item_type: None, count: Stat::MAX });
+
+	let avatar_ids = Owners::<T>::get(&player, season_id);
+	for (avatar_id, dna) in avatar_ids.iter().zip(sp_std::iter::once(leader).chain(sacrifices)) {
+		Avatars::<T>::mutate(avatar_id, |maybe_avatar| {
+			if let Some((_owner, avatar)) = maybe_avatar {
+				avatar.encoding = DnaEncoding::V2;
+				avatar.dna = dna.to_vec().try_into().unwrap();
+			}
+		});
+	}
+
+	let leader_id = avatar_ids[0];
+	let sacrifice_ids = avatar_ids[1..].to_vec();
+	let (_, leader) = Avatars::<T>::get(leader_id).unwrap();
+	let sacrifices = sacrifice_ids
+		.iter()
+		.map(|avatar_id| Avatars::<T>::get(avatar_id).unwrap().1)
+		.collect::<Vec<_>>();
+	assert_eq!(AAvatars::<T>::forge_type_of(&leader, &sacrifices), forge_type);
+
+	Ok((player, leader_id, sacrifice_ids))
+}
+
+/// Hand the leader over to another owner, renting it back to the player so that the forge goes
+/// through the rental. Only forge types that never consume their leader can forge rented avatars.
+fn rent_forge_leader<T: Config>(player: &T::AccountId, leader_id: AvatarIdOf<T>) {
+	let owner = account::<T>("owner");
+	let season_id = CurrentSeasonStatus::<T>::get().season_id;
+	Avatars::<T>::mutate(leader_id, |maybe_avatar| {
+		if let Some((avatar_owner, _)) = maybe_avatar {
+			*avatar_owner = owner.clone();
+		}
+	});
+	Owners::<T>::mutate(player, season_id, |avatar_ids| avatar_ids.retain(|id| id != &leader_id));
+	Owners::<T>::insert(&owner, season_id, BoundedVec::truncate_from(vec![leader_id]));
+	Rentals::<T>::insert(
+		leader_id,
+		RentalAgreement {
+			renter: player.clone(),
+			expires_at: BlockNumberFor::<T>::from(u32::MAX),
+			forges_left: Some(Stat::MAX),
+		},
+	);
+}
+
 fn create_collection<T: Config>(organizer: T::AccountId) -> DispatchResult {
 	let collection_deposit = <T as pallet_nfts::Config>::CollectionDeposit::get();
 	<T as pallet_nfts::Config>::Currency::make_free_balance_be(
@@ -203,6 +319,29 @@ fn create_service_account_and_prepare_avatar<T: Config>(
 	Ok(service_account)
 }
 
+fn create_quests<T: Config>(condition: QuestCondition) {
+	let season_id = CurrentSeasonStatus::<T>::get().season_id;
+	for quest_id in 0..MAX_QUESTS {
+		let quest = Quest { condition: condition.clone(), reward: QuestReward::FreeMints(1) };
+		Quests::<T>::insert(season_id, quest_id, quest);
+	}
+}
+
+fn create_mint_limits<T: Config>() {
+	let season_id = CurrentSeasonStatus::<T>::get().season_id;
+	Seasons::<T>::mutate(season_id, |season| {
+		if let Some(season) = season {
+			season.mint_limits = MintLimits {
+				period: BlockNumberFor::<T>::from(10_u32),
+				max_per_period: Stat::MAX,
+				max_per_account: MintCount::MAX,
+				early_requires_identity: false,
+			};
+		}
+	});
+	GlobalConfigs::<T>::mutate(|config| config.mint.max_per_block = MintCount::MAX);
+}
+
 fn assert_last_event<T: Config>(avatars_event: Event<T>) {
 	let event = <T as AvatarsConfig>::RuntimeEvent::from(avatars_event);
 	frame_system::Pallet::<T>::assert_last_event(event.into());
@@ -216,6 +355,11 @@ benchmarks! {
 
 		let caller = account::<T>(name);
 		PlayerConfigs::<T>::mutate(&caller, |account| account.free_mints = MintCount::MAX);
+		create_mint_limits::<T>();
+		create_quests::<T>(QuestCondition::MintPacks {
+			pack_type: PackType::Material,
+			count: Stat::MAX,
+		});
 
 		let mint_option = MintOption { payment: MintPayment::Free, pack_size: MintPackSize::Six,
 			pack_type: PackType::Material, };
@@ -234,8 +378,17 @@ benchmarks! {
 
 		let caller = account::<T>(name);
 		let season = Seasons::<T>::get(CurrentSeasonStatus::<T>::get().season_id).unwrap();
+		let deposit = season.fee.avatar_deposit.saturating_mul(6_u32.into());
 		let mint_fee = season.fee.mint.fee_for(&MintPackSize::Six);
-		CurrencyOf::<T>::make_free_balance_be(&caller, mint_fee);
+		CurrencyOf::<T>::make_free_balance_be(
+			&caller,
+			mint_fee + deposit + CurrencyOf::<T>::minimum_balance(),
+		);
+		create_mint_limits::<T>();
+		create_quests::<T>(QuestCondition::MintPacks {
+			pack_type: PackType::Material,
+			count: Stat::MAX,
+		});
 
 		let mint_option = MintOption { payment: MintPayment::Normal, pack_size: MintPackSize::Six,
 			pack_type: PackType::Material };
@@ -257,6 +410,7 @@ benchmarks! {
 		let avatar_ids = Owners::<T>::get(&player, season_id);
 		let avatar_id = avatar_ids[0];
 		let (_owner, original_avatar) = Avatars::<T>::get(avatar_id).unwrap();
+		create_quests::<T>(QuestCondition::Forge { item_type: None, count: Stat::MAX });
 	}: _(RawOrigin::Signed(player), avatar_id, avatar_ids[1..5].to_vec())
 	verify {
 		let (_owner, upgraded_avatar) = Avatars::<T>::get(avatar_id).unwrap();
@@ -273,6 +427,246 @@ benchmarks! {
 		assert_last_event::<T>(Event::AvatarsForged { avatar_ids: vec![(avatar_id, upgraded_components)] })
 	}
 
+	forge_none {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Essence,
+			EssenceItemType::PaintFlask.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			1,
+		);
+		let sacrifice = dna_v2(
+			ItemType::Material,
+			MaterialItemType::Polymers.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			4,
+		);
+		let sacrifices = vec![sacrifice; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::None, leader, sacrifices)?;
+		rent_forge_leader::<T>(&player, leader_id);
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_stack {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Material,
+			MaterialItemType::Polymers.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			4,
+		);
+		let sacrifices = vec![leader; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Stack, leader, sacrifices)?;
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_tinker {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Pet,
+			PetItemType::PetPart.as_byte(),
+			(1, 1),
+			RarityTier::Common,
+			1,
+		);
+		let sacrifice = dna_v2(
+			ItemType::Material,
+			MaterialItemType::Polymers.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			4,
+		);
+		let sacrifices = vec![sacrifice; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Tinker, leader, sacrifices)?;
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_build {
+		let leader = dna_v2(ItemType::Blueprint, 1, (0, 0), RarityTier::Common, 1);
+		let sacrifice = dna_v2(
+			ItemType::Material,
+			MaterialItemType::Polymers.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			4,
+		);
+		let sacrifices = vec![sacrifice; 4];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Build, leader, sacrifices)?;
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_assemble {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Equippable,
+			EquippableItemType::ArmorBase.as_byte(),
+			(1, 1),
+			RarityTier::Common,
+			1,
+		);
+		let sacrifice = dna_v2(
+			ItemType::Equippable,
+			EquippableItemType::ArmorComponent1.as_byte(),
+			(1, 1),
+			RarityTier::Common,
+			1,
+		);
+		let sacrifices = vec![sacrifice; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Assemble, leader, sacrifices)?;
+		rent_forge_leader::<T>(&player, leader_id);
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_breed {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(ItemType::Pet, PetItemType::Egg.as_byte(), (0, 0), RarityTier::Rare, 1);
+		let sacrifices = vec![leader; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Breed, leader, sacrifices)?;
+		rent_forge_leader::<T>(&player, leader_id);
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_equip {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Pet,
+			PetItemType::Pet.as_byte(),
+			(0, 1),
+			RarityTier::Legendary,
+			1,
+		);
+		let sacrifice = dna_v2(
+			ItemType::Equippable,
+			EquippableItemType::ArmorBase.as_byte(),
+			(0, 1),
+			RarityTier::Legendary,
+			1,
+		);
+		let sacrifices = vec![sacrifice; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Equip, leader, sacrifices)?;
+		rent_forge_leader::<T>(&player, leader_id);
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_mate {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Pet,
+			PetItemType::Pet.as_byte(),
+			(0, 0),
+			RarityTier::Legendary,
+			1,
+		);
+		let sacrifices = vec![leader; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Mate, leader, sacrifices)?;
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_feed {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Pet,
+			PetItemType::Pet.as_byte(),
+			(0, 0),
+			RarityTier::Legendary,
+			1,
+		);
+		let sacrifice = dna_v2(
+			ItemType::Pet,
+			PetItemType::Egg.as_byte(),
+			(0, 0),
+			RarityTier::Rare,
+			1,
+		);
+		let sacrifices = vec![sacrifice; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Feed, leader, sacrifices)?;
+		rent_forge_leader::<T>(&player, leader_id);
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_glimmer {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Essence,
+			EssenceItemType::Glimmer.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			4,
+		);
+		let sacrifice = dna_v2(
+			ItemType::Material,
+			MaterialItemType::Polymers.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			4,
+		);
+		let sacrifices = vec![sacrifice; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Glimmer, leader, sacrifices)?;
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_spark {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Essence,
+			EssenceItemType::ColorSpark.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			1,
+		);
+		let sacrifices = vec![leader; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Spark, leader, sacrifices)?;
+		rent_forge_leader::<T>(&player, leader_id);
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_statue {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Pet,
+			PetItemType::PetPart.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			1,
+		);
+		let sacrifices = vec![leader; n as usize];
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Statue, leader, sacrifices)?;
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
+	forge_flask {
+		let n in 1 .. MAX_SACRIFICES;
+		let leader = dna_v2(
+			ItemType::Equippable,
+			EquippableItemType::ArmorBase.as_byte(),
+			(0, 0),
+			RarityTier::Epic,
+			1,
+		);
+		let paint_flask = dna_v2(
+			ItemType::Essence,
+			EssenceItemType::PaintFlask.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			1,
+		);
+		let glimmer = dna_v2(
+			ItemType::Essence,
+			EssenceItemType::Glimmer.as_byte(),
+			(0, 0),
+			RarityTier::Common,
+			1,
+		);
+		let mut sacrifices = vec![paint_flask];
+		sacrifices.resize(n as usize, glimmer);
+		let (player, leader_id, sacrifice_ids) =
+			create_forge_avatars::<T>("player", ForgeType::Flask, leader, sacrifices)?;
+		rent_forge_leader::<T>(&player, leader_id);
+	}: forge(RawOrigin::Signed(player), leader_id, sacrifice_ids)
+
 	transfer_avatar_normal {
 		let from = account::<T>("from");
 		let to = account::<T>("to");
@@ -362,6 +756,7 @@ benchmarks! {
 		let season_id = CurrentSeasonStatus::<T>::get().season_id;
 		let avatar_id = Owners::<T>::get(&seller, season_id)[0];
 		Trade::<T>::insert(season_id, avatar_id, sell_fee);
+		create_quests::<T>(QuestCondition::Buy { count: Stat::MAX });
 	}: _(RawOrigin::Signed(buyer.clone()), avatar_id)
 	verify {
 		assert_last_event::<T>(Event::AvatarTraded { avatar_id, from: seller, to: buyer })
@@ -450,17 +845,58 @@ benchmarks! {
 			per_period: BlockNumberFor::<T>::from(1_u32),
 			periods: u16::MAX,
 			trade_filters: BoundedVec::default(),
+			dissolve_souls: vec![
+				(RarityTier::Common, SoulCount::MAX),
+				(RarityTier::Uncommon, SoulCount::MAX),
+				(RarityTier::Rare, SoulCount::MAX),
+				(RarityTier::Epic, SoulCount::MAX),
+				(RarityTier::Legendary, SoulCount::MAX),
+				(RarityTier::Mythical, SoulCount::MAX),
+			]
+			.try_into()
+			.unwrap(),
+			forge_cooldown: BlockNumberFor::<T>::from(u32::MAX),
+			mint_limits: MintLimits {
+				period: BlockNumberFor::<T>::from(u32::MAX),
+				max_per_period: Stat::MAX,
+				max_per_account: MintCount::MAX,
+				early_requires_identity: true,
+			},
 			fee: Fee {
 				mint: MintFees {
 					one: BalanceOf::<T>::unique_saturated_from(u128::MAX),
 					three: BalanceOf::<T>::unique_saturated_from(u128::MAX),
 					six: BalanceOf::<T>::unique_saturated_from(u128::MAX),
 				},
+				mint_assets: BoundedVec::default(),
 				transfer_avatar: BalanceOf::<T>::unique_saturated_from(u128::MAX),
 				buy_minimum: BalanceOf::<T>::unique_saturated_from(u128::MAX),
 				buy_percent: u8::MAX,
 				upgrade_storage: BalanceOf::<T>::unique_saturated_from(u128::MAX),
 				prepare_avatar: BalanceOf::<T>::unique_saturated_from(u128::MAX),
+				avatar_deposit: BalanceOf::<T>::unique_saturated_from(u128::MAX),
+				forge: [
+					ForgeType::None,
+					ForgeType::Stack,
+					ForgeType::Tinker,
+					ForgeType::Build,
+					ForgeType::Assemble,
+					ForgeType::Breed,
+					ForgeType::Equip,
+					ForgeType::Mate,
+					ForgeType::Feed,
+					ForgeType::Glimmer,
+					ForgeType::Spark,
+					ForgeType::Statue,
+					ForgeType::Flask,
+				]
+				.into_iter()
+				.map(|forge_type| {
+					(forge_type, ForgeFee::Currency(BalanceOf::<T>::unique_saturated_from(u128::MAX)))
+				})
+				.collect::<vec::Vec<_>>()
+				.try_into()
+				.unwrap(),
 			},
 			mint_logic: LogicGeneration::First,
 			forge_logic: LogicGeneration::First,
@@ -479,6 +915,7 @@ benchmarks! {
 				open: true,
 				cooldown: BlockNumberFor::<T>::from(u32::MAX),
 				free_mint_fee_multiplier: MintCount::MAX,
+				max_per_block: MintCount::MAX,
 			},
 			forge: ForgeConfig { open: true },
 			transfer: TransferConfig {
@@ -605,6 +1042,255 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::PreparedIpfsUrl { url })
 	}
 
+	burn_avatar {
+		let name = "player";
+		let n in 1 .. MaxAvatarsPerPlayer::get();
+		create_avatars::<T>(name, n)?;
+
+		let player = account::<T>(name);
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let avatar_id = Owners::<T>::get(&player, season_id)[n as usize - 1];
+	}: _(RawOrigin::Signed(player), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::AvatarBurned { avatar_id })
+	}
+
+	set_quest {
+		create_seasons::<T>(1)?;
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let quest_id = MAX_QUESTS - 1;
+		let quest = Some(Quest {
+			condition: QuestCondition::ReachRarity {
+				item_type: Some(ItemType::Pet),
+				rarity: RarityTier::Mythical,
+			},
+			reward: QuestReward::Tokens(BalanceOf::<T>::unique_saturated_from(u128::MAX)),
+		});
+	}: _(RawOrigin::Signed(organizer), season_id, quest_id, quest.clone())
+	verify {
+		assert_last_event::<T>(Event::QuestSet { season_id, quest_id, quest })
+	}
+
+	claim_quest_reward {
+		create_seasons::<T>(1)?;
+		let player = account::<T>("player");
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let quest_id = MAX_QUESTS - 1;
+
+		let amount = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000_u64);
+		let reward = QuestReward::Tokens(amount);
+		let condition = QuestCondition::Buy { count: 1 };
+		Quests::<T>::insert(season_id, quest_id, Quest { condition, reward: reward.clone() });
+		PlayerQuests::<T>::insert((&player, season_id, quest_id), QuestProgress {
+			progress: 1,
+			claimed: false,
+		});
+
+		Treasury::<T>::insert(season_id, amount);
+		CurrencyOf::<T>::make_free_balance_be(
+			&AAvatars::<T>::treasury_account_id(),
+			amount + CurrencyOf::<T>::minimum_balance(),
+		);
+	}: _(RawOrigin::Signed(player.clone()), season_id, quest_id)
+	verify {
+		assert_last_event::<T>(Event::QuestRewardClaimed { player, season_id, quest_id, reward })
+	}
+
+	retire_avatars {
+		let name = "player";
+		let n in 1 .. MaxAvatarsPerPlayer::get();
+		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+
+		let player = account::<T>(name);
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let avatar_ids: BoundedVec<AvatarIdOf<T>, MaxAvatarsPerPlayer> =
+			Owners::<T>::get(&player, season_id)[..n as usize].to_vec().try_into().unwrap();
+		CurrentSeasonStatus::<T>::mutate(|status| status.season_id = season_id + 1);
+	}: _(RawOrigin::Signed(player.clone()), avatar_ids.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::AvatarsRetired {
+			player,
+			avatar_ids: avatar_ids.into_inner(),
+		})
+	}
+
+	dissolve {
+		let name = "player";
+		let n in 1 .. MaxAvatarsPerPlayer::get();
+		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+
+		let player = account::<T>(name);
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let avatar_ids: BoundedVec<AvatarIdOf<T>, MaxAvatarsPerPlayer> =
+			Owners::<T>::get(&player, season_id)[..n as usize].to_vec().try_into().unwrap();
+		let target = DissolveTarget::Balance;
+	}: _(RawOrigin::Signed(player), avatar_ids.clone(), target.clone())
+	verify {
+		let souls = SoulPoints::<T>::get(&account::<T>(name), season_id);
+		assert_last_event::<T>(Event::<T>::AvatarsDissolved {
+			avatar_ids: avatar_ids.into_inner(),
+			target,
+			souls,
+		})
+	}
+
+	offer_rental {
+		let name = "player";
+		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+		let owner = account::<T>(name);
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let avatar_id = Owners::<T>::get(&owner, season_id)[0];
+		let terms = RentalTerms {
+			price: BalanceOf::<T>::unique_saturated_from(u128::MAX),
+			duration: BlockNumberFor::<T>::from(u32::MAX),
+			max_forges: Some(Stat::MAX),
+		};
+	}: _(RawOrigin::Signed(owner), avatar_id, terms.clone())
+	verify {
+		assert_last_event::<T>(Event::RentalOffered { avatar_id, terms })
+	}
+
+	cancel_rental_offer {
+		let name = "player";
+		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+		let owner = account::<T>(name);
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let avatar_id = Owners::<T>::get(&owner, season_id)[0];
+		RentalOffers::<T>::insert(
+			avatar_id,
+			RentalTerms {
+				price: BalanceOf::<T>::unique_saturated_from(u128::MAX),
+				duration: BlockNumberFor::<T>::from(u32::MAX),
+				max_forges: None,
+			},
+		);
+	}: _(RawOrigin::Signed(owner), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::RentalOfferCancelled { avatar_id })
+	}
+
+	rent_avatar {
+		let name = "player";
+		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+		let owner = account::<T>(name);
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let avatar_id = Owners::<T>::get(&owner, season_id)[0];
+
+		let renter = account::<T>("renter");
+		let price = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000_u128);
+		CurrencyOf::<T>::make_free_balance_be(
+			&renter,
+			price + CurrencyOf::<T>::minimum_balance(),
+		);
+		let duration = BlockNumberFor::<T>::from(u32::MAX);
+		RentalOffers::<T>::insert(avatar_id, RentalTerms { price, duration, max_forges: None });
+	}: _(RawOrigin::Signed(renter.clone()), avatar_id)
+	verify {
+		let expires_at = <frame_system::Pallet<T>>::block_number() + duration;
+		assert_last_event::<T>(Event::AvatarRented { avatar_id, owner, renter, expires_at })
+	}
+
+	end_rental {
+		let name = "player";
+		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+		let owner = account::<T>(name);
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let avatar_id = Owners::<T>::get(&owner, season_id)[0];
+		let renter = account::<T>("renter");
+		Rentals::<T>::insert(
+			avatar_id,
+			RentalAgreement { renter, expires_at: Zero::zero(), forges_left: None },
+		);
+	}: _(RawOrigin::Signed(owner), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::RentalEnded { avatar_id })
+	}
+
+	set_sponsorship {
+		create_seasons::<T>(1)?;
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let budget = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+		let max_per_account = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+	}: _(RawOrigin::Signed(organizer.clone()), season_id, budget, max_per_account)
+	verify {
+		let sponsorship = Some(Sponsorship { sponsor: organizer, budget, max_per_account });
+		assert_last_event::<T>(Event::SponsorshipSet { season_id, sponsorship })
+	}
+
+	archive_season {
+		let n in 0 .. 100;
+
+		create_seasons::<T>(3)?;
+		let season_id = CurrentSeasonStatus::<T>::get().season_id;
+		let deposit = Seasons::<T>::get(season_id).unwrap().fee.avatar_deposit;
+
+		// Refunding the last avatar of a player, along with pruning its config, is the heaviest
+		// step of an archival, so every step of the benchmark does so for a different player.
+		for i in 0..n {
+			let player: T::AccountId = frame_benchmarking::account("player", i, 0);
+			let avatar_id = T::Hashing::hash_of(&i);
+			CurrencyOf::<T>::make_free_balance_be(
+				&player,
+				deposit + CurrencyOf::<T>::minimum_balance(),
+			);
+			CurrencyOf::<T>::reserve(&player, deposit)?;
+			AvatarDeposits::<T>::insert(avatar_id, (&player, deposit));
+			Owners::<T>::insert(&player, season_id, BoundedVec::truncate_from(vec![avatar_id]));
+			PlayerSeasonConfigs::<T>::insert(&player, season_id, PlayerSeasonConfig::default());
+			SeasonPlayers::<T>::insert(season_id, &player, ());
+		}
+		SeasonArchives::<T>::insert(
+			season_id,
+			SeasonArchive { stage: ArchiveStage::Players, summary: Default::default() },
+		);
+		CurrentSeasonStatus::<T>::mutate(|status| status.season_id += 1);
+
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+	}: _(RawOrigin::Signed(organizer), season_id, n)
+	verify {
+		assert_eq!(SeasonPlayers::<T>::iter_prefix(season_id).count(), 0);
+		assert_eq!(AvatarDeposits::<T>::iter().count(), 0);
+	}
+
+	retry_migration {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let id = T::SteppedMigration::id();
+		OngoingMigration::<T>::put(MigrationProgress {
+			id,
+			cursor: None,
+			steps: 0,
+			started_at: Zero::zero(),
+			failed: true,
+		});
+	}: _(RawOrigin::Signed(organizer))
+	verify {
+		assert_last_event::<T>(Event::MigrationRetried { id })
+	}
+
+	abort_migration {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let id = T::SteppedMigration::id();
+		OngoingMigration::<T>::put(MigrationProgress {
+			id,
+			cursor: None,
+			steps: 0,
+			started_at: Zero::zero(),
+			failed: true,
+		});
+	}: _(RawOrigin::Signed(organizer))
+	verify {
+		assert_last_event::<T>(Event::MigrationAborted { id, steps: 0 })
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
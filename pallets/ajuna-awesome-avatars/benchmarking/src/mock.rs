@@ -185,6 +185,11 @@ impl pallet_ajuna_awesome_avatars::Config for Runtime {
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
 	type NftHandler = NftTransfer;
+	type IdentityVerifier = ();
+	type AssetId = u32;
+	type Assets = ();
+	type SteppedMigration = ();
+	type ArchiveGracePeriod = ConstU64<100>;
 	type WeightInfo = ();
 }
 
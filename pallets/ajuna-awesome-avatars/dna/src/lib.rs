@@ -0,0 +1,63 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Awesome Avatars DNA
+//!
+//! The DNA layout of Awesome Avatars, shared by the pallet and off-chain consumers. It provides
+//! the item types encoded in V2 avatars, the positions of their attributes, specs and progress
+//! arrays, and decoding and encoding of whole DNA strands into serializable values.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod types;
+pub mod v1;
+pub mod v2;
+
+pub use types::*;
+
+use core::ops::Range;
+
+/// Conversion between DNA bytes and the values they represent.
+pub trait ByteConvertible: Clone {
+	fn from_byte(byte: u8) -> Self;
+	fn as_byte(&self) -> u8;
+}
+
+impl ByteConvertible for u8 {
+	fn from_byte(byte: u8) -> Self {
+		byte
+	}
+
+	fn as_byte(&self) -> u8 {
+		*self
+	}
+}
+
+/// The range of bytes a value can be represented with.
+pub trait Ranged {
+	fn range() -> Range<usize>;
+}
+
+/// Errors returned when decoding a DNA strand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DnaError {
+	/// The DNA does not have the length required by its encoding.
+	InvalidLength { expected: usize, actual: usize },
+	/// The DNA encodes an item type that does not exist.
+	UnknownItemType(u8),
+}
@@ -0,0 +1,61 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! DNA of V1 avatars: a sequence of components, each holding a rarity tier in its high nibble
+//! and a variation in its low nibble.
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// A single component of a V1 DNA.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Component {
+	pub rarity: u8,
+	pub variation: u8,
+}
+
+/// A decoded V1 DNA.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DnaV1 {
+	pub components: Vec<Component>,
+}
+
+impl DnaV1 {
+	pub fn decode(dna: &[u8]) -> Self {
+		let components = dna
+			.iter()
+			.map(|byte| Component { rarity: byte >> 4, variation: byte & 0x0F })
+			.collect();
+		Self { components }
+	}
+
+	pub fn encode(&self) -> Vec<u8> {
+		self.components
+			.iter()
+			.map(|Component { rarity, variation }| (rarity << 4) | (variation & 0x0F))
+			.collect()
+	}
+}
+
+/// The rarity of a V1 DNA, which is that of its least rare component.
+pub fn rarity(dna: &[u8]) -> u8 {
+	dna.iter().map(|x| *x >> 4).min().unwrap_or_default()
+}
+
+/// The force of a V1 DNA, derived from the variation of its last component.
+pub fn force(dna: &[u8]) -> u8 {
+	(dna.last().unwrap_or(&0) & 0b0000_1111).saturating_add(1)
+}
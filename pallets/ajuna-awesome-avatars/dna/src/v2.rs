@@ -0,0 +1,259 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! DNA of V2 avatars: a 32 bytes strand holding the avatar's attributes in its first 5 bytes,
+//! followed by 16 spec bytes and an 11 bytes progress array.
+
+use crate::{ByteConvertible, ByteType, DnaError, ItemType};
+use core::ops::Range;
+use serde::{Deserialize, Serialize};
+
+/// The length of a V2 DNA.
+pub const DNA_LENGTH: usize = 32;
+/// The positions of the spec bytes in a V2 DNA.
+pub const SPECS: Range<usize> = 5..21;
+/// The positions of the progress array in a V2 DNA.
+pub const PROGRESS: Range<usize> = 21..32;
+
+pub type Specs = [u8; 16];
+pub type ProgressArray = [u8; 11];
+
+#[derive(Copy, Clone)]
+pub enum AvatarAttr {
+	ItemType,
+	ItemSubType,
+	ClassType1,
+	ClassType2,
+	CustomType1,
+	CustomType2,
+	RarityTier,
+	Quantity,
+}
+
+impl AvatarAttr {
+	/// The byte holding the attribute, and which part of it.
+	pub fn position(&self) -> (usize, ByteType) {
+		match self {
+			AvatarAttr::ItemType => (0, ByteType::High),
+			AvatarAttr::ItemSubType => (0, ByteType::Low),
+			AvatarAttr::ClassType1 => (1, ByteType::High),
+			AvatarAttr::ClassType2 => (1, ByteType::Low),
+			AvatarAttr::CustomType1 => (2, ByteType::High),
+			AvatarAttr::CustomType2 => (4, ByteType::Full),
+			AvatarAttr::RarityTier => (2, ByteType::Low),
+			AvatarAttr::Quantity => (3, ByteType::Full),
+		}
+	}
+}
+
+#[derive(Copy, Clone)]
+pub enum SpecIdx {
+	Byte1,
+	Byte2,
+	Byte3,
+	Byte4,
+	Byte5,
+	Byte6,
+	Byte7,
+	Byte8,
+	Byte9,
+	Byte10,
+	Byte11,
+	Byte12,
+	Byte13,
+	Byte14,
+	Byte15,
+	Byte16,
+}
+
+impl SpecIdx {
+	/// The position of the spec byte in the DNA.
+	pub fn position(&self) -> usize {
+		SPECS.start + *self as usize
+	}
+}
+
+pub fn high_nibble_of(byte: u8) -> u8 {
+	byte >> 4
+}
+
+pub fn low_nibble_of(byte: u8) -> u8 {
+	byte & 0x0F
+}
+
+pub fn read_at(dna: &[u8], position: usize, byte_type: ByteType) -> u8 {
+	match byte_type {
+		ByteType::Full => dna[position],
+		ByteType::High => high_nibble_of(dna[position]),
+		ByteType::Low => low_nibble_of(dna[position]),
+	}
+}
+
+pub fn write_at(dna: &mut [u8], position: usize, byte_type: ByteType, value: u8) {
+	match byte_type {
+		ByteType::Full => dna[position] = value,
+		ByteType::High => dna[position] = (dna[position] & (ByteType::High as u8)) | (value << 4),
+		ByteType::Low =>
+			dna[position] =
+				(dna[position] & (ByteType::Low as u8)) | (value & (ByteType::High as u8)),
+	}
+}
+
+pub fn read_attribute(dna: &[u8], attribute: AvatarAttr) -> u8 {
+	let (position, byte_type) = attribute.position();
+	read_at(dna, position, byte_type)
+}
+
+pub fn write_attribute(dna: &mut [u8], attribute: AvatarAttr, value: u8) {
+	let (position, byte_type) = attribute.position();
+	write_at(dna, position, byte_type, value)
+}
+
+pub fn read_spec(dna: &[u8], index: SpecIdx) -> u8 {
+	dna[index.position()]
+}
+
+pub fn write_spec(dna: &mut [u8], index: SpecIdx, value: u8) {
+	dna[index.position()] = value;
+}
+
+pub fn read_specs(dna: &[u8]) -> Specs {
+	let mut out = Specs::default();
+	out.copy_from_slice(&dna[SPECS]);
+	out
+}
+
+pub fn write_specs(dna: &mut [u8], value: Specs) {
+	dna[SPECS].copy_from_slice(&value);
+}
+
+pub fn read_progress(dna: &[u8]) -> ProgressArray {
+	let mut out = ProgressArray::default();
+	out.copy_from_slice(&dna[PROGRESS]);
+	out
+}
+
+pub fn write_progress(dna: &mut [u8], value: ProgressArray) {
+	dna[PROGRESS].copy_from_slice(&value);
+}
+
+/// A decoded V2 DNA. Sub, class and custom types are kept as raw bytes since their meaning
+/// depends on the item type.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DnaV2 {
+	pub item_type: ItemType,
+	pub item_sub_type: u8,
+	pub class_type_1: u8,
+	pub class_type_2: u8,
+	pub custom_type_1: u8,
+	pub custom_type_2: u8,
+	pub rarity: u8,
+	pub quantity: u8,
+	pub specs: Specs,
+	pub progress: ProgressArray,
+}
+
+impl DnaV2 {
+	pub fn decode(dna: &[u8]) -> Result<Self, DnaError> {
+		if dna.len() != DNA_LENGTH {
+			return Err(DnaError::InvalidLength { expected: DNA_LENGTH, actual: dna.len() })
+		}
+		let item_type = read_attribute(dna, AvatarAttr::ItemType);
+		if ItemType::from_byte(item_type).as_byte() != item_type {
+			return Err(DnaError::UnknownItemType(item_type))
+		}
+
+		Ok(Self {
+			item_type: ItemType::from_byte(item_type),
+			item_sub_type: read_attribute(dna, AvatarAttr::ItemSubType),
+			class_type_1: read_attribute(dna, AvatarAttr::ClassType1),
+			class_type_2: read_attribute(dna, AvatarAttr::ClassType2),
+			custom_type_1: read_attribute(dna, AvatarAttr::CustomType1),
+			custom_type_2: read_attribute(dna, AvatarAttr::CustomType2),
+			rarity: read_attribute(dna, AvatarAttr::RarityTier),
+			quantity: read_attribute(dna, AvatarAttr::Quantity),
+			specs: read_specs(dna),
+			progress: read_progress(dna),
+		})
+	}
+
+	pub fn encode(&self) -> [u8; DNA_LENGTH] {
+		let mut dna = [0; DNA_LENGTH];
+		write_attribute(&mut dna, AvatarAttr::ItemType, self.item_type.as_byte());
+		write_attribute(&mut dna, AvatarAttr::ItemSubType, self.item_sub_type);
+		write_attribute(&mut dna, AvatarAttr::ClassType1, self.class_type_1);
+		write_attribute(&mut dna, AvatarAttr::ClassType2, self.class_type_2);
+		write_attribute(&mut dna, AvatarAttr::CustomType1, self.custom_type_1);
+		write_attribute(&mut dna, AvatarAttr::CustomType2, self.custom_type_2);
+		write_attribute(&mut dna, AvatarAttr::RarityTier, self.rarity);
+		write_attribute(&mut dna, AvatarAttr::Quantity, self.quantity);
+		write_specs(&mut dna, self.specs);
+		write_progress(&mut dna, self.progress);
+		dna
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const DNA: [u8; DNA_LENGTH] = [
+		0x24, 0x00, 0x12, 0x08, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A,
+		0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x21, 0x22, 0x23, 0x24, 0x25, 0x20, 0x21, 0x22, 0x23,
+		0x24, 0x25,
+	];
+
+	#[test]
+	fn decode_reads_layout() {
+		let dna = DnaV2::decode(&DNA).unwrap();
+		assert_eq!(dna.item_type, ItemType::Material);
+		assert_eq!(dna.item_sub_type, 4);
+		assert_eq!(dna.custom_type_1, 1);
+		assert_eq!(dna.rarity, 2);
+		assert_eq!(dna.quantity, 8);
+		assert_eq!(dna.specs[0], 0x01);
+		assert_eq!(dna.specs[15], 0x10);
+		assert_eq!(
+			dna.progress,
+			[0x21, 0x22, 0x23, 0x24, 0x25, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25]
+		);
+	}
+
+	#[test]
+	fn encode_roundtrips() {
+		assert_eq!(DnaV2::decode(&DNA).unwrap().encode(), DNA);
+	}
+
+	#[test]
+	fn decode_rejects_invalid_dna() {
+		assert_eq!(
+			DnaV2::decode(&DNA[..31]),
+			Err(DnaError::InvalidLength { expected: DNA_LENGTH, actual: 31 })
+		);
+
+		let mut dna = DNA;
+		write_attribute(&mut dna, AvatarAttr::ItemType, 0);
+		assert_eq!(DnaV2::decode(&dna), Err(DnaError::UnknownItemType(0)));
+	}
+
+	#[test]
+	fn serializes_to_json() {
+		let json = serde_json::to_value(DnaV2::decode(&DNA).unwrap()).unwrap();
+		assert_eq!(json["item_type"], "Material");
+		assert_eq!(json["rarity"], 2);
+		assert_eq!(DnaV2::deserialize(&json).unwrap().encode(), DNA);
+	}
+}
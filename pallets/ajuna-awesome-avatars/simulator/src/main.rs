@@ -0,0 +1,179 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Awesome Avatars Simulator
+//!
+//! Plays simulated mint and forge sessions against a season configuration, so that changes to
+//! its odds and fees can be evaluated before they are deployed. Each session starts with a fresh
+//! player and chain state, mints the requested packs and greedily forges after every pack. The
+//! randomness of each session is derived from the seed and the session index, so a run is fully
+//! reproducible.
+//!
+//! The season is read from a file containing the hex-encoded SCALE representation of a
+//! `Season`, as submitted with `set_season`. Without one, a baseline season is simulated.
+
+mod report;
+mod runtime;
+mod simulation;
+
+use clap::{Parser, ValueEnum};
+use pallet_ajuna_awesome_avatars::types::{MintPackSize, PackType};
+use parity_scale_codec::Decode;
+use report::Aggregate;
+use simulation::{SeasonConfig, Settings};
+use std::{fs, path::PathBuf, thread};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Simulate Awesome Avatars sessions to balance a season")]
+struct Cli {
+	/// File containing a hex-encoded `Season`. Defaults to a baseline season.
+	#[clap(long)]
+	season: Option<PathBuf>,
+
+	/// Number of sessions to simulate.
+	#[clap(long, default_value_t = 10_000)]
+	sessions: u64,
+
+	/// Number of packs minted in each session.
+	#[clap(long, default_value_t = 20)]
+	packs: u32,
+
+	#[clap(long, value_enum, default_value_t = PackSizeArg::Six)]
+	pack_size: PackSizeArg,
+
+	#[clap(long, value_enum, default_value_t = PackTypeArg::Material)]
+	pack_type: PackTypeArg,
+
+	/// Seed from which the randomness of every session is derived.
+	#[clap(long, default_value_t = 0)]
+	seed: u64,
+
+	/// Number of threads to simulate on. Defaults to the available parallelism.
+	#[clap(long)]
+	threads: Option<usize>,
+
+	#[clap(long, value_enum, default_value_t = Format::Json)]
+	format: Format,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+enum PackSizeArg {
+	One,
+	Three,
+	Six,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+enum PackTypeArg {
+	Material,
+	Equipment,
+	Special,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+enum Format {
+	Csv,
+	Json,
+}
+
+fn read_season(path: &PathBuf) -> Result<SeasonConfig, String> {
+	let contents = fs::read_to_string(path).map_err(|e| format!("cannot read season: {e}"))?;
+	let contents = contents.trim();
+	let bytes = hex::decode(contents.strip_prefix("0x").unwrap_or(contents))
+		.map_err(|e| format!("season is not valid hex: {e}"))?;
+	SeasonConfig::decode(&mut bytes.as_slice()).map_err(|e| format!("cannot decode season: {e}"))
+}
+
+fn main() -> Result<(), String> {
+	let cli = Cli::parse();
+
+	let season = match &cli.season {
+		Some(path) => read_season(path)?,
+		None => simulation::default_season(),
+	};
+	let settings = Settings {
+		seed: cli.seed,
+		packs: cli.packs,
+		pack_size: match cli.pack_size {
+			PackSizeArg::One => MintPackSize::One,
+			PackSizeArg::Three => MintPackSize::Three,
+			PackSizeArg::Six => MintPackSize::Six,
+		},
+		pack_type: match cli.pack_type {
+			PackTypeArg::Material => PackType::Material,
+			PackTypeArg::Equipment => PackType::Equipment,
+			PackTypeArg::Special => PackType::Special,
+		},
+	};
+	let threads = cli
+		.threads
+		.or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+		.unwrap_or(1)
+		.max(1) as u64;
+
+	let aggregate = thread::scope(|scope| {
+		let handles = (0..threads)
+			.map(|thread_index| {
+				let (season, settings) = (&season, &settings);
+				scope.spawn(move || {
+					let mut aggregate = Aggregate::default();
+					let sessions = (thread_index..cli.sessions).step_by(threads as usize);
+					for session_index in sessions {
+						aggregate.record(simulation::run_session(season, settings, session_index));
+					}
+					aggregate
+				})
+			})
+			.collect::<Vec<_>>();
+
+		handles.into_iter().fold(Aggregate::default(), |mut total, handle| {
+			total.merge(handle.join().expect("simulation thread panicked"));
+			total
+		})
+	});
+
+	let report = aggregate.into_report();
+	match cli.format {
+		Format::Csv => print!("{}", report.to_csv()),
+		Format::Json => println!("{}", report.to_json()),
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parity_scale_codec::Encode;
+
+	#[test]
+	fn read_season_decodes_hex_encoded_seasons() {
+		let season = simulation::default_season();
+		let path = std::env::temp_dir().join("aaa-simulator-season.hex");
+
+		for contents in
+			[hex::encode(season.encode()), format!("0x{}\n", hex::encode(season.encode()))]
+		{
+			fs::write(&path, contents).unwrap();
+			assert_eq!(read_season(&path), Ok(season.clone()));
+		}
+
+		fs::write(&path, "0xzz").unwrap();
+		assert!(read_season(&path).unwrap_err().starts_with("season is not valid hex"));
+		fs::write(&path, "0x00").unwrap();
+		assert!(read_season(&path).unwrap_err().starts_with("cannot decode season"));
+		fs::remove_file(&path).unwrap();
+	}
+}
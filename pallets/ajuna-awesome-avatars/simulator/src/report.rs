@@ -0,0 +1,180 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Aggregation of session outcomes into a report.
+
+use crate::{
+	runtime::Balance,
+	simulation::{SessionOutcome, RARITY_TIERS},
+};
+use pallet_ajuna_awesome_avatars::types::RarityTier;
+use serde::Serialize;
+
+/// Running totals of session outcomes, which can be merged across threads.
+#[derive(Default)]
+pub struct Aggregate {
+	sessions: u64,
+	packs: u64,
+	forges: u64,
+	minted: [u64; RARITY_TIERS],
+	held: [u64; RARITY_TIERS],
+	forges_to_max_tier: Vec<u32>,
+	souls: u64,
+	dissolve_souls: u64,
+	spent: Balance,
+}
+
+impl Aggregate {
+	pub fn record(&mut self, outcome: SessionOutcome) {
+		self.sessions += 1;
+		self.packs += outcome.packs as u64;
+		self.forges += outcome.forges as u64;
+		for tier in 0..RARITY_TIERS {
+			self.minted[tier] += outcome.minted[tier];
+			self.held[tier] += outcome.held[tier];
+		}
+		if let Some(forges) = outcome.forges_to_max_tier {
+			self.forges_to_max_tier.push(forges);
+		}
+		self.souls += outcome.souls;
+		self.dissolve_souls += outcome.dissolve_souls;
+		self.spent = self.spent.saturating_add(outcome.spent);
+	}
+
+	pub fn merge(&mut self, other: Aggregate) {
+		self.sessions += other.sessions;
+		self.packs += other.packs;
+		self.forges += other.forges;
+		for tier in 0..RARITY_TIERS {
+			self.minted[tier] += other.minted[tier];
+			self.held[tier] += other.held[tier];
+		}
+		self.forges_to_max_tier.extend(other.forges_to_max_tier);
+		self.souls += other.souls;
+		self.dissolve_souls += other.dissolve_souls;
+		self.spent = self.spent.saturating_add(other.spent);
+	}
+
+	pub fn into_report(mut self) -> Report {
+		let sessions = self.sessions.max(1) as f64;
+		self.forges_to_max_tier.sort_unstable();
+		let reached = self.forges_to_max_tier.len();
+		let total_souls = self.souls.max(1) as f64;
+
+		Report {
+			sessions: self.sessions,
+			packs_per_session: self.packs as f64 / sessions,
+			forges_per_session: self.forges as f64 / sessions,
+			minted_rarity: distribution(&self.minted),
+			held_rarity: distribution(&self.held),
+			max_tier_rate: reached as f64 / sessions,
+			forges_to_max_tier_mean: (reached > 0).then(|| {
+				self.forges_to_max_tier.iter().map(|f| *f as f64).sum::<f64>() / reached as f64
+			}),
+			forges_to_max_tier_median: self.forges_to_max_tier.get(reached / 2).copied(),
+			souls_per_session: self.souls as f64 / sessions,
+			dissolve_souls_per_session: self.dissolve_souls as f64 / sessions,
+			spent_per_session: self.spent as f64 / sessions,
+			spent_per_soul: self.spent as f64 / total_souls,
+		}
+	}
+}
+
+fn distribution(counts: &[u64; RARITY_TIERS]) -> Vec<RarityShare> {
+	let tiers = [
+		RarityTier::Common,
+		RarityTier::Uncommon,
+		RarityTier::Rare,
+		RarityTier::Epic,
+		RarityTier::Legendary,
+		RarityTier::Mythical,
+	];
+	let total = counts.iter().sum::<u64>().max(1) as f64;
+	tiers
+		.into_iter()
+		.map(|tier| RarityShare {
+			share: counts[tier.clone() as usize] as f64 / total,
+			rarity: tier.to_string(),
+		})
+		.collect()
+}
+
+/// The share of avatars of a given rarity.
+#[derive(Serialize)]
+pub struct RarityShare {
+	pub rarity: String,
+	pub share: f64,
+}
+
+/// Summary statistics of a simulation run.
+#[derive(Serialize)]
+pub struct Report {
+	pub sessions: u64,
+	pub packs_per_session: f64,
+	pub forges_per_session: f64,
+	/// Share of each rarity among minted avatars.
+	pub minted_rarity: Vec<RarityShare>,
+	/// Share of each rarity among the avatars held at the end of a session.
+	pub held_rarity: Vec<RarityShare>,
+	/// Share of sessions in which an avatar reached the season's max tier.
+	pub max_tier_rate: f64,
+	pub forges_to_max_tier_mean: Option<f64>,
+	pub forges_to_max_tier_median: Option<u32>,
+	pub souls_per_session: f64,
+	/// Soul points obtainable by dissolving the avatars held at the end of a session.
+	pub dissolve_souls_per_session: f64,
+	pub spent_per_session: f64,
+	pub spent_per_soul: f64,
+}
+
+impl Report {
+	pub fn to_json(&self) -> String {
+		serde_json::to_string_pretty(self).expect("report is serializable; qed")
+	}
+
+	pub fn to_csv(&self) -> String {
+		let optional = |value: Option<String>| value.unwrap_or_default();
+		let mut rows = vec![
+			("sessions".to_string(), self.sessions.to_string()),
+			("packs_per_session".to_string(), self.packs_per_session.to_string()),
+			("forges_per_session".to_string(), self.forges_per_session.to_string()),
+			("max_tier_rate".to_string(), self.max_tier_rate.to_string()),
+			(
+				"forges_to_max_tier_mean".to_string(),
+				optional(self.forges_to_max_tier_mean.map(|v| v.to_string())),
+			),
+			(
+				"forges_to_max_tier_median".to_string(),
+				optional(self.forges_to_max_tier_median.map(|v| v.to_string())),
+			),
+			("souls_per_session".to_string(), self.souls_per_session.to_string()),
+			("dissolve_souls_per_session".to_string(), self.dissolve_souls_per_session.to_string()),
+			("spent_per_session".to_string(), self.spent_per_session.to_string()),
+			("spent_per_soul".to_string(), self.spent_per_soul.to_string()),
+		];
+		for (prefix, shares) in [("minted", &self.minted_rarity), ("held", &self.held_rarity)] {
+			rows.extend(shares.iter().map(|RarityShare { rarity, share }| {
+				(format!("{prefix}_rarity.{rarity}"), share.to_string())
+			}));
+		}
+
+		let mut csv = String::from("metric,value\n");
+		for (metric, value) in rows {
+			csv.push_str(&format!("{metric},{value}\n"));
+		}
+		csv
+	}
+}
@@ -0,0 +1,222 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A minimal runtime hosting the Awesome Avatars pallet, with deterministic randomness.
+
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU64},
+	PalletId,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSigned};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Get, IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, MultiSignature,
+};
+use std::cell::Cell;
+
+pub type Signature = MultiSignature;
+pub type AccountPublic = <Signature as Verify>::Signer;
+pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+pub type Balance = u128;
+pub type BlockNumber = BlockNumberFor<Runtime>;
+pub type CollectionId = u32;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub struct Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Nft: pallet_nfts,
+		AAvatars: pallet_ajuna_awesome_avatars,
+		NftTransfer: pallet_ajuna_nft_transfer,
+	}
+);
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type Nonce = u32;
+	type Block = Block;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+}
+
+parameter_types! {
+	pub const CollectionDeposit: Balance = 1;
+	pub const ItemDeposit: Balance = 1;
+	pub const StringLimit: u32 = 128;
+	pub const MetadataDepositBase: Balance = 1;
+	pub const AttributeDepositBase: Balance = 1;
+	pub const DepositPerByte: Balance = 1;
+	pub const ApprovalsLimit: u32 = 1;
+	pub const ItemAttributesApprovalsLimit: u32 = 10;
+	pub const MaxTips: u32 = 1;
+	pub const MaxDeadlineDuration: u32 = 1;
+	pub const MaxAttributesPerCall: u32 = 10;
+	pub ConfigFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct ParameterGet<const N: u32>;
+
+impl<const N: u32> Get<u32> for ParameterGet<N> {
+	fn get() -> u32 {
+		N
+	}
+}
+
+pub type KeyLimit = ParameterGet<32>;
+pub type ValueLimit = ParameterGet<64>;
+
+impl pallet_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type ItemId = H256;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type Locker = ();
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type StringLimit = StringLimit;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type ApprovalsLimit = ApprovalsLimit;
+	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
+	type MaxTips = MaxTips;
+	type MaxDeadlineDuration = MaxDeadlineDuration;
+	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type Features = ConfigFeatures;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	pallet_nfts::runtime_benchmarks_enabled! {
+		type Helper = ();
+	}
+	type WeightInfo = ();
+}
+
+thread_local! {
+	static SEED: Cell<[u8; 32]> = Cell::new([0; 32]);
+	static DRAWS: Cell<u64> = Cell::new(0);
+}
+
+/// Randomness derived from a seed set by the simulation, so that runs can be reproduced.
+pub struct SeededRandomness;
+
+impl SeededRandomness {
+	/// Reset the source of randomness to `seed`.
+	pub fn reseed(seed: [u8; 32]) {
+		SEED.with(|s| s.set(seed));
+		DRAWS.with(|d| d.set(0));
+	}
+}
+
+impl frame_support::traits::Randomness<H256, BlockNumber> for SeededRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		let seed = SEED.with(|s| s.get());
+		let draw = DRAWS.with(|d| {
+			let draw = d.get();
+			d.set(draw.wrapping_add(1));
+			draw
+		});
+		let output = (seed, draw, subject).using_encoded(sp_io::hashing::blake2_256);
+		(H256::from(output), frame_system::Pallet::<Runtime>::block_number())
+	}
+}
+
+parameter_types! {
+	pub const AwesomeAvatarsPalletId: PalletId = PalletId(*b"aj/aaatr");
+}
+
+impl pallet_ajuna_awesome_avatars::Config for Runtime {
+	type PalletId = AwesomeAvatarsPalletId;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Randomness = SeededRandomness;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type NftHandler = NftTransfer;
+	type IdentityVerifier = ();
+	type AssetId = u32;
+	type Assets = ();
+	type SteppedMigration = ();
+	type ArchiveGracePeriod = ConstU64<100>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const NftTransferPalletId: PalletId = PalletId(*b"aj/nfttr");
+}
+
+impl pallet_ajuna_nft_transfer::Config for Runtime {
+	type PalletId = NftTransferPalletId;
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type ItemId = H256;
+	type ItemConfig = pallet_nfts::ItemConfig;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type NftHelper = Nft;
+}
+
+pub fn new_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	sp_io::TestExternalities::new(storage)
+}
@@ -0,0 +1,316 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Simulated play sessions: a fresh player mints packs and greedily forges them.
+
+use crate::runtime::*;
+use frame_support::traits::Currency;
+use pallet_ajuna_awesome_avatars::{types::*, *};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::H256;
+use sp_runtime::traits::TrailingZeroInput;
+
+pub type SeasonConfig = Season<BlockNumber, Balance, u32>;
+
+/// Number of rarity tiers, including `RarityTier::None`.
+pub const RARITY_TIERS: usize = 7;
+
+/// Upper bound of forges attempted after each minted pack, in case a forge leaves the
+/// avatars unchanged.
+const MAX_FORGES_PER_PACK: u32 = 64;
+
+const SEASON_ID: SeasonId = 1;
+
+/// The parameters of a simulation run.
+#[derive(Clone, Debug)]
+pub struct Settings {
+	pub seed: u64,
+	pub packs: u32,
+	pub pack_size: MintPackSize,
+	pub pack_type: PackType,
+}
+
+/// What a single simulated session produced.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionOutcome {
+	pub minted: [u64; RARITY_TIERS],
+	pub held: [u64; RARITY_TIERS],
+	pub packs: u32,
+	pub forges: u32,
+	pub forges_to_max_tier: Option<u32>,
+	pub souls: u64,
+	pub dissolve_souls: u64,
+	pub spent: Balance,
+}
+
+/// A baseline season using the second logic generation, for when no season is provided.
+pub fn default_season() -> SeasonConfig {
+	Season {
+		name: b"Simulation".to_vec().try_into().unwrap(),
+		description: Default::default(),
+		early_start: 1,
+		start: 1,
+		end: BlockNumber::MAX,
+		max_tier_forges: u32::MAX,
+		max_variations: 6,
+		max_components: 11,
+		min_sacrifices: 1,
+		max_sacrifices: 4,
+		tiers: vec![
+			RarityTier::Common,
+			RarityTier::Uncommon,
+			RarityTier::Rare,
+			RarityTier::Epic,
+			RarityTier::Legendary,
+			RarityTier::Mythical,
+		]
+		.try_into()
+		.unwrap(),
+		single_mint_probs: vec![70, 20, 5, 4, 1].try_into().unwrap(),
+		batch_mint_probs: vec![40, 30, 15, 10, 5].try_into().unwrap(),
+		base_prob: 0,
+		per_period: 10,
+		periods: 12,
+		trade_filters: Default::default(),
+		dissolve_souls: vec![
+			(RarityTier::Common, 1),
+			(RarityTier::Uncommon, 2),
+			(RarityTier::Rare, 4),
+			(RarityTier::Epic, 8),
+			(RarityTier::Legendary, 16),
+			(RarityTier::Mythical, 32),
+		]
+		.try_into()
+		.unwrap(),
+		forge_cooldown: 0,
+		mint_limits: Default::default(),
+		fee: Fee {
+			mint: MintFees { one: 550_000_000_000, three: 500_000_000_000, six: 450_000_000_000 },
+			mint_assets: Default::default(),
+			transfer_avatar: 1_000_000_000_000,
+			buy_minimum: 1_000_000_000,
+			buy_percent: 1,
+			upgrade_storage: 1_000_000_000_000,
+			prepare_avatar: 5_000_000_000_000,
+			avatar_deposit: 0,
+			forge: Default::default(),
+		},
+		mint_logic: LogicGeneration::Second,
+		forge_logic: LogicGeneration::Second,
+	}
+}
+
+/// Run the session with the given index in a fresh externalities environment.
+pub fn run_session(season: &SeasonConfig, settings: &Settings, index: u64) -> SessionOutcome {
+	new_ext().execute_with(|| {
+		SeededRandomness::reseed((settings.seed, index).using_encoded(sp_io::hashing::blake2_256));
+		let player = account(index);
+		start_season(&player, season);
+
+		let funds = Balance::MAX / 2;
+		Balances::make_free_balance_be(&player, funds);
+
+		let mut outcome = SessionOutcome::default();
+		for _ in 0..settings.packs {
+			let Some(avatar_ids) = mint(&player, settings) else { break };
+			outcome.packs += 1;
+			for avatar_id in avatar_ids {
+				outcome.minted[rarity_index(&avatar_of(&avatar_id))] += 1;
+			}
+			forge_all(&player, season, &mut outcome);
+		}
+
+		for (_, avatar) in owned_avatars(&player) {
+			outcome.held[rarity_index(&avatar)] += 1;
+			outcome.souls += avatar.souls as u64;
+			outcome.dissolve_souls += season.dissolve_souls_of(&avatar).unwrap_or_default() as u64;
+		}
+		outcome.spent = funds.saturating_sub(Balances::total_balance(&player));
+		outcome
+	})
+}
+
+fn account(index: u64) -> AccountId {
+	let entropy = (b"simulator", index).using_encoded(sp_io::hashing::blake2_256);
+	AccountId::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+		.expect("input is padded with zeroes; qed")
+}
+
+/// Activate the season for the duration of the session and open minting and forging. Season
+/// scheduling is out of scope, so the season stays active regardless of its configured blocks.
+fn start_season(player: &AccountId, season: &SeasonConfig) {
+	let mut season = season.clone();
+	season.early_start = 1;
+	season.start = 1;
+	season.end = BlockNumber::MAX;
+	Seasons::<Runtime>::insert(SEASON_ID, season);
+	CurrentSeasonStatus::<Runtime>::put(SeasonStatus {
+		season_id: SEASON_ID,
+		early: false,
+		active: true,
+		early_ended: false,
+		max_tier_avatars: 0,
+	});
+
+	let mut config = GlobalConfigs::<Runtime>::get();
+	config.mint.open = true;
+	config.mint.cooldown = 0;
+	config.forge.open = true;
+	GlobalConfigs::<Runtime>::put(config);
+
+	PlayerSeasonConfigs::<Runtime>::mutate(player, SEASON_ID, |config| {
+		config.storage_tier = StorageTier::Max;
+	});
+	System::set_block_number(1);
+}
+
+fn mint(player: &AccountId, settings: &Settings) -> Option<Vec<H256>> {
+	let before = owned_avatars(player).into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+	AAvatars::mint(
+		RuntimeOrigin::signed(player.clone()),
+		MintOption {
+			payment: MintPayment::Normal,
+			pack_type: settings.pack_type.clone(),
+			pack_size: settings.pack_size.clone(),
+		},
+	)
+	.ok()?;
+	advance_block(1);
+
+	let minted = Owners::<Runtime>::get(player, SEASON_ID)
+		.into_iter()
+		.filter(|id| !before.contains(id))
+		.collect();
+	Some(minted)
+}
+
+fn forge_all(player: &AccountId, season: &SeasonConfig, outcome: &mut SessionOutcome) {
+	for _ in 0..MAX_FORGES_PER_PACK {
+		let Some((leader_id, sacrifice_ids)) = select_forge(player, season) else { return };
+		if AAvatars::forge(RuntimeOrigin::signed(player.clone()), leader_id, sacrifice_ids).is_err()
+		{
+			return
+		}
+		outcome.forges += 1;
+		advance_block(season.forge_cooldown.max(1));
+
+		if outcome.forges_to_max_tier.is_none() && is_max_tier(season, &avatar_of(&leader_id)) {
+			outcome.forges_to_max_tier = Some(outcome.forges);
+		}
+	}
+}
+
+/// Choose the next forge: the rarest avatar below the max tier leads, and the least rare
+/// avatars it can be forged with are sacrificed.
+fn select_forge(player: &AccountId, season: &SeasonConfig) -> Option<(H256, Vec<H256>)> {
+	let mut avatars = owned_avatars(player);
+	avatars.sort_by_key(|(_, avatar)| avatar.rarity_tier());
+
+	avatars
+		.iter()
+		.rev()
+		.filter(|(_, leader)| !is_max_tier(season, leader))
+		.find_map(|(leader_id, leader)| {
+			let mut sacrifices = Vec::<(H256, Avatar)>::new();
+			for (id, avatar) in avatars.iter().filter(|(id, _)| id != leader_id) {
+				if sacrifices.len() >= season.max_sacrifices as usize {
+					break
+				}
+				sacrifices.push((*id, avatar.clone()));
+				if !is_forgeable(season, leader, &sacrifices) {
+					sacrifices.pop();
+				}
+			}
+			(sacrifices.len() >= season.min_sacrifices as usize)
+				.then(|| (*leader_id, sacrifices.into_iter().map(|(id, _)| id).collect()))
+		})
+}
+
+fn is_forgeable(season: &SeasonConfig, leader: &Avatar, sacrifices: &[(H256, Avatar)]) -> bool {
+	match season.forge_logic {
+		LogicGeneration::First => true,
+		LogicGeneration::Second => {
+			let sacrifices =
+				sacrifices.iter().map(|(_, avatar)| avatar.clone()).collect::<Vec<_>>();
+			AAvatars::forge_type_of(leader, &sacrifices) != ForgeType::None
+		},
+	}
+}
+
+fn is_max_tier(season: &SeasonConfig, avatar: &Avatar) -> bool {
+	season.tiers.last().map_or(false, |max_tier| &avatar.rarity_tier() >= max_tier)
+}
+
+fn owned_avatars(player: &AccountId) -> Vec<(H256, Avatar)> {
+	Owners::<Runtime>::get(player, SEASON_ID)
+		.into_iter()
+		.map(|id| (id, avatar_of(&id)))
+		.collect()
+}
+
+fn avatar_of(avatar_id: &H256) -> Avatar {
+	Avatars::<Runtime>::get(avatar_id).map(|(_, avatar)| avatar).unwrap_or_default()
+}
+
+fn rarity_index(avatar: &Avatar) -> usize {
+	(avatar.rarity_tier() as usize).min(RARITY_TIERS - 1)
+}
+
+fn advance_block(blocks: BlockNumber) {
+	System::set_block_number(System::block_number().saturating_add(blocks));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::report::Aggregate;
+
+	fn settings() -> Settings {
+		Settings { seed: 7, packs: 5, pack_size: MintPackSize::Six, pack_type: PackType::Material }
+	}
+
+	fn season_with_logic(logic: LogicGeneration) -> SeasonConfig {
+		let mut season = default_season();
+		season.mint_logic = logic.clone();
+		season.forge_logic = logic;
+		season
+	}
+
+	#[test]
+	fn sessions_are_reproducible() {
+		let (season, settings) = (default_season(), settings());
+		let report = || {
+			let mut aggregate = Aggregate::default();
+			for index in 0..3 {
+				aggregate.record(run_session(&season, &settings, index));
+			}
+			aggregate.into_report().to_json()
+		};
+
+		assert_eq!(run_session(&season, &settings, 0), run_session(&season, &settings, 0));
+		assert_eq!(report(), report());
+	}
+
+	#[test]
+	fn sessions_forge_with_every_logic() {
+		for logic in [LogicGeneration::First, LogicGeneration::Second] {
+			let outcome = run_session(&season_with_logic(logic.clone()), &settings(), 0);
+			assert_eq!(outcome.packs, settings().packs, "{logic:?}");
+			assert!(outcome.forges > 0, "{logic:?}");
+			assert!(outcome.spent > 0, "{logic:?}");
+		}
+	}
+}
@@ -40,17 +40,30 @@
 //! * `remove_price` - Remove the price of an avatar.
 //! * `buy` - Buy an avatar.
 //! * `upgrade_storage` - Upgrade the capacity to hold avatars.
+//! * `burn_avatar` - Remove an avatar from the game, releasing its storage deposit.
+//! * `dissolve` - Burn a batch of avatars in exchange for soul points.
+//! * `claim_quest_reward` - Claim the reward of a completed season quest.
+//! * `retire_avatars` - Convert avatars of finished seasons into soulbound trophies.
+//! * `claim_asset_treasury` - Claim the mint fees of a season paid in an asset.
+//! * `offer_rental` - Offer an avatar for rent as a forge leader.
+//! * `cancel_rental_offer` - Withdraw the rental offer of an avatar.
+//! * `rent_avatar` - Rent an avatar offered for rent.
+//! * `end_rental` - Clean up an expired rental.
 //! * `set_organizer` - Set the game organizer.
 //! * `set_treasurer` - Set the treasurer.
 //! * `set_season` - Add a new season.
 //! * `update_global_config` - Update the configuration.
 //! * `set_free_mints` - Set a number of free mints to a player.
+//! * `set_quest` - Define or remove a season quest.
+//! * `set_sponsorship` - Sponsor the transaction fees of players for a season.
+//! * `archive_season` - Prune the per-season data of a finished season.
 //!
 //! ### Public Functions
 //!
 //! * `do_forge` - Forge avatar.
 //! * `do_mint` - Mint avatar.
 //! * `ensure_season` - Given a season id and a season, validate them.
+//! * `trophies_of` - List the trophies of an account.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -65,13 +78,21 @@ mod mock;
 mod tests;
 
 pub mod migration;
+pub mod sponsored_payment;
 pub mod types;
 pub mod weights;
 
-use crate::{types::*, weights::WeightInfo};
+use crate::{
+	migration::stepped::{MigrationId, MigrationProgress, SteppedMigration},
+	types::*,
+	weights::WeightInfo,
+};
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::AllowDeath, Randomness, WithdrawReasons},
+	traits::{
+		Currency, ExistenceRequirement::AllowDeath, Randomness, ReservableCurrency, WithdrawReasons,
+	},
+	weights::WeightMeter,
 	PalletId,
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
@@ -91,11 +112,21 @@ pub mod pallet {
 	use sp_std::collections::vec_deque::VecDeque;
 
 	pub(crate) type AccountIdFor<T> = <T as frame_system::Config>::AccountId;
-	pub(crate) type SeasonOf<T> = Season<BlockNumberFor<T>, BalanceOf<T>>;
+	pub(crate) type SeasonOf<T> = Season<BlockNumberFor<T>, BalanceOf<T>, AssetIdOf<T>>;
 	pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdFor<T>>>::Balance;
+	pub(crate) type AssetIdOf<T> = <T as Config>::AssetId;
+	pub(crate) type MintOptionOf<T> = MintOption<AssetIdOf<T>>;
 	pub(crate) type AvatarIdOf<T> = <T as frame_system::Config>::Hash;
 	pub(crate) type BoundedAvatarIdsOf<T> = BoundedVec<AvatarIdOf<T>, MaxAvatarsPerPlayer>;
 	pub(crate) type GlobalConfigOf<T> = GlobalConfig<BlockNumberFor<T>>;
+	pub(crate) type QuestOf<T> = Quest<BalanceOf<T>>;
+	pub(crate) type TrophyOf<T> = Trophy<BlockNumberFor<T>>;
+	pub(crate) type RentalTermsOf<T> = RentalTerms<BlockNumberFor<T>, BalanceOf<T>>;
+	pub(crate) type RentalAgreementOf<T> = RentalAgreement<AccountIdFor<T>, BlockNumberFor<T>>;
+	pub(crate) type SponsorshipOf<T> = Sponsorship<AccountIdFor<T>, BalanceOf<T>>;
+	pub(crate) type MigrationProgressOf<T> = MigrationProgress<BlockNumberFor<T>>;
+	pub(crate) type SeasonArchiveOf<T> = SeasonArchive<<T as frame_system::Config>::Hash>;
+	pub(crate) type SeasonSummaryOf<T> = SeasonSummary<<T as frame_system::Config>::Hash>;
 	pub(crate) type KeyLimitOf<T> = <T as Config>::KeyLimit;
 	pub(crate) type ValueLimitOf<T> = <T as Config>::ValueLimit;
 	pub(crate) type CollectionIdOf<T> = <<T as Config>::NftHandler as NftHandler<
@@ -126,7 +157,7 @@ pub mod pallet {
 
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
@@ -146,6 +177,23 @@ pub mod pallet {
 			Avatar,
 		>;
 
+		/// Verifies identities of accounts minting early in seasons that require it.
+		type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+
+		/// Identifier of the assets that can be accepted as payment for mints.
+		type AssetId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Handles mint payments in assets other than the native currency.
+		type Assets: AssetPayment<Self::AccountId, Self::AssetId, BalanceOf<Self>>;
+
+		/// Migration executed across blocks once started with
+		/// [`migration::stepped::StartSteppedMigration`].
+		type SteppedMigration: SteppedMigration;
+
+		/// Number of blocks after the end of a season until it is archived automatically.
+		#[pallet::constant]
+		type ArchiveGracePeriod: Get<BlockNumberFor<Self>>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -172,6 +220,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Treasury<T: Config> = StorageMap<_, Identity, SeasonId, BalanceOf<T>, ValueQuery>;
 
+	/// Mint fees collected per season in each accepted asset.
+	#[pallet::storage]
+	pub type AssetTreasury<T: Config> =
+		StorageDoubleMap<_, Identity, SeasonId, Identity, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	pub type GlobalConfigs<T: Config> = StorageValue<_, GlobalConfigOf<T>, ValueQuery>;
 
@@ -189,9 +242,65 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Storage deposits held for avatars, along with the account the deposit is reserved from.
+	///
+	/// Avatars created before storage deposits were introduced have no entry and are therefore
+	/// exempt from holding a deposit.
+	#[pallet::storage]
+	pub type AvatarDeposits<T: Config> =
+		StorageMap<_, Identity, AvatarIdOf<T>, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+	/// Trophies of avatars retired by each account.
+	#[pallet::storage]
+	pub type Trophies<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::AccountId,
+		Identity,
+		AvatarIdOf<T>,
+		TrophyOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	pub type LockedAvatars<T: Config> = StorageMap<_, Identity, AvatarIdOf<T>, ()>;
 
+	/// Avatars offered for rent by their owners.
+	#[pallet::storage]
+	pub type RentalOffers<T: Config> =
+		StorageMap<_, Identity, AvatarIdOf<T>, RentalTermsOf<T>, OptionQuery>;
+
+	/// Rental agreements of rented avatars. Expired agreements no longer restrict the avatar and
+	/// can be removed by anyone with `end_rental`.
+	#[pallet::storage]
+	pub type Rentals<T: Config> =
+		StorageMap<_, Identity, AvatarIdOf<T>, RentalAgreementOf<T>, OptionQuery>;
+
+	/// Sponsors paying the transaction fees of sponsored calls, per season.
+	#[pallet::storage]
+	pub type Sponsorships<T: Config> =
+		StorageMap<_, Identity, SeasonId, SponsorshipOf<T>, OptionQuery>;
+
+	/// Transaction fees paid by the sponsor of a season for each player.
+	#[pallet::storage]
+	pub type SponsoredFees<T: Config> =
+		StorageDoubleMap<_, Identity, SeasonId, Identity, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Progress of the stepped migration being executed, during which minting and forging are
+	/// paused.
+	#[pallet::storage]
+	pub type OngoingMigration<T: Config> = StorageValue<_, MigrationProgressOf<T>, OptionQuery>;
+
+	/// Archivals of finished seasons, holding the summary that remains once their per-season data
+	/// is pruned.
+	#[pallet::storage]
+	pub type SeasonArchives<T: Config> =
+		StorageMap<_, Identity, SeasonId, SeasonArchiveOf<T>, OptionQuery>;
+
+	/// The oldest season not yet archived automatically.
+	#[pallet::storage]
+	pub type NextSeasonToArchive<T: Config> = StorageValue<_, SeasonId, ValueQuery>;
+
 	#[pallet::storage]
 	pub type CollectionId<T: Config> = StorageValue<_, CollectionIdOf<T>, OptionQuery>;
 
@@ -210,6 +319,53 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Players who traded, received avatars or upgraded their storage in each season. Archival
+	/// also moves the players of `SeasonStats` here, so it can find the avatars and configs of a
+	/// season without iterating over those of every season.
+	#[pallet::storage]
+	pub type SeasonPlayers<T: Config> =
+		StorageDoubleMap<_, Identity, SeasonId, Identity, T::AccountId, (), OptionQuery>;
+
+	/// Soul points obtained by players from dissolving avatars, per season.
+	#[pallet::storage]
+	pub type SoulPoints<T: Config> =
+		StorageDoubleMap<_, Identity, T::AccountId, Identity, SeasonId, SoulCount, ValueQuery>;
+
+	/// Quests defined by the organizer for each season.
+	#[pallet::storage]
+	pub type Quests<T: Config> =
+		StorageDoubleMap<_, Identity, SeasonId, Identity, QuestId, QuestOf<T>, OptionQuery>;
+
+	/// Progress of players towards completing the quests of a season.
+	#[pallet::storage]
+	pub type PlayerQuests<T: Config> = StorageNMap<
+		_,
+		(NMapKey<Identity, T::AccountId>, NMapKey<Identity, SeasonId>, NMapKey<Identity, QuestId>),
+		QuestProgress,
+		ValueQuery,
+	>;
+
+	/// Number of avatars minted in the current block, used to enforce the per-block mint limit.
+	#[pallet::storage]
+	pub type BlockMints<T: Config> = StorageValue<_, (BlockNumberFor<T>, MintCount), ValueQuery>;
+
+	/// Number of avatars minted in the current period of each season.
+	#[pallet::storage]
+	pub type PeriodMints<T: Config> =
+		StorageMap<_, Identity, SeasonId, (BlockNumberFor<T>, Stat), ValueQuery>;
+
+	/// Number of avatars minted by each account in the current period of each season.
+	#[pallet::storage]
+	pub type AccountPeriodMints<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::AccountId,
+		Identity,
+		SeasonId,
+		(BlockNumberFor<T>, MintCount),
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	pub type SeasonStats<T: Config> =
 		StorageDoubleMap<_, Identity, SeasonId, Identity, T::AccountId, SeasonInfo, ValueQuery>;
@@ -246,7 +402,12 @@ pub mod pallet {
 				max_tier_avatars: Default::default(),
 			});
 			GlobalConfigs::<T>::put(GlobalConfig {
-				mint: MintConfig { open: true, cooldown: 5_u8.into(), free_mint_fee_multiplier: 1 },
+				mint: MintConfig {
+					open: true,
+					cooldown: 5_u8.into(),
+					free_mint_fee_multiplier: 1,
+					max_per_block: 0,
+				},
 				forge: ForgeConfig { open: true },
 				transfer: TransferConfig {
 					open: true,
@@ -273,6 +434,13 @@ pub mod pallet {
 		TreasurerSet { season_id: SeasonId, treasurer: T::AccountId },
 		/// A season's treasury has been claimed by a treasurer.
 		TreasuryClaimed { season_id: SeasonId, treasurer: T::AccountId, amount: BalanceOf<T> },
+		/// The treasury of {asset_id} for {season_id} has been claimed.
+		AssetTreasuryClaimed {
+			season_id: SeasonId,
+			asset_id: AssetIdOf<T>,
+			treasurer: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 		/// The season configuration for {season_id} has been updated.
 		UpdatedSeason { season_id: SeasonId, season: SeasonOf<T> },
 		/// Global configuration updated.
@@ -283,6 +451,50 @@ pub mod pallet {
 		AvatarsForged { avatar_ids: Vec<(AvatarIdOf<T>, UpgradedComponents)> },
 		/// Avatar transferred.
 		AvatarTransferred { from: T::AccountId, to: T::AccountId, avatar_id: AvatarIdOf<T> },
+		/// Avatar burned.
+		AvatarBurned { avatar_id: AvatarIdOf<T> },
+		/// Avatars retired into trophies.
+		AvatarsRetired { player: T::AccountId, avatar_ids: Vec<AvatarIdOf<T>> },
+		/// Avatar offered for rent.
+		RentalOffered { avatar_id: AvatarIdOf<T>, terms: RentalTermsOf<T> },
+		/// Avatar rental offer withdrawn.
+		RentalOfferCancelled { avatar_id: AvatarIdOf<T> },
+		/// Avatar rented.
+		AvatarRented {
+			avatar_id: AvatarIdOf<T>,
+			owner: T::AccountId,
+			renter: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Avatar rental ended and the avatar reverted to its owner.
+		RentalEnded { avatar_id: AvatarIdOf<T> },
+		/// The sponsorship of {season_id} has been set, or removed if `None`.
+		SponsorshipSet { season_id: SeasonId, sponsorship: Option<SponsorshipOf<T>> },
+		/// A sponsor paid the transaction fee of a player.
+		SponsoredFeePaid {
+			season_id: SeasonId,
+			sponsor: T::AccountId,
+			player: T::AccountId,
+			fee: BalanceOf<T>,
+		},
+		/// The per-season data of a finished season has been pruned.
+		SeasonArchived { season_id: SeasonId, summary: SeasonSummaryOf<T> },
+		/// A stepped migration has started.
+		MigrationStarted { id: MigrationId },
+		/// A stepped migration has completed.
+		MigrationCompleted { id: MigrationId, steps: u32 },
+		/// A stepped migration has failed and stopped.
+		MigrationFailed { id: MigrationId, steps: u32 },
+		/// A failed stepped migration is retried from where it stopped.
+		MigrationRetried { id: MigrationId },
+		/// A stepped migration has been aborted before completing.
+		MigrationAborted { id: MigrationId, steps: u32 },
+		/// Avatars dissolved into soul points.
+		AvatarsDissolved {
+			avatar_ids: Vec<AvatarIdOf<T>>,
+			target: DissolveTarget<AvatarIdOf<T>>,
+			souls: SoulCount,
+		},
 		/// A season has started.
 		SeasonStarted(SeasonId),
 		/// A season has finished.
@@ -291,6 +503,17 @@ pub mod pallet {
 		FreeMintsTransferred { from: T::AccountId, to: T::AccountId, how_many: MintCount },
 		/// Free mints set for target account.
 		FreeMintsSet { target: T::AccountId, how_many: MintCount },
+		/// A quest has been set or removed for a season.
+		QuestSet { season_id: SeasonId, quest_id: QuestId, quest: Option<QuestOf<T>> },
+		/// A player has completed a quest.
+		QuestCompleted { player: T::AccountId, season_id: SeasonId, quest_id: QuestId },
+		/// A player has claimed the reward of a completed quest.
+		QuestRewardClaimed {
+			player: T::AccountId,
+			season_id: SeasonId,
+			quest_id: QuestId,
+			reward: QuestReward<BalanceOf<T>>,
+		},
 		/// Avatar has price set for trade.
 		AvatarPriceSet { avatar_id: AvatarIdOf<T>, price: BalanceOf<T> },
 		/// Avatar has price removed for trade.
@@ -341,6 +564,18 @@ pub mod pallet {
 		UnknownTreasurer,
 		/// The preparation doesn't exist.
 		UnknownPreparation,
+		/// The quest doesn't exist.
+		UnknownQuest,
+		/// The quest ID exceeds the maximum number of quests per season.
+		QuestIdTooHigh,
+		/// The quest's condition can never be fulfilled.
+		InvalidQuestCondition,
+		/// Tried claiming the reward of a quest that hasn't been completed yet.
+		QuestNotCompleted,
+		/// Tried claiming the reward of a quest more than once.
+		QuestRewardAlreadyClaimed,
+		/// The season's treasury cannot cover the quest reward.
+		InsufficientTreasury,
 		/// The season ID of a season to create is not sequential.
 		NonSequentialSeasonId,
 		/// The sum of the given single mint probabilities overflows.
@@ -356,6 +591,12 @@ pub mod pallet {
 		BaseProbTooHigh,
 		/// Some rarity tier are duplicated.
 		DuplicatedRarityTier,
+		/// Some forge types have more than one fee.
+		DuplicatedForgeType,
+		/// Some assets have more than one set of mint fees.
+		DuplicatedMintAsset,
+		/// The asset is not accepted as payment for minting in the current season.
+		UnsupportedMintAsset,
 		/// Minting is not available at the moment.
 		MintClosed,
 		/// Forging is not available at the moment.
@@ -390,6 +631,16 @@ pub mod pallet {
 		IncorrectSeasonId,
 		/// The player must wait cooldown period.
 		MintCooldown,
+		/// The player must wait the season's forge cooldown period.
+		ForgeCooldown,
+		/// The maximum number of avatars minted in this block has been reached.
+		BlockMintLimitReached,
+		/// The maximum number of avatars minted in this period of the season has been reached.
+		PeriodMintLimitReached,
+		/// The player has reached the maximum number of avatars minted in this period.
+		AccountMintLimitReached,
+		/// The season requires a verified identity to mint during its early phase.
+		IdentityNotVerified,
 		/// The season's max components value is less than the minimum allowed (1).
 		MaxComponentsTooLow,
 		/// The season's max components value is more than the maximum allowed (random byte: 32).
@@ -410,6 +661,12 @@ pub mod pallet {
 		TooManySacrifices,
 		/// Leader is being sacrificed.
 		LeaderSacrificed,
+		/// No avatars were given to dissolve.
+		NoAvatarsToDissolve,
+		/// The same avatar was given more than once to dissolve.
+		DuplicatedAvatarToDissolve,
+		/// The season's conversion table has no entry for the avatar's rarity.
+		AvatarCannotBeDissolved,
 		/// This avatar cannot be used in trades.
 		AvatarCannotBeTraded,
 		/// An avatar listed for trade is used to forge.
@@ -432,6 +689,36 @@ pub mod pallet {
 		CannotClaimDuringSeason,
 		/// Tried claiming treasury which is zero.
 		CannotClaimZero,
+		/// Tried retiring avatars of a season that hasn't finished yet.
+		CannotRetireDuringSeason,
+		/// No avatars were given to retire.
+		NoAvatarsToRetire,
+		/// The avatar is rented out and cannot be used by its owner.
+		AvatarRented,
+		/// The avatar has not been offered for rent.
+		UnknownRentalOffer,
+		/// Rentals must last at least one block and allow at least one forge.
+		InvalidRentalTerms,
+		/// Tried renting an owned avatar.
+		CannotRentOwnAvatar,
+		/// Tried ending a rental which has not expired.
+		RentalNotExpired,
+		/// The avatar has no rental agreement.
+		UnknownRental,
+		/// Rented avatars cannot be consumed by forging.
+		RentedAvatarConsumed,
+		/// Minting and forging are paused while a stepped migration is in progress.
+		MigrationInProgress,
+		/// No stepped migration is in progress.
+		NoMigrationInProgress,
+		/// Only failed stepped migrations can be retried.
+		MigrationNotFailed,
+		/// Only finished seasons can be archived.
+		SeasonNotFinished,
+		/// The season has already been archived.
+		SeasonAlreadyArchived,
+		/// Avatars of seasons being archived can no longer be traded or transferred.
+		SeasonArchived,
 		/// The components tried to mint were not compatible.
 		IncompatibleMintComponents,
 		/// The components tried to forge were not compatible.
@@ -472,6 +759,15 @@ pub mod pallet {
 
 			weight
 		}
+
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut weight = Self::progress_migration(remaining_weight);
+			weight.saturating_accrue(Self::archive_expired_season(
+				now,
+				remaining_weight.saturating_sub(weight),
+			));
+			weight
+		}
 	}
 
 	#[pallet::call]
@@ -488,7 +784,7 @@ pub mod pallet {
 			T::WeightInfo::mint_normal(n)
 				.max(T::WeightInfo::mint_free(n))
 		})]
-		pub fn mint(origin: OriginFor<T>, mint_option: MintOption) -> DispatchResult {
+		pub fn mint(origin: OriginFor<T>, mint_option: MintOptionOf<T>) -> DispatchResult {
 			let player = ensure_signed(origin)?;
 			Self::do_mint(&player, &mint_option)
 		}
@@ -501,16 +797,17 @@ pub mod pallet {
 		///
 		/// Emits `AvatarForged` event when successful.
 		///
-		/// Weight: `O(1)`
+		/// Weight: `O(1)`, refunded down to the weight of the forge type performed.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::forge(MaxAvatarsPerPlayer::get()))]
+		#[pallet::weight(Pallet::<T>::max_forge_weight())]
 		pub fn forge(
 			origin: OriginFor<T>,
 			leader: AvatarIdOf<T>,
 			sacrifices: Vec<AvatarIdOf<T>>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let player = ensure_signed(origin)?;
-			Self::do_forge(&player, &leader, sacrifices)
+			let weight = Self::do_forge(&player, &leader, sacrifices)?;
+			Ok(Some(weight).into())
 		}
 
 		#[pallet::call_index(2)]
@@ -687,6 +984,9 @@ pub mod pallet {
 			PlayerSeasonConfigs::<T>::mutate(&seller, current_season_id, |config| {
 				config.stats.trade.sold.saturating_inc()
 			});
+			SeasonPlayers::<T>::insert(current_season_id, &buyer, ());
+			SeasonPlayers::<T>::insert(current_season_id, &seller, ());
+			Self::update_quest_progress(&buyer, current_season_id, QuestAction::Buy);
 
 			Self::deposit_event(Event::AvatarTraded { avatar_id, from: seller, to: buyer });
 			Ok(())
@@ -733,6 +1033,7 @@ pub mod pallet {
 			PlayerSeasonConfigs::<T>::mutate(&account_to_upgrade, season_id, |account| {
 				account.storage_tier = storage_tier.upgrade()
 			});
+			SeasonPlayers::<T>::insert(season_id, &account_to_upgrade, ());
 			Self::deposit_event(Event::StorageTierUpgraded {
 				account: account_to_upgrade,
 				season_id,
@@ -792,17 +1093,7 @@ pub mod pallet {
 		#[pallet::call_index(10)]
 		#[pallet::weight(T::WeightInfo::claim_treasury())]
 		pub fn claim_treasury(origin: OriginFor<T>, season_id: SeasonId) -> DispatchResult {
-			let maybe_treasurer = ensure_signed(origin)?;
-			let treasurer = Treasurer::<T>::get(season_id).ok_or(Error::<T>::UnknownTreasurer)?;
-			ensure!(maybe_treasurer == treasurer, DispatchError::BadOrigin);
-
-			let (current_season_id, season) = Self::current_season_with_id()?;
-			ensure!(
-				season_id < current_season_id ||
-					(season_id == current_season_id &&
-						<frame_system::Pallet<T>>::block_number() > season.end),
-				Error::<T>::CannotClaimDuringSeason
-			);
+			let treasurer = Self::ensure_treasurer_claim(origin, season_id)?;
 
 			let amount = Treasury::<T>::take(season_id);
 			ensure!(!amount.is_zero(), Error::<T>::CannotClaimZero);
@@ -1083,9 +1374,425 @@ pub mod pallet {
 					}),
 			}
 		}
+
+		/// Burn an avatar.
+		///
+		/// The origin of this call must specify an avatar, owned by the origin, that is neither
+		/// locked, prepared nor listed for trade. The avatar is removed from storage and any
+		/// storage deposit held for it is released to its depositor.
+		///
+		/// Emits `AvatarBurned` event when successful.
+		///
+		/// Weight: `O(n)` where:
+		/// - `n = max avatars per player`
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::burn_avatar(MaxAvatarsPerPlayer::get()))]
+		pub fn burn_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+			let avatar = Self::ensure_ownership(&player, &avatar_id)?;
+			ensure!(Self::ensure_for_trade(&avatar_id).is_err(), Error::<T>::AvatarInTrade);
+			Self::ensure_unlocked(&avatar_id)?;
+			Self::ensure_unprepared(&avatar_id)?;
+
+			Self::remove_avatar_from(&player, &avatar.season_id, &avatar_id);
+			Self::deposit_event(Event::AvatarBurned { avatar_id });
+			Ok(())
+		}
+
+		/// Dissolve avatars into soul points.
+		///
+		/// The origin of this call must specify avatars it owns that are neither locked, prepared
+		/// nor listed for trade. Each avatar is burned and converted into soul points according
+		/// to its season's conversion table by rarity. The soul points are added either to a
+		/// leader avatar of the same season or to the player's soul balance.
+		///
+		/// Emits `AvatarsDissolved` event when successful.
+		///
+		/// Weight: `O(n)` where:
+		/// - `n = number of avatars to dissolve`
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::dissolve(avatar_ids.len() as u32))]
+		pub fn dissolve(
+			origin: OriginFor<T>,
+			avatar_ids: BoundedAvatarIdsOf<T>,
+			target: DissolveTarget<AvatarIdOf<T>>,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+			Self::do_dissolve(&player, avatar_ids.into_inner(), target)
+		}
+
+		/// Set a quest.
+		///
+		/// Defines the quest with the given ID for a season, or removes it when no quest is
+		/// given. Progress made by players towards a removed quest can no longer be claimed.
+		///
+		/// It can only be called by an organizer account.
+		///
+		/// Emits `QuestSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_quest())]
+		pub fn set_quest(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			quest_id: QuestId,
+			quest: Option<QuestOf<T>>,
+		) -> DispatchResult {
+			Self::ensure_organizer(origin)?;
+			ensure!(Seasons::<T>::contains_key(season_id), Error::<T>::UnknownSeason);
+			ensure!(quest_id < MAX_QUESTS, Error::<T>::QuestIdTooHigh);
+			if let Some(quest) = &quest {
+				ensure!(!quest.condition.target().is_zero(), Error::<T>::InvalidQuestCondition);
+			}
+
+			Quests::<T>::set(season_id, quest_id, quest.clone());
+			Self::deposit_event(Event::QuestSet { season_id, quest_id, quest });
+			Ok(())
+		}
+
+		/// Claim the reward of a completed quest.
+		///
+		/// Free mints are credited to the player, while tokens are paid out of the season's
+		/// treasury. The reward of a quest can only be claimed once per player.
+		///
+		/// Emits `QuestRewardClaimed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::claim_quest_reward())]
+		pub fn claim_quest_reward(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			quest_id: QuestId,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+			let Quest { condition, reward } =
+				Quests::<T>::get(season_id, quest_id).ok_or(Error::<T>::UnknownQuest)?;
+
+			PlayerQuests::<T>::try_mutate((&player, season_id, quest_id), |quest_progress| {
+				ensure!(!quest_progress.claimed, Error::<T>::QuestRewardAlreadyClaimed);
+				ensure!(quest_progress.is_complete(&condition), Error::<T>::QuestNotCompleted);
+				quest_progress.claimed = true;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			match &reward {
+				QuestReward::FreeMints(how_many) => {
+					PlayerConfigs::<T>::mutate(&player, |config| {
+						config.free_mints.saturating_accrue(*how_many)
+					});
+				},
+				QuestReward::Tokens(amount) => {
+					Treasury::<T>::try_mutate(season_id, |treasury| {
+						*treasury =
+							treasury.checked_sub(amount).ok_or(Error::<T>::InsufficientTreasury)?;
+						Ok::<_, DispatchError>(())
+					})?;
+					T::Currency::transfer(
+						&Self::treasury_account_id(),
+						&player,
+						*amount,
+						AllowDeath,
+					)?;
+				},
+			}
+
+			Self::deposit_event(Event::QuestRewardClaimed { player, season_id, quest_id, reward });
+			Ok(())
+		}
+
+		/// Retire avatars into trophies.
+		///
+		/// The origin of this call must specify avatars it owns from finished seasons, that are
+		/// neither locked, prepared nor listed for trade. Each avatar is removed from the game,
+		/// freeing its storage tier slot and releasing its storage deposit, and is recorded as a
+		/// soulbound trophy of the player.
+		///
+		/// Emits `AvatarsRetired` event when successful.
+		///
+		/// Weight: `O(n)` where:
+		/// - `n = number of avatars to retire`
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::retire_avatars(avatar_ids.len() as u32))]
+		pub fn retire_avatars(
+			origin: OriginFor<T>,
+			avatar_ids: BoundedAvatarIdsOf<T>,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+			ensure!(!avatar_ids.is_empty(), Error::<T>::NoAvatarsToRetire);
+
+			let current_season_id = CurrentSeasonStatus::<T>::get().season_id;
+			let now = <frame_system::Pallet<T>>::block_number();
+			for avatar_id in avatar_ids.iter() {
+				let avatar = Self::ensure_ownership(&player, avatar_id)?;
+				ensure!(avatar.season_id < current_season_id, Error::<T>::CannotRetireDuringSeason);
+				ensure!(Self::ensure_for_trade(avatar_id).is_err(), Error::<T>::AvatarInTrade);
+				Self::ensure_unlocked(avatar_id)?;
+				Self::ensure_unprepared(avatar_id)?;
+
+				let season = Self::seasons(&avatar.season_id)?;
+				let rarity = RarityTier::from_byte(avatar.rarity());
+				let trophy = Trophy {
+					season_id: avatar.season_id,
+					max_tier: rarity >= season.max_tier(),
+					rarity,
+					encoding: avatar.encoding,
+					dna: avatar.dna,
+					souls: avatar.souls,
+					retired_at: now,
+				};
+				Self::remove_avatar_from(&player, &trophy.season_id, avatar_id);
+				Trophies::<T>::insert(&player, avatar_id, trophy);
+			}
+
+			Self::deposit_event(Event::AvatarsRetired {
+				player,
+				avatar_ids: avatar_ids.into_inner(),
+			});
+			Ok(())
+		}
+
+		/// Claim the treasury of a season collected in the given asset.
+		///
+		/// The origin of this call must be signed by a treasurer account associated with the given
+		/// season ID. Like the native currency treasury, it can only be claimed once the season
+		/// finishes.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::claim_asset_treasury())]
+		pub fn claim_asset_treasury(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let treasurer = Self::ensure_treasurer_claim(origin, season_id)?;
+
+			let amount = AssetTreasury::<T>::take(season_id, asset_id);
+			ensure!(!amount.is_zero(), Error::<T>::CannotClaimZero);
+
+			T::Assets::transfer(&asset_id, &Self::treasury_account_id(), &treasurer, amount)?;
+			Self::deposit_event(Event::AssetTreasuryClaimed {
+				season_id,
+				asset_id,
+				treasurer,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Offer an avatar for rent.
+		///
+		/// Replaces any previous offer for the avatar. Renters pay the price upfront and may use
+		/// the avatar as a forge leader until the rental expires.
+		///
+		/// Emits `RentalOffered` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::offer_rental())]
+		pub fn offer_rental(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+			terms: RentalTermsOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let _ = Self::ensure_ownership(&owner, &avatar_id)?;
+			ensure!(
+				!terms.duration.is_zero() && terms.max_forges != Some(0),
+				Error::<T>::InvalidRentalTerms
+			);
+			Self::ensure_for_rental(&avatar_id)?;
+
+			RentalOffers::<T>::insert(avatar_id, &terms);
+			Self::deposit_event(Event::RentalOffered { avatar_id, terms });
+			Ok(())
+		}
+
+		/// Withdraw the rental offer of an avatar.
+		///
+		/// Emits `RentalOfferCancelled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::cancel_rental_offer())]
+		pub fn cancel_rental_offer(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let (avatar_owner, _) = Self::avatars(&avatar_id)?;
+			ensure!(owner == avatar_owner, Error::<T>::Ownership);
+			ensure!(RentalOffers::<T>::take(avatar_id).is_some(), Error::<T>::UnknownRentalOffer);
+
+			Self::deposit_event(Event::RentalOfferCancelled { avatar_id });
+			Ok(())
+		}
+
+		/// Rent an avatar offered for rent.
+		///
+		/// The rent is paid upfront to the owner. The renter can use the avatar as a forge leader
+		/// until the rental expires, after which the avatar reverts to its owner.
+		///
+		/// Emits `AvatarRented` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::rent_avatar())]
+		pub fn rent_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let renter = ensure_signed(origin)?;
+			let (owner, _) = Self::avatars(&avatar_id)?;
+			ensure!(renter != owner, Error::<T>::CannotRentOwnAvatar);
+			Self::ensure_not_rented(&avatar_id)?;
+			Self::ensure_for_rental(&avatar_id)?;
+			let terms = RentalOffers::<T>::take(avatar_id).ok_or(Error::<T>::UnknownRentalOffer)?;
+
+			T::Currency::transfer(&renter, &owner, terms.price, AllowDeath)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let rental = RentalAgreement::new(renter.clone(), &terms, now);
+			let expires_at = rental.expires_at;
+			Rentals::<T>::insert(avatar_id, rental);
+
+			Self::deposit_event(Event::AvatarRented { avatar_id, owner, renter, expires_at });
+			Ok(())
+		}
+
+		/// Remove the agreement of an expired rental.
+		///
+		/// Expired rentals no longer restrict their avatars, so this only cleans up storage. It
+		/// can be called by any account.
+		///
+		/// Emits `RentalEnded` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::end_rental())]
+		pub fn end_rental(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let rental = Rentals::<T>::get(avatar_id).ok_or(Error::<T>::UnknownRental)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(rental.has_expired(now), Error::<T>::RentalNotExpired);
+
+			Rentals::<T>::remove(avatar_id);
+			Self::deposit_event(Event::RentalEnded { avatar_id });
+			Ok(())
+		}
+
+		/// Sponsor the transaction fees of players for a season.
+		///
+		/// The organizer pays the fees of sponsored calls from its own account, up to `budget` in
+		/// total and `max_per_account` for each player. A zero budget removes the sponsorship.
+		///
+		/// Emits `SponsorshipSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_sponsorship())]
+		pub fn set_sponsorship(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			budget: BalanceOf<T>,
+			max_per_account: BalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = Self::ensure_organizer(origin)?;
+			ensure!(Seasons::<T>::contains_key(season_id), Error::<T>::UnknownSeason);
+
+			let sponsorship =
+				(!budget.is_zero()).then_some(Sponsorship { sponsor, budget, max_per_account });
+			Sponsorships::<T>::set(season_id, sponsorship.clone());
+			Self::deposit_event(Event::SponsorshipSet { season_id, sponsorship });
+			Ok(())
+		}
+
+		/// Prune the per-season data of a finished season, processing at most `limit` entries.
+		///
+		/// Archiving a season removes its player stats, trades, player configs and avatar ownership
+		/// lists, refunding the deposits held for its avatars and keeping only a summary. Its
+		/// avatars can no longer be traded, transferred or forged once the archival starts. Large
+		/// seasons are archived over several calls, each resuming where the previous one stopped.
+		/// Seasons are also archived automatically once `ArchiveGracePeriod` has passed since their
+		/// end.
+		///
+		/// Emits `SeasonArchived` event once the season is fully archived.
+		///
+		/// Weight: `O(n)` where:
+		/// - `n = limit`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::archive_season(*limit))]
+		pub fn archive_season(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			limit: u32,
+		) -> DispatchResult {
+			let _ = Self::ensure_organizer(origin)?;
+			ensure!(Seasons::<T>::contains_key(season_id), Error::<T>::UnknownSeason);
+			ensure!(
+				season_id < CurrentSeasonStatus::<T>::get().season_id,
+				Error::<T>::SeasonNotFinished
+			);
+			ensure!(
+				!SeasonArchives::<T>::get(season_id).map_or(false, |archive| archive.is_archived()),
+				Error::<T>::SeasonAlreadyArchived
+			);
+
+			let item_weight = Self::archive_item_weight();
+			let mut meter = WeightMeter::from_limit(item_weight.saturating_mul(limit.into()));
+			Self::do_archive_season(season_id, item_weight, &mut meter);
+			Ok(())
+		}
+
+		/// Retry the failed stepped migration, resuming from the last step that succeeded.
+		///
+		/// Emits `MigrationRetried` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::retry_migration())]
+		pub fn retry_migration(origin: OriginFor<T>) -> DispatchResult {
+			let _ = Self::ensure_organizer(origin)?;
+			let id = OngoingMigration::<T>::try_mutate(|maybe_progress| {
+				let progress = maybe_progress.as_mut().ok_or(Error::<T>::NoMigrationInProgress)?;
+				ensure!(progress.failed, Error::<T>::MigrationNotFailed);
+				progress.failed = false;
+				Ok::<_, DispatchError>(progress.id)
+			})?;
+
+			Self::deposit_event(Event::MigrationRetried { id });
+			Ok(())
+		}
+
+		/// Abort the ongoing stepped migration, resuming minting and forging.
+		///
+		/// Entries migrated by the steps executed so far are left as they are.
+		///
+		/// Emits `MigrationAborted` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::abort_migration())]
+		pub fn abort_migration(origin: OriginFor<T>) -> DispatchResult {
+			let _ = Self::ensure_organizer(origin)?;
+			let progress =
+				OngoingMigration::<T>::take().ok_or(Error::<T>::NoMigrationInProgress)?;
+
+			Self::deposit_event(Event::MigrationAborted { id: progress.id, steps: progress.steps });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The trophies of an account, along with the IDs of the avatars they were retired from.
+		pub fn trophies_of(account: &T::AccountId) -> Vec<(AvatarIdOf<T>, TrophyOf<T>)> {
+			Trophies::<T>::iter_prefix(account).collect()
+		}
+
+		/// The forge type a second generation forge of `leader` with `sacrifices` would perform.
+		pub fn forge_type_of(leader: &Avatar, sacrifices: &[Avatar]) -> ForgeType {
+			ForgerV2::<T>::forge_type_of(leader, sacrifices)
+		}
+
 		/// The account ID of the treasury.
 		pub fn treasury_account_id() -> T::AccountId {
 			T::PalletId::get().into_account_truncating()
@@ -1101,6 +1808,65 @@ pub mod pallet {
 			T::Currency::deposit_creating(&Self::treasury_account_id(), amount);
 		}
 
+		/// Check that the origin is the treasurer of a season which has finished.
+		pub(crate) fn ensure_treasurer_claim(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+		) -> Result<T::AccountId, DispatchError> {
+			let maybe_treasurer = ensure_signed(origin)?;
+			let treasurer = Treasurer::<T>::get(season_id).ok_or(Error::<T>::UnknownTreasurer)?;
+			ensure!(maybe_treasurer == treasurer, DispatchError::BadOrigin);
+
+			let (current_season_id, season) = Self::current_season_with_id()?;
+			ensure!(
+				season_id < current_season_id ||
+					(season_id == current_season_id &&
+						<frame_system::Pallet<T>>::block_number() > season.end),
+				Error::<T>::CannotClaimDuringSeason
+			);
+			Ok(treasurer)
+		}
+
+		/// The season and sponsor paying a transaction fee of `fee` for `player`, if any.
+		pub(crate) fn sponsor_of(
+			player: &T::AccountId,
+			fee: BalanceOf<T>,
+		) -> Option<(SeasonId, T::AccountId)> {
+			let season_id = CurrentSeasonStatus::<T>::get().season_id;
+			let sponsorship = Sponsorships::<T>::get(season_id)?;
+			let spent = SponsoredFees::<T>::get(season_id, player);
+			(fee <= sponsorship.budget && spent.saturating_add(fee) <= sponsorship.max_per_account)
+				.then_some((season_id, sponsorship.sponsor))
+		}
+
+		/// Account for a transaction fee paid by the sponsor of a season.
+		pub(crate) fn charge_sponsorship(
+			season_id: SeasonId,
+			player: &T::AccountId,
+			fee: BalanceOf<T>,
+		) {
+			Sponsorships::<T>::mutate(season_id, |maybe_sponsorship| {
+				if let Some(sponsorship) = maybe_sponsorship {
+					sponsorship.budget.saturating_reduce(fee);
+				}
+			});
+			SponsoredFees::<T>::mutate(season_id, player, |spent| spent.saturating_accrue(fee));
+		}
+
+		/// Return the unused part of a sponsored transaction fee to the sponsorship of a season.
+		pub(crate) fn refund_sponsorship(
+			season_id: SeasonId,
+			player: &T::AccountId,
+			refund: BalanceOf<T>,
+		) {
+			Sponsorships::<T>::mutate(season_id, |maybe_sponsorship| {
+				if let Some(sponsorship) = maybe_sponsorship {
+					sponsorship.budget.saturating_accrue(refund);
+				}
+			});
+			SponsoredFees::<T>::mutate(season_id, player, |spent| spent.saturating_reduce(refund));
+		}
+
 		/// Check that the origin is an organizer account.
 		pub(crate) fn ensure_organizer(
 			origin: OriginFor<T>,
@@ -1151,10 +1917,14 @@ pub mod pallet {
 		}
 
 		/// Mint a new avatar.
-		pub(crate) fn do_mint(player: &T::AccountId, mint_option: &MintOption) -> DispatchResult {
+		pub(crate) fn do_mint(
+			player: &T::AccountId,
+			mint_option: &MintOptionOf<T>,
+		) -> DispatchResult {
 			let (season_id, season) = Self::current_season_with_id()?;
 
 			Self::ensure_for_mint(player, &season_id, mint_option)?;
+			Self::track_mint_limits(player, &season_id, &season, mint_option)?;
 
 			let generated_avatar_ids = match season.mint_logic {
 				LogicGeneration::First => MinterV1::<T>::mint(player, &season_id, mint_option),
@@ -1168,6 +1938,16 @@ pub mod pallet {
 					T::Currency::withdraw(player, fee, WithdrawReasons::FEE, AllowDeath)?;
 					Self::deposit_into_treasury(&season_id, fee);
 				},
+				MintPayment::Asset(asset_id) => {
+					let fee = season
+						.fee
+						.mint_asset_fee_for(&asset_id, &mint_option.pack_size)
+						.ok_or(Error::<T>::UnsupportedMintAsset)?;
+					T::Assets::transfer(&asset_id, player, &Self::treasury_account_id(), fee)?;
+					AssetTreasury::<T>::mutate(season_id, asset_id, |bal| {
+						bal.saturating_accrue(fee)
+					});
+				},
 				MintPayment::Free => {
 					let fee = (mint_option.pack_size.as_mint_count())
 						.saturating_mul(mint.free_mint_fee_multiplier);
@@ -1181,6 +1961,10 @@ pub mod pallet {
 				},
 			};
 
+			for avatar_id in generated_avatar_ids.iter() {
+				Self::reserve_avatar_deposit(player, avatar_id, season.fee.avatar_deposit)?;
+			}
+
 			PlayerSeasonConfigs::<T>::try_mutate(
 				player,
 				season_id,
@@ -1201,21 +1985,27 @@ pub mod pallet {
 			SeasonStats::<T>::mutate(season_id, player, |info| {
 				info.minted.saturating_accrue(generated_avatar_ids.len() as Stat);
 			});
+			Self::update_quest_progress(
+				player,
+				season_id,
+				QuestAction::Mint(&mint_option.pack_type),
+			);
 
 			Self::deposit_event(Event::AvatarsMinted { avatar_ids: generated_avatar_ids });
 			Ok(())
 		}
 
-		/// Enhance an avatar using a batch of avatars.
+		/// Enhance an avatar using a batch of avatars, returning the weight of the forge performed.
 		pub(crate) fn do_forge(
 			player: &T::AccountId,
 			leader_id: &AvatarIdOf<T>,
 			sacrifice_ids: Vec<AvatarIdOf<T>>,
-		) -> DispatchResult {
+		) -> Result<Weight, DispatchError> {
 			let GlobalConfig { forge, .. } = GlobalConfigs::<T>::get();
 			ensure!(forge.open, Error::<T>::ForgeClosed);
+			ensure!(!OngoingMigration::<T>::exists(), Error::<T>::MigrationInProgress);
 
-			let (leader, sacrifice_ids, sacrifices, season_id, season) =
+			let (leader_owner, leader, sacrifice_ids, sacrifices, season_id, season) =
 				Self::ensure_for_forge(player, leader_id, sacrifice_ids)?;
 
 			let avatar_count = Owners::<T>::get(player, season_id).len();
@@ -1223,7 +2013,18 @@ pub mod pallet {
 				PlayerSeasonConfigs::<T>::get(player, season_id).storage_tier as usize;
 			let restricted_forge = max_storage == avatar_count;
 
-			let input_leader = (*leader_id, leader);
+			let (forge_type, weight) = match season.forge_logic {
+				LogicGeneration::First =>
+					(ForgeType::None, T::WeightInfo::forge(avatar_count as u32)),
+				LogicGeneration::Second => {
+					let forge_type = ForgerV2::<T>::forge_type_of(&leader, &sacrifices);
+					let weight = Self::forge_weight(&forge_type, sacrifices.len() as u32);
+					(forge_type, weight)
+				},
+			};
+			Self::charge_forge_fee(player, &season_id, &season, &forge_type)?;
+
+			let input_leader = (*leader_id, leader.clone());
 			let input_sacrifices =
 				sacrifice_ids.into_iter().zip(sacrifices).collect::<Vec<ForgeItem<T>>>();
 			let (output_leader, output_other) = match season.forge_logic {
@@ -1247,14 +2048,63 @@ pub mod pallet {
 
 			Self::process_leader_forge_output(
 				player,
+				&leader_owner,
 				&season_id,
 				&season,
 				input_leader,
 				output_leader,
 			)?;
-			Self::process_other_forge_outputs(player, &season_id, output_other)?;
+			Self::process_other_forge_outputs(player, &season_id, &season, output_other)?;
 			Self::update_forging_statistics_for_player(player, season_id)?;
-			Ok(())
+			if &leader_owner != player {
+				Self::use_rental_forge(leader_id);
+			}
+
+			let forged_leader =
+				Avatars::<T>::get(leader_id).map(|(_, avatar)| avatar).unwrap_or(leader);
+			Self::update_quest_progress(player, season_id, QuestAction::Forge(&forged_leader));
+			Ok(weight)
+		}
+
+		/// Weight of a second generation forge of `forge_type` with `sacrifices` avatars.
+		pub(crate) fn forge_weight(forge_type: &ForgeType, sacrifices: u32) -> Weight {
+			match forge_type {
+				ForgeType::None => T::WeightInfo::forge_none(sacrifices),
+				ForgeType::Stack => T::WeightInfo::forge_stack(sacrifices),
+				ForgeType::Tinker => T::WeightInfo::forge_tinker(sacrifices),
+				ForgeType::Build => T::WeightInfo::forge_build(),
+				ForgeType::Assemble => T::WeightInfo::forge_assemble(sacrifices),
+				ForgeType::Breed => T::WeightInfo::forge_breed(sacrifices),
+				ForgeType::Equip => T::WeightInfo::forge_equip(sacrifices),
+				ForgeType::Mate => T::WeightInfo::forge_mate(sacrifices),
+				ForgeType::Feed => T::WeightInfo::forge_feed(sacrifices),
+				ForgeType::Glimmer => T::WeightInfo::forge_glimmer(sacrifices),
+				ForgeType::Spark => T::WeightInfo::forge_spark(sacrifices),
+				ForgeType::Statue => T::WeightInfo::forge_statue(sacrifices),
+				ForgeType::Flask => T::WeightInfo::forge_flask(sacrifices),
+			}
+		}
+
+		/// Upper bound of the weight of any forge, charged before the forge type is known.
+		pub(crate) fn max_forge_weight() -> Weight {
+			[
+				ForgeType::None,
+				ForgeType::Stack,
+				ForgeType::Tinker,
+				ForgeType::Build,
+				ForgeType::Assemble,
+				ForgeType::Breed,
+				ForgeType::Equip,
+				ForgeType::Mate,
+				ForgeType::Feed,
+				ForgeType::Glimmer,
+				ForgeType::Spark,
+				ForgeType::Statue,
+				ForgeType::Flask,
+			]
+			.iter()
+			.map(|forge_type| Self::forge_weight(forge_type, MAX_SACRIFICE as u32))
+			.fold(T::WeightInfo::forge(MaxAvatarsPerPlayer::get()), Weight::max)
 		}
 
 		fn do_transfer_avatar(
@@ -1263,6 +2113,7 @@ pub mod pallet {
 			season_id: &SeasonId,
 			avatar_id: &AvatarIdOf<T>,
 		) -> DispatchResult {
+			Self::ensure_not_archived(season_id)?;
 			let mut from_avatar_ids = Owners::<T>::get(from, season_id);
 			from_avatar_ids.retain(|existing_avatar_id| existing_avatar_id != avatar_id);
 
@@ -1278,6 +2129,14 @@ pub mod pallet {
 
 			Owners::<T>::mutate(from, season_id, |avatar_ids| *avatar_ids = from_avatar_ids);
 			Owners::<T>::mutate(to, season_id, |avatar_ids| *avatar_ids = to_avatar_ids);
+			SeasonPlayers::<T>::insert(season_id, to, ());
+			RentalOffers::<T>::remove(avatar_id);
+
+			// Locked avatars keep their deposit with the player that locked them.
+			if to != &Self::technical_account_id() {
+				Self::transfer_avatar_deposit(avatar_id, to)?;
+			}
+
 			Avatars::<T>::try_mutate(avatar_id, |maybe_avatar| -> DispatchResult {
 				let (from_owner, _) = maybe_avatar.as_mut().ok_or(Error::<T>::UnknownAvatar)?;
 				*from_owner = to.clone();
@@ -1312,16 +2171,55 @@ pub mod pallet {
 		) -> Result<Avatar, DispatchError> {
 			let (owner, avatar) = Self::avatars(avatar_id)?;
 			ensure!(player == &owner, Error::<T>::Ownership);
+			Self::ensure_not_rented(avatar_id)?;
 			Ok(avatar)
 		}
 
+		/// Check that the avatar is not rented out, treating expired rentals as over.
+		fn ensure_not_rented(avatar_id: &AvatarIdOf<T>) -> DispatchResult {
+			if let Some(rental) = Rentals::<T>::get(avatar_id) {
+				let now = <frame_system::Pallet<T>>::block_number();
+				ensure!(rental.has_expired(now), Error::<T>::AvatarRented);
+			}
+			Ok(())
+		}
+
+		/// Check that the avatar can be offered or handed over for rent.
+		fn ensure_for_rental(avatar_id: &AvatarIdOf<T>) -> DispatchResult {
+			ensure!(Self::ensure_for_trade(avatar_id).is_err(), Error::<T>::AvatarInTrade);
+			Self::ensure_unlocked(avatar_id)?;
+			Self::ensure_unprepared(avatar_id)?;
+			Ok(())
+		}
+
+		/// Check that the player can use the avatar as a forge leader, either as its owner or as
+		/// its renter. Returns the owner of the avatar.
+		fn ensure_forge_leader(
+			player: &T::AccountId,
+			leader_id: &AvatarIdOf<T>,
+		) -> Result<(T::AccountId, Avatar), DispatchError> {
+			let (owner, avatar) = Self::avatars(leader_id)?;
+			if player != &owner {
+				let rental = Rentals::<T>::get(leader_id).ok_or(Error::<T>::Ownership)?;
+				let now = <frame_system::Pallet<T>>::block_number();
+				ensure!(
+					&rental.renter == player && !rental.has_expired(now),
+					Error::<T>::Ownership
+				);
+			} else {
+				Self::ensure_not_rented(leader_id)?;
+			}
+			Ok((owner, avatar))
+		}
+
 		pub(crate) fn ensure_for_mint(
 			player: &T::AccountId,
 			season_id: &SeasonId,
-			mint_option: &MintOption,
+			mint_option: &MintOptionOf<T>,
 		) -> DispatchResult {
 			let GlobalConfig { mint, .. } = GlobalConfigs::<T>::get();
 			ensure!(mint.open, Error::<T>::MintClosed);
+			ensure!(!OngoingMigration::<T>::exists(), Error::<T>::MigrationInProgress);
 
 			let player_season_config = PlayerSeasonConfigs::<T>::get(player, season_id);
 
@@ -1340,16 +2238,31 @@ pub mod pallet {
 
 			let mint_count = mint_option.pack_size.as_mint_count();
 			let (_, Season { fee, .. }) = Self::current_season_with_id()?;
-			match mint_option.payment {
+			let deposit = fee.avatar_deposit.saturating_mul(mint_count.unique_saturated_into());
+			match &mint_option.payment {
 				MintPayment::Normal => {
 					let fee = fee.mint.fee_for(&mint_option.pack_size);
 					T::Currency::free_balance(player)
+						.checked_sub(&fee.saturating_add(deposit))
+						.ok_or(Error::<T>::InsufficientBalance)?;
+				},
+				MintPayment::Asset(asset_id) => {
+					let fee = fee
+						.mint_asset_fee_for(asset_id, &mint_option.pack_size)
+						.ok_or(Error::<T>::UnsupportedMintAsset)?;
+					T::Assets::balance(asset_id, player)
 						.checked_sub(&fee)
 						.ok_or(Error::<T>::InsufficientBalance)?;
+					T::Currency::free_balance(player)
+						.checked_sub(&deposit)
+						.ok_or(Error::<T>::InsufficientBalance)?;
 				},
 				MintPayment::Free => {
 					let fee = mint_count.saturating_mul(mint.free_mint_fee_multiplier);
 					free_mints.checked_sub(fee).ok_or(Error::<T>::InsufficientFreeMints)?;
+					T::Currency::free_balance(player)
+						.checked_sub(&deposit)
+						.ok_or(Error::<T>::InsufficientBalance)?;
 				},
 			};
 
@@ -1359,16 +2272,126 @@ pub mod pallet {
 			Ok(())
 		}
 
+		pub(crate) fn do_dissolve(
+			player: &T::AccountId,
+			avatar_ids: Vec<AvatarIdOf<T>>,
+			target: DissolveTarget<AvatarIdOf<T>>,
+		) -> DispatchResult {
+			let dissolved = Self::ensure_for_dissolve(player, &avatar_ids, &target)?;
+
+			let mut total_souls = SoulCount::zero();
+			for (avatar_id, season_id, souls) in dissolved {
+				Self::remove_avatar_from(player, &season_id, &avatar_id);
+				if target == DissolveTarget::Balance {
+					SoulPoints::<T>::mutate(player, season_id, |balance| {
+						balance.saturating_accrue(souls)
+					});
+				}
+				total_souls.saturating_accrue(souls);
+			}
+
+			if let DissolveTarget::Leader(leader_id) = &target {
+				Avatars::<T>::mutate(leader_id, |maybe_avatar| {
+					if let Some((_, leader)) = maybe_avatar {
+						leader.souls.saturating_accrue(total_souls);
+					}
+				});
+			}
+
+			Self::deposit_event(Event::AvatarsDissolved { avatar_ids, target, souls: total_souls });
+			Ok(())
+		}
+
+		fn update_quest_progress(player: &T::AccountId, season_id: SeasonId, action: QuestAction) {
+			for (quest_id, Quest { condition, .. }) in Quests::<T>::iter_prefix(season_id) {
+				let progress = condition.progress_for(&action);
+				if progress.is_zero() {
+					continue
+				}
+
+				let is_completed =
+					PlayerQuests::<T>::mutate((player, season_id, quest_id), |quest_progress| {
+						let was_complete = quest_progress.is_complete(&condition);
+						quest_progress.progress.saturating_accrue(progress);
+						!was_complete && quest_progress.is_complete(&condition)
+					});
+				if is_completed {
+					Self::deposit_event(Event::QuestCompleted {
+						player: player.clone(),
+						season_id,
+						quest_id,
+					});
+				}
+			}
+		}
+
+		fn ensure_for_dissolve(
+			player: &T::AccountId,
+			avatar_ids: &[AvatarIdOf<T>],
+			target: &DissolveTarget<AvatarIdOf<T>>,
+		) -> Result<Vec<(AvatarIdOf<T>, SeasonId, SoulCount)>, DispatchError> {
+			ensure!(!avatar_ids.is_empty(), Error::<T>::NoAvatarsToDissolve);
+
+			let mut unique_ids = avatar_ids.to_vec();
+			unique_ids.sort();
+			unique_ids.dedup();
+			ensure!(unique_ids.len() == avatar_ids.len(), Error::<T>::DuplicatedAvatarToDissolve);
+
+			let leader_season_id = match target {
+				DissolveTarget::Leader(leader_id) => {
+					ensure!(!avatar_ids.contains(leader_id), Error::<T>::LeaderSacrificed);
+					let leader = Self::ensure_ownership(player, leader_id)?;
+					ensure!(Self::ensure_for_trade(leader_id).is_err(), Error::<T>::AvatarInTrade);
+					Self::ensure_unlocked(leader_id)?;
+					Self::ensure_unprepared(leader_id)?;
+					Some(leader.season_id)
+				},
+				DissolveTarget::Balance => None,
+			};
+
+			avatar_ids
+				.iter()
+				.map(|avatar_id| {
+					let avatar = Self::ensure_ownership(player, avatar_id)?;
+					if let Some(season_id) = leader_season_id {
+						ensure!(avatar.season_id == season_id, Error::<T>::IncorrectAvatarSeason);
+					}
+					ensure!(Self::ensure_for_trade(avatar_id).is_err(), Error::<T>::AvatarInTrade);
+					Self::ensure_unlocked(avatar_id)?;
+					Self::ensure_unprepared(avatar_id)?;
+
+					let season =
+						Seasons::<T>::get(avatar.season_id).ok_or(Error::<T>::UnknownSeason)?;
+					let souls = season
+						.dissolve_souls_of(&avatar)
+						.ok_or(Error::<T>::AvatarCannotBeDissolved)?;
+					Ok((*avatar_id, avatar.season_id, souls))
+				})
+				.collect()
+		}
+
 		fn ensure_for_forge(
 			player: &T::AccountId,
 			leader_id: &AvatarIdOf<T>,
 			sacrifice_ids: Vec<AvatarIdOf<T>>,
-		) -> Result<(Avatar, Vec<AvatarIdOf<T>>, Vec<Avatar>, SeasonId, SeasonOf<T>), DispatchError>
-		{
+		) -> Result<
+			(T::AccountId, Avatar, Vec<AvatarIdOf<T>>, Vec<Avatar>, SeasonId, SeasonOf<T>),
+			DispatchError,
+		> {
 			let sacrifice_count = sacrifice_ids.len() as u8;
 
-			let leader = Self::ensure_ownership(player, leader_id)?;
+			let (leader_owner, leader) = Self::ensure_forge_leader(player, leader_id)?;
 			let (season_id, season) = Self::season_with_id_for(&leader)?;
+			Self::ensure_not_archived(&season_id)?;
+
+			let last_block = PlayerSeasonConfigs::<T>::get(player, season_id).stats.forge.last;
+			if !last_block.is_zero() {
+				let current_block = <frame_system::Pallet<T>>::block_number();
+				ensure!(
+					current_block >= last_block.saturating_add(season.forge_cooldown),
+					Error::<T>::ForgeCooldown
+				);
+			}
 
 			ensure!(sacrifice_count >= season.min_sacrifices, Error::<T>::TooFewSacrifices);
 			ensure!(sacrifice_count <= season.max_sacrifices, Error::<T>::TooManySacrifices);
@@ -1409,11 +2432,105 @@ pub mod pallet {
 				})
 				.collect::<Result<Vec<Avatar>, DispatchError>>()?;
 
-			Ok((leader, deduplicated_sacrifice_ids, sacrifices, season_id, season))
+			Ok((leader_owner, leader, deduplicated_sacrifice_ids, sacrifices, season_id, season))
+		}
+
+		fn track_mint_limits(
+			player: &T::AccountId,
+			season_id: &SeasonId,
+			season: &SeasonOf<T>,
+			mint_option: &MintOptionOf<T>,
+		) -> DispatchResult {
+			let mint_count = mint_option.pack_size.as_mint_count();
+			let now = <frame_system::Pallet<T>>::block_number();
+			let GlobalConfig { mint, .. } = GlobalConfigs::<T>::get();
+			let limits = &season.mint_limits;
+
+			if limits.early_requires_identity && CurrentSeasonStatus::<T>::get().early {
+				ensure!(T::IdentityVerifier::is_verified(player), Error::<T>::IdentityNotVerified);
+			}
+
+			if !mint.max_per_block.is_zero() {
+				BlockMints::<T>::try_mutate(|(block, minted)| -> DispatchResult {
+					if *block != now {
+						*block = now;
+						*minted = 0;
+					}
+					*minted = minted.saturating_add(mint_count);
+					ensure!(*minted <= mint.max_per_block, Error::<T>::BlockMintLimitReached);
+					Ok(())
+				})?;
+			}
+
+			if let Some(period) = limits.period_of(season.early_start, now) {
+				if !limits.max_per_period.is_zero() {
+					PeriodMints::<T>::try_mutate(
+						season_id,
+						|(current, minted)| -> DispatchResult {
+							if *current != period {
+								*current = period;
+								*minted = 0;
+							}
+							*minted = minted.saturating_add(mint_count as Stat);
+							ensure!(
+								*minted <= limits.max_per_period,
+								Error::<T>::PeriodMintLimitReached
+							);
+							Ok(())
+						},
+					)?;
+				}
+				if !limits.max_per_account.is_zero() {
+					AccountPeriodMints::<T>::try_mutate(
+						player,
+						season_id,
+						|(current, minted)| -> DispatchResult {
+							if *current != period {
+								*current = period;
+								*minted = 0;
+							}
+							*minted = minted.saturating_add(mint_count);
+							ensure!(
+								*minted <= limits.max_per_account,
+								Error::<T>::AccountMintLimitReached
+							);
+							Ok(())
+						},
+					)?;
+				}
+			}
+			Ok(())
+		}
+
+		fn charge_forge_fee(
+			player: &T::AccountId,
+			season_id: &SeasonId,
+			season: &SeasonOf<T>,
+			forge_type: &ForgeType,
+		) -> DispatchResult {
+			match season.fee.forge_fee_for(forge_type) {
+				Some(ForgeFee::Currency(fee)) => {
+					T::Currency::withdraw(player, *fee, WithdrawReasons::FEE, AllowDeath)
+						.map_err(|_| Error::<T>::InsufficientBalance)?;
+					Self::deposit_into_treasury(season_id, *fee);
+				},
+				Some(ForgeFee::FreeMints(fee)) => {
+					PlayerConfigs::<T>::try_mutate(player, |config| -> DispatchResult {
+						config.free_mints = config
+							.free_mints
+							.checked_sub(*fee)
+							.ok_or(Error::<T>::InsufficientFreeMints)?;
+						Ok(())
+					})?;
+				},
+				None => {},
+			}
+			Ok(())
 		}
 
 		fn process_leader_forge_output(
 			player: &AccountIdFor<T>,
+			owner: &AccountIdFor<T>,
 			season_id: &SeasonId,
 			season: &SeasonOf<T>,
 			input_leader: ForgeItem<T>,
@@ -1434,15 +2551,17 @@ pub mod pallet {
 						});
 					}
 
-					Avatars::<T>::insert(leader_id, (player, leader));
+					Avatars::<T>::insert(leader_id, (owner, leader));
 
 					// TODO: May change in the future
 					Self::deposit_event(Event::AvatarsForged {
 						avatar_ids: vec![(leader_id, upgraded_components)],
 					});
 				},
-				LeaderForgeOutput::Consumed(leader_id) =>
-					Self::remove_avatar_from(player, season_id, &leader_id),
+				LeaderForgeOutput::Consumed(leader_id) => {
+					ensure!(player == owner, Error::<T>::RentedAvatarConsumed);
+					Self::remove_avatar_from(player, season_id, &leader_id)
+				},
 			}
 
 			Ok(())
@@ -1451,6 +2570,7 @@ pub mod pallet {
 		fn process_other_forge_outputs(
 			player: &AccountIdFor<T>,
 			season_id: &SeasonId,
+			season: &SeasonOf<T>,
 			other_outputs: Vec<ForgeOutput<T>>,
 		) -> DispatchResult {
 			let mut minted_avatars: Vec<AvatarIdOf<T>> = Vec::with_capacity(0);
@@ -1466,6 +2586,11 @@ pub mod pallet {
 					ForgeOutput::Minted(avatar) => {
 						let avatar_id = Self::random_hash(b"create_avatar", player);
 						Self::try_add_avatar_to(player, season_id, avatar_id, avatar)?;
+						Self::reserve_avatar_deposit(
+							player,
+							&avatar_id,
+							season.fee.avatar_deposit,
+						)?;
 						minted_avatars.push(avatar_id);
 					},
 					ForgeOutput::Consumed(avatar_id) =>
@@ -1528,15 +2653,72 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Count a forge against the rental of a leader, ending the rental once it runs out.
+		fn use_rental_forge(leader_id: &AvatarIdOf<T>) {
+			Rentals::<T>::mutate_exists(leader_id, |maybe_rental| {
+				if let Some(rental) = maybe_rental {
+					if let Some(forges_left) = rental.forges_left.as_mut() {
+						forges_left.saturating_dec();
+						if forges_left.is_zero() {
+							*maybe_rental = None;
+							Self::deposit_event(Event::RentalEnded { avatar_id: *leader_id });
+						}
+					}
+				}
+			});
+		}
+
 		fn remove_avatar_from(
 			player: &AccountIdFor<T>,
 			season_id: &SeasonId,
 			avatar_id: &AvatarIdOf<T>,
 		) {
 			Avatars::<T>::remove(avatar_id);
-			Owners::<T>::mutate(player, season_id, |avatars| {
-				avatars.retain(|id| id != avatar_id);
+			RentalOffers::<T>::remove(avatar_id);
+			// Avatars of archived seasons have no ownership list left to remove them from.
+			Owners::<T>::mutate_exists(player, season_id, |maybe_avatars| {
+				if let Some(avatars) = maybe_avatars {
+					avatars.retain(|id| id != avatar_id);
+				}
 			});
+			Self::release_avatar_deposit(avatar_id);
+		}
+
+		fn reserve_avatar_deposit(
+			player: &AccountIdFor<T>,
+			avatar_id: &AvatarIdOf<T>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			if deposit.is_zero() {
+				return Ok(())
+			}
+			T::Currency::reserve(player, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			AvatarDeposits::<T>::insert(avatar_id, (player, deposit));
+			Ok(())
+		}
+
+		fn release_avatar_deposit(avatar_id: &AvatarIdOf<T>) {
+			if let Some((depositor, deposit)) = AvatarDeposits::<T>::take(avatar_id) {
+				let _ = T::Currency::unreserve(&depositor, deposit);
+			}
+		}
+
+		fn transfer_avatar_deposit(
+			avatar_id: &AvatarIdOf<T>,
+			to: &AccountIdFor<T>,
+		) -> DispatchResult {
+			AvatarDeposits::<T>::try_mutate(avatar_id, |maybe_deposit| -> DispatchResult {
+				match maybe_deposit {
+					Some((depositor, deposit)) if depositor != to => {
+						T::Currency::reserve(to, *deposit)
+							.map_err(|_| Error::<T>::InsufficientBalance)?;
+						let _ = T::Currency::unreserve(depositor, *deposit);
+						*depositor = to.clone();
+						Ok(())
+					},
+					_ => Ok(()),
+				}
+			})
 		}
 
 		fn ensure_for_trade(
@@ -1559,12 +2741,125 @@ pub mod pallet {
 		}
 
 		fn ensure_tradable(avatar: &Avatar) -> DispatchResult {
+			Self::ensure_not_archived(&avatar.season_id)?;
 			let season = Seasons::<T>::get(avatar.season_id)
 				.ok_or::<DispatchError>(Error::<T>::UnknownSeason.into())?;
 			ensure!(season.is_tradable(avatar), Error::<T>::AvatarCannotBeTraded);
 			Ok(())
 		}
 
+		/// Avatars are frozen as soon as their season's archival starts, rather than once it
+		/// completes, so that entries pruned by earlier stages cannot be recreated.
+		fn ensure_not_archived(season_id: &SeasonId) -> DispatchResult {
+			ensure!(!SeasonArchives::<T>::contains_key(season_id), Error::<T>::SeasonArchived);
+			Ok(())
+		}
+
+		/// Weight of pruning a single entry while archiving a season.
+		fn archive_item_weight() -> Weight {
+			T::WeightInfo::archive_season(1).saturating_sub(T::WeightInfo::archive_season(0))
+		}
+
+		/// Archive the oldest finished season whose grace period has passed, within `limit`.
+		fn archive_expired_season(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let mut meter = WeightMeter::from_limit(limit);
+			if meter.try_consume(T::DbWeight::get().reads_writes(4, 1)).is_err() ||
+				OngoingMigration::<T>::exists()
+			{
+				return Weight::zero()
+			}
+
+			let season_id = NextSeasonToArchive::<T>::get().max(1);
+			if season_id >= CurrentSeasonStatus::<T>::get().season_id {
+				return meter.consumed()
+			}
+
+			let is_archived =
+				SeasonArchives::<T>::get(season_id).map_or(false, |archive| archive.is_archived());
+			let archived = match Seasons::<T>::get(season_id) {
+				// Seasons archived by the organizer are skipped without waiting for their grace.
+				Some(_) if is_archived => true,
+				Some(season) if now < season.end.saturating_add(T::ArchiveGracePeriod::get()) =>
+					return meter.consumed(),
+				Some(_) =>
+					Self::do_archive_season(season_id, Self::archive_item_weight(), &mut meter)
+						.is_archived(),
+				None => true,
+			};
+			if archived {
+				NextSeasonToArchive::<T>::put(season_id.saturating_add(1));
+			}
+			meter.consumed()
+		}
+
+		/// Prune entries of a season's archive stages, one per `item_weight` available in `meter`.
+		fn do_archive_season(
+			season_id: SeasonId,
+			item_weight: Weight,
+			meter: &mut WeightMeter,
+		) -> SeasonArchiveOf<T> {
+			let mut archive = SeasonArchives::<T>::get(season_id).unwrap_or_default();
+			if archive.is_archived() {
+				return archive
+			}
+
+			while !archive.is_archived() && meter.try_consume(item_weight).is_ok() {
+				if !Self::prune_season_entry(season_id, &mut archive) {
+					archive.stage = archive.stage.next();
+				}
+			}
+
+			SeasonArchives::<T>::insert(season_id, &archive);
+			if archive.is_archived() {
+				Self::deposit_event(Event::SeasonArchived {
+					season_id,
+					summary: archive.summary.clone(),
+				});
+			}
+			archive
+		}
+
+		/// Prune the next entry of the current archive stage, returning `false` once there are no
+		/// entries left in it.
+		fn prune_season_entry(season_id: SeasonId, archive: &mut SeasonArchiveOf<T>) -> bool {
+			match archive.stage {
+				ArchiveStage::SeasonStats => SeasonStats::<T>::drain_prefix(season_id)
+					.next()
+					.map(|(player, info)| {
+						SeasonPlayers::<T>::insert(season_id, &player, ());
+						let summary = &mut archive.summary;
+						summary.players.saturating_inc();
+						summary.minted.saturating_accrue(info.minted);
+						summary.forged.saturating_accrue(info.forged);
+						summary.leaderboard =
+							(&summary.leaderboard, player, info).using_encoded(T::Hashing::hash);
+					})
+					.is_some(),
+				ArchiveStage::Trade => Trade::<T>::drain_prefix(season_id).next().is_some(),
+				ArchiveStage::Players => SeasonPlayers::<T>::iter_key_prefix(season_id)
+					.next()
+					.map(|player| Self::prune_player_entry(season_id, &player))
+					.is_some(),
+				ArchiveStage::Archived => false,
+			}
+		}
+
+		/// Release the deposit of one of the player's avatars in the season, pruning the player's
+		/// avatars and config once there are none left.
+		fn prune_player_entry(season_id: SeasonId, player: &T::AccountId) {
+			let mut avatar_ids = Owners::<T>::get(player, season_id);
+			if let Some(avatar_id) = avatar_ids.pop() {
+				Self::release_avatar_deposit(&avatar_id);
+			}
+			if avatar_ids.is_empty() {
+				Owners::<T>::remove(player, season_id);
+				PlayerSeasonConfigs::<T>::remove(player, season_id);
+				SeasonPlayers::<T>::remove(season_id, player);
+			} else {
+				Owners::<T>::insert(player, season_id, avatar_ids);
+			}
+		}
+
 		fn start_season(
 			weight: &mut Weight,
 			block_number: BlockNumberFor<T>,
@@ -14,12 +14,14 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+pub mod stepped;
 pub mod v6;
+pub mod v7;
 
 use super::*;
 use frame_support::traits::OnRuntimeUpgrade;
 
 // The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
 
 const LOG_TARGET: &str = "runtime::ajuna-awesome-avatars";
@@ -0,0 +1,195 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Migrations spanning multiple blocks.
+//!
+//! Changes to avatars or their owners touch too many entries to be migrated within a single
+//! block. A [`SteppedMigration`] instead migrates a bounded batch of entries per step, resuming
+//! from the cursor returned by its previous step. Once started by [`StartSteppedMigration`] as part
+//! of a runtime upgrade, steps of `Config::SteppedMigration` are executed in `on_idle` for as long
+//! as blocks have weight left. Minting and forging are paused until the migration completes.
+//! A failed migration stays paused until the organizer retries or aborts it.
+
+use super::*;
+use frame_support::{traits::OnRuntimeUpgrade, weights::WeightMeter};
+
+/// Identifier of a stepped migration.
+pub type MigrationId = [u8; 16];
+
+/// Opaque position a stepped migration resumes from.
+pub type MigrationCursor = BoundedVec<u8, ConstU32<1_024>>;
+
+/// Progress of the ongoing stepped migration.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct MigrationProgress<BlockNumber> {
+	pub id: MigrationId,
+	/// Position to resume from, `None` until the first step is executed.
+	pub cursor: Option<MigrationCursor>,
+	/// Number of steps executed so far.
+	pub steps: u32,
+	pub started_at: BlockNumber,
+	/// Set once a step fails, after which no further steps are executed.
+	pub failed: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SteppedMigrationError {
+	/// Not enough weight left for the step, which is retried in a later block.
+	InsufficientWeight,
+	/// The migration cannot continue.
+	Failed,
+}
+
+pub trait SteppedMigration {
+	fn id() -> MigrationId;
+
+	/// Migrate the entries following `cursor`, consuming the weight used from `meter`.
+	///
+	/// Returns the cursor to resume from, or `None` once there is nothing left to migrate.
+	fn step(
+		cursor: Option<MigrationCursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<MigrationCursor>, SteppedMigrationError>;
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		Ok(())
+	}
+}
+
+impl SteppedMigration for () {
+	fn id() -> MigrationId {
+		Default::default()
+	}
+
+	fn step(
+		_cursor: Option<MigrationCursor>,
+		_meter: &mut WeightMeter,
+	) -> Result<Option<MigrationCursor>, SteppedMigrationError> {
+		Ok(None)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Execute steps of the ongoing stepped migration within `limit`, returning the weight used.
+	pub(crate) fn progress_migration(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::from_limit(limit);
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+
+		let mut progress = match OngoingMigration::<T>::get() {
+			Some(progress) if !progress.failed => progress,
+			_ => return T::DbWeight::get().reads(1),
+		};
+
+		loop {
+			match T::SteppedMigration::step(progress.cursor.clone(), &mut meter) {
+				Ok(Some(cursor)) => {
+					progress.cursor = Some(cursor);
+					progress.steps.saturating_inc();
+				},
+				Ok(None) => {
+					progress.steps.saturating_inc();
+					OngoingMigration::<T>::kill();
+					log::info!(
+						target: LOG_TARGET,
+						"Stepped migration {:?} completed in {} steps",
+						progress.id,
+						progress.steps
+					);
+					Self::deposit_event(Event::MigrationCompleted {
+						id: progress.id,
+						steps: progress.steps,
+					});
+					return meter.consumed()
+				},
+				Err(SteppedMigrationError::InsufficientWeight) => break,
+				Err(SteppedMigrationError::Failed) => {
+					progress.failed = true;
+					log::error!(
+						target: LOG_TARGET,
+						"Stepped migration {:?} failed after {} steps",
+						progress.id,
+						progress.steps
+					);
+					Self::deposit_event(Event::MigrationFailed {
+						id: progress.id,
+						steps: progress.steps,
+					});
+					break
+				},
+			}
+		}
+
+		OngoingMigration::<T>::put(progress);
+		meter.consumed()
+	}
+}
+
+/// Start `Config::SteppedMigration`, to be progressed in the following blocks.
+///
+/// With `try-runtime`, `post_upgrade` runs the whole migration at once to check its outcome.
+pub struct StartSteppedMigration<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for StartSteppedMigration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let id = T::SteppedMigration::id();
+		if let Some(progress) = OngoingMigration::<T>::get() {
+			log::warn!(
+				target: LOG_TARGET,
+				"Stepped migration {:?} not started, {:?} is still in progress",
+				id,
+				progress.id
+			);
+			return T::DbWeight::get().reads(1)
+		}
+
+		OngoingMigration::<T>::put(MigrationProgress {
+			id,
+			cursor: None,
+			steps: 0,
+			started_at: <frame_system::Pallet<T>>::block_number(),
+			failed: false,
+		});
+		log::info!(target: LOG_TARGET, "Started stepped migration {:?}", id);
+		Pallet::<T>::deposit_event(Event::MigrationStarted { id });
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		ensure!(
+			OngoingMigration::<T>::get().is_none(),
+			"A stepped migration is already in progress"
+		);
+		T::SteppedMigration::pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		let progress = OngoingMigration::<T>::get().ok_or("Stepped migration was not started")?;
+		ensure!(progress.id == T::SteppedMigration::id(), "Unexpected stepped migration started");
+
+		Pallet::<T>::progress_migration(Weight::MAX);
+		ensure!(OngoingMigration::<T>::get().is_none(), "Stepped migration did not complete");
+		T::SteppedMigration::post_upgrade(state)
+	}
+}
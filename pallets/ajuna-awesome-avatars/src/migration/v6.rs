@@ -32,6 +32,7 @@ where
 			open: self.open,
 			cooldown: self.cooldown,
 			free_mint_fee_multiplier: self.free_mint_fee_multiplier,
+			max_per_block: 0,
 		}
 	}
 }
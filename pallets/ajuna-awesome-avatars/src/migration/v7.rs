@@ -0,0 +1,229 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[derive(Decode)]
+pub struct FeeV6<Balance> {
+	pub mint: MintFees<Balance>,
+	pub transfer_avatar: Balance,
+	pub buy_minimum: Balance,
+	pub buy_percent: u8,
+	pub upgrade_storage: Balance,
+	pub prepare_avatar: Balance,
+}
+
+impl<Balance> FeeV6<Balance>
+where
+	Balance: Zero,
+{
+	fn migrate_to_v7<AssetId>(self) -> Fee<Balance, AssetId> {
+		Fee {
+			mint: self.mint,
+			mint_assets: Default::default(),
+			transfer_avatar: self.transfer_avatar,
+			buy_minimum: self.buy_minimum,
+			buy_percent: self.buy_percent,
+			upgrade_storage: self.upgrade_storage,
+			prepare_avatar: self.prepare_avatar,
+			avatar_deposit: Zero::zero(),
+			forge: Default::default(),
+		}
+	}
+}
+
+#[derive(Decode)]
+pub struct MintConfigV6<BlockNumber> {
+	pub open: bool,
+	pub cooldown: BlockNumber,
+	pub free_mint_fee_multiplier: MintCount,
+}
+
+impl<BlockNumber> MintConfigV6<BlockNumber> {
+	fn migrate_to_v7(self) -> MintConfig<BlockNumber> {
+		MintConfig {
+			open: self.open,
+			cooldown: self.cooldown,
+			free_mint_fee_multiplier: self.free_mint_fee_multiplier,
+			max_per_block: Zero::zero(),
+		}
+	}
+}
+
+#[derive(Decode)]
+pub struct GlobalConfigV6<BlockNumber> {
+	pub mint: MintConfigV6<BlockNumber>,
+	pub forge: ForgeConfig,
+	pub transfer: TransferConfig,
+	pub freemint_transfer: FreemintTransferConfig,
+	pub trade: TradeConfig,
+	pub nft_transfer: NftTransferConfig,
+}
+
+impl<BlockNumber> GlobalConfigV6<BlockNumber> {
+	fn migrate_to_v7(self) -> GlobalConfig<BlockNumber> {
+		GlobalConfig {
+			mint: self.mint.migrate_to_v7(),
+			forge: self.forge,
+			transfer: self.transfer,
+			freemint_transfer: self.freemint_transfer,
+			trade: self.trade,
+			nft_transfer: self.nft_transfer,
+		}
+	}
+}
+
+#[derive(Decode)]
+pub struct SeasonV6<BlockNumber, Balance> {
+	pub name: BoundedVec<u8, ConstU32<100>>,
+	pub description: BoundedVec<u8, ConstU32<1_000>>,
+	pub early_start: BlockNumber,
+	pub start: BlockNumber,
+	pub end: BlockNumber,
+	pub max_tier_forges: u32,
+	pub max_variations: u8,
+	pub max_components: u8,
+	pub min_sacrifices: SacrificeCount,
+	pub max_sacrifices: SacrificeCount,
+	pub tiers: BoundedVec<RarityTier, ConstU32<6>>,
+	pub single_mint_probs: BoundedVec<RarityPercent, ConstU32<5>>,
+	pub batch_mint_probs: BoundedVec<RarityPercent, ConstU32<5>>,
+	pub base_prob: RarityPercent,
+	pub per_period: BlockNumber,
+	pub periods: u16,
+	pub trade_filters: BoundedVec<TradeFilter, ConstU32<100>>,
+	pub fee: FeeV6<Balance>,
+	pub mint_logic: LogicGeneration,
+	pub forge_logic: LogicGeneration,
+}
+
+impl<BlockNumber, Balance> SeasonV6<BlockNumber, Balance>
+where
+	BlockNumber: Zero,
+	Balance: Zero,
+{
+	fn migrate_to_v7<AssetId>(self) -> Season<BlockNumber, Balance, AssetId> {
+		Season {
+			name: self.name,
+			description: self.description,
+			early_start: self.early_start,
+			start: self.start,
+			end: self.end,
+			max_tier_forges: self.max_tier_forges,
+			max_variations: self.max_variations,
+			max_components: self.max_components,
+			min_sacrifices: self.min_sacrifices,
+			max_sacrifices: self.max_sacrifices,
+			tiers: self.tiers,
+			single_mint_probs: self.single_mint_probs,
+			batch_mint_probs: self.batch_mint_probs,
+			base_prob: self.base_prob,
+			per_period: self.per_period,
+			periods: self.periods,
+			trade_filters: self.trade_filters,
+			dissolve_souls: Default::default(),
+			forge_cooldown: Zero::zero(),
+			mint_limits: Default::default(),
+			fee: self.fee.migrate_to_v7(),
+			mint_logic: self.mint_logic,
+			forge_logic: self.forge_logic,
+		}
+	}
+}
+
+/// Existing avatars are grandfathered: they have no entry in `AvatarDeposits` and therefore hold
+/// no deposit, while seasons are migrated with storage deposits, avatar dissolution, forge fees,
+/// forge cooldowns, mint limits and asset mint payments disabled, as is the global per-block mint
+/// limit.
+pub struct MigrateToV7<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current_version = Pallet::<T>::current_storage_version();
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		if onchain_version == 6 && current_version == 7 {
+			let _ = GlobalConfigs::<T>::translate::<GlobalConfigV6<BlockNumberFor<T>>, _>(
+				|old_config| {
+					log::info!(target: LOG_TARGET, "Updated GlobalConfig from v6 to v7");
+					old_config.map(|old| old.migrate_to_v7())
+				},
+			);
+
+			let mut translated = 0_u64;
+			Seasons::<T>::translate::<SeasonV6<BlockNumberFor<T>, BalanceOf<T>>, _>(
+				|season_id, old_season| {
+					translated.saturating_inc();
+					log::info!(target: LOG_TARGET, "Updated Season {} from v6 to v7", season_id);
+					Some(old_season.migrate_to_v7())
+				},
+			);
+
+			current_version.put::<Pallet<T>>();
+			log::info!(target: LOG_TARGET, "Upgraded storage to version {:?}", current_version);
+			T::DbWeight::get().reads_writes(translated + 2, translated + 2)
+		} else {
+			log::info!(
+				target: LOG_TARGET,
+				"Migration for v7 did not execute."
+			);
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		Ok((Seasons::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		let current_version = Pallet::<T>::current_storage_version();
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		ensure!(current_version == onchain_version, "Storage version mismatch after v7 upgrade");
+
+		let prev_season_count: u32 = Decode::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode season count from pre_upgrade")?;
+		let season_count = Seasons::<T>::iter_values().count() as u32;
+		ensure!(prev_season_count == season_count, "Seasons were lost during v7 upgrade");
+		ensure!(
+			Seasons::<T>::iter_values().all(|season| season.fee.avatar_deposit.is_zero()),
+			"Migrated seasons must not require avatar deposits"
+		);
+		ensure!(
+			Seasons::<T>::iter_values().all(|season| season.dissolve_souls.is_empty()),
+			"Migrated seasons must not allow dissolving avatars"
+		);
+		ensure!(
+			Seasons::<T>::iter_values()
+				.all(|season| season.fee.forge.is_empty() && season.forge_cooldown.is_zero()),
+			"Migrated seasons must not restrict forging"
+		);
+		ensure!(
+			Seasons::<T>::iter_values().all(|season| season.mint_limits == Default::default()),
+			"Migrated seasons must not limit mints"
+		);
+		ensure!(
+			Seasons::<T>::iter_values().all(|season| season.fee.mint_assets.is_empty()),
+			"Migrated seasons must not accept assets for mints"
+		);
+		ensure!(
+			GlobalConfigs::<T>::get().mint.max_per_block.is_zero(),
+			"Migrated global config must not limit mints per block"
+		);
+		ensure!(AvatarDeposits::<T>::iter_keys().next().is_none(), "Avatars must be grandfathered");
+
+		Ok(())
+	}
+}
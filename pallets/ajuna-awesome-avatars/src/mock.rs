@@ -14,10 +14,19 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_ajuna_awesome_avatars, types::*, *};
+use crate::{
+	self as pallet_ajuna_awesome_avatars,
+	migration::stepped::{MigrationCursor, SteppedMigrationError},
+	types::*,
+	*,
+};
 use frame_support::{
 	parameter_types,
-	traits::{tokens::nonfungibles_v2::Create, AsEnsureOriginWithArg, ConstU16, ConstU64, Hooks},
+	traits::{
+		tokens::nonfungibles_v2::Create, AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64,
+		ConstU8, Hooks,
+	},
+	weights::{IdentityFee, Weight, WeightMeter},
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
@@ -36,6 +45,7 @@ pub type MockBlockNumber = u64;
 pub type MockBalance = u64;
 pub type MockNonce = u64;
 pub type MockCollectionId = u32;
+pub type MockAssetId = u32;
 
 pub const ALICE: MockAccountId = 1;
 pub const BOB: MockAccountId = 2;
@@ -48,6 +58,8 @@ frame_support::construct_runtime!(
 	pub struct Test {
 		System: frame_system,
 		Balances: pallet_balances,
+		TransactionPayment: pallet_transaction_payment,
+		Assets: pallet_assets,
 		Randomness: pallet_insecure_randomness_collective_flip,
 		Nft: pallet_nfts,
 		AAvatars: pallet_ajuna_awesome_avatars,
@@ -101,6 +113,38 @@ impl pallet_balances::Config for Test {
 	type RuntimeHoldReason = ();
 }
 
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<MockBalance>;
+	type LengthToFee = IdentityFee<MockBalance>;
+	type FeeMultiplierUpdate = ();
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = MockBalance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = MockAssetId;
+	type AssetIdParameter = MockAssetId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<MockAccountId>>;
+	type ForceOrigin = EnsureRoot<MockAccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<20>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_insecure_randomness_collective_flip::Config for Test {}
 
 parameter_types! {
@@ -180,6 +224,57 @@ impl pallet_nfts::Config for Test {
 
 parameter_types! {
 	pub const AwesomeAvatarsPalletId: PalletId = PalletId(*b"aj/aaatr");
+	pub static VerifiedAccounts: Vec<MockAccountId> = vec![];
+}
+
+parameter_types! {
+	pub static MigratedAvatars: Vec<AvatarIdOf<Test>> = vec![];
+	pub static FailingMigration: bool = false;
+	pub const ArchiveGracePeriod: MockBlockNumber = 10;
+}
+
+pub const MIGRATION_STEP_WEIGHT: Weight = Weight::from_parts(1_000, 0);
+
+/// Migrates a single avatar per step.
+pub struct MockSteppedMigration;
+impl SteppedMigration for MockSteppedMigration {
+	fn id() -> MigrationId {
+		*b"mock-avatars-v1 "
+	}
+
+	fn step(
+		cursor: Option<MigrationCursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<MigrationCursor>, SteppedMigrationError> {
+		if FailingMigration::get() {
+			return Err(SteppedMigrationError::Failed)
+		}
+		meter
+			.try_consume(MIGRATION_STEP_WEIGHT)
+			.map_err(|_| SteppedMigrationError::InsufficientWeight)?;
+
+		let mut avatar_ids = match cursor {
+			Some(cursor) => Avatars::<Test>::iter_keys_from(cursor.into_inner()),
+			None => Avatars::<Test>::iter_keys(),
+		};
+		match avatar_ids.next() {
+			Some(avatar_id) => {
+				MigratedAvatars::mutate(|migrated| migrated.push(avatar_id));
+				let cursor = Avatars::<Test>::hashed_key_for(avatar_id)
+					.try_into()
+					.map_err(|_| SteppedMigrationError::Failed)?;
+				Ok(Some(cursor))
+			},
+			None => Ok(None),
+		}
+	}
+}
+
+pub struct MockIdentityVerifier;
+impl IdentityVerifier<MockAccountId> for MockIdentityVerifier {
+	fn is_verified(who: &MockAccountId) -> bool {
+		VerifiedAccounts::get().contains(who)
+	}
 }
 
 impl pallet_ajuna_awesome_avatars::Config for Test {
@@ -190,6 +285,11 @@ impl pallet_ajuna_awesome_avatars::Config for Test {
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
 	type NftHandler = NftTransfer;
+	type IdentityVerifier = MockIdentityVerifier;
+	type AssetId = MockAssetId;
+	type Assets = FungiblesPayment<Assets>;
+	type SteppedMigration = MockSteppedMigration;
+	type ArchiveGracePeriod = ArchiveGracePeriod;
 	type WeightInfo = ();
 }
 
@@ -211,7 +311,7 @@ impl pallet_ajuna_nft_transfer::Config for Test {
 pub struct ExtBuilder {
 	existential_deposit: MockBalance,
 	organizer: Option<MockAccountId>,
-	seasons: Vec<(SeasonId, Season<MockBlockNumber, MockBalance>)>,
+	seasons: Vec<(SeasonId, Season<MockBlockNumber, MockBalance, MockAssetId>)>,
 	mint_cooldown: MockBlockNumber,
 	balances: Vec<(MockAccountId, MockBalance)>,
 	free_mints: Vec<(MockAccountId, MintCount)>,
@@ -241,7 +341,10 @@ impl ExtBuilder {
 		self.organizer = Some(organizer);
 		self
 	}
-	pub fn seasons(mut self, seasons: &[(SeasonId, Season<MockBlockNumber, MockBalance>)]) -> Self {
+	pub fn seasons(
+		mut self,
+		seasons: &[(SeasonId, Season<MockBlockNumber, MockBalance, MockAssetId>)],
+	) -> Self {
 		self.seasons = seasons.to_vec();
 		self
 	}
@@ -0,0 +1,249 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Transaction payment extension letting season sponsors pay the fees of players.
+//!
+//! [`ChargeSponsoredTransactionPayment`] replaces `pallet_transaction_payment`'s
+//! `ChargeTransactionPayment` in a runtime's signed extensions. Calls accepted by its `Sponsored`
+//! filter are paid by the sponsor of the current season, as long as the sponsorship's budget and
+//! per-account limit allow it and no tip is given. All other transactions are charged as usual.
+//!
+//! The extension keeps the identifier and encoding of `ChargeTransactionPayment`, so switching to
+//! it does not change the extrinsic format and clients need no upgrade.
+
+use crate::{types::SeasonId, BalanceOf, Config, Event, Pallet};
+use frame_support::{
+	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
+	pallet_prelude::*,
+	traits::Contains,
+};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension, UniqueSaturatedInto,
+		Zero,
+	},
+	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+	Saturating,
+};
+use sp_std::{fmt, marker::PhantomData};
+
+type FeeBalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+type LiquidityInfoOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo;
+
+/// Data carried from `pre_dispatch` to `post_dispatch`.
+pub enum SponsoredPre<T: Config + pallet_transaction_payment::Config>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	FeeBalanceOf<T>: Send + Sync + From<u64>,
+{
+	/// The fee has been withdrawn from the sponsor of `season_id`.
+	Sponsored {
+		season_id: SeasonId,
+		sponsor: T::AccountId,
+		player: T::AccountId,
+		fee: FeeBalanceOf<T>,
+		liquidity_info: LiquidityInfoOf<T>,
+	},
+	/// The fee has been charged to the signer.
+	Unsponsored(<ChargeTransactionPayment<T> as SignedExtension>::Pre),
+}
+
+/// Charges transaction fees to the sponsor of the current season for `Sponsored` calls, and to
+/// the signer otherwise.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T, Sponsored))]
+pub struct ChargeSponsoredTransactionPayment<T, Sponsored>(
+	#[codec(compact)] FeeBalanceOf<T>,
+	PhantomData<Sponsored>,
+)
+where
+	T: pallet_transaction_payment::Config;
+
+impl<T, Sponsored> ChargeSponsoredTransactionPayment<T, Sponsored>
+where
+	T: Config + pallet_transaction_payment::Config,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	FeeBalanceOf<T>: Send + Sync + From<u64>,
+	Sponsored: Contains<T::RuntimeCall>,
+{
+	/// Create the extension with the given tip, which is only allowed for unsponsored calls.
+	pub fn from(tip: FeeBalanceOf<T>) -> Self {
+		Self(tip, PhantomData)
+	}
+
+	/// The sponsor paying `fee` for `who`'s `call`, if any, along with the sponsoring season.
+	fn sponsor_of(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		tip: FeeBalanceOf<T>,
+		fee: FeeBalanceOf<T>,
+	) -> Option<(SeasonId, T::AccountId)> {
+		if !tip.is_zero() || !Sponsored::contains(call) {
+			return None
+		}
+		Pallet::<T>::sponsor_of(who, Self::to_balance(fee))
+	}
+
+	fn to_balance(fee: FeeBalanceOf<T>) -> BalanceOf<T> {
+		fee.unique_saturated_into()
+	}
+
+	fn charge_sponsor(
+		sponsor: &T::AccountId,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		fee: FeeBalanceOf<T>,
+	) -> Result<LiquidityInfoOf<T>, TransactionValidityError> {
+		<T as pallet_transaction_payment::Config>::OnChargeTransaction::withdraw_fee(
+			sponsor,
+			call,
+			info,
+			fee,
+			Zero::zero(),
+		)
+	}
+}
+
+impl<T, Sponsored> fmt::Debug for ChargeSponsoredTransactionPayment<T, Sponsored>
+where
+	T: pallet_transaction_payment::Config,
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ChargeSponsoredTransactionPayment<{:?}>", self.0)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T, Sponsored> SignedExtension for ChargeSponsoredTransactionPayment<T, Sponsored>
+where
+	T: Config + pallet_transaction_payment::Config + Send + Sync,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	FeeBalanceOf<T>: Send + Sync + From<u64>,
+	Sponsored: Contains<T::RuntimeCall> + Clone + Eq + Send + Sync + 'static,
+{
+	const IDENTIFIER: &'static str = <ChargeTransactionPayment<T> as SignedExtension>::IDENTIFIER;
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = SponsoredPre<T>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.0);
+		match Self::sponsor_of(who, call, self.0, fee) {
+			Some((_, sponsor)) => {
+				Self::charge_sponsor(&sponsor, call, info, fee)?;
+				Ok(ValidTransaction {
+					priority: ChargeTransactionPayment::<T>::get_priority(
+						info,
+						len,
+						Zero::zero(),
+						fee,
+					),
+					..Default::default()
+				})
+			},
+			None => ChargeTransactionPayment::<T>::from(self.0).validate(who, call, info, len),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.0);
+		match Self::sponsor_of(who, call, self.0, fee) {
+			Some((season_id, sponsor)) => {
+				let liquidity_info = Self::charge_sponsor(&sponsor, call, info, fee)?;
+				Pallet::<T>::charge_sponsorship(season_id, who, Self::to_balance(fee));
+				Ok(SponsoredPre::Sponsored {
+					season_id,
+					sponsor,
+					player: who.clone(),
+					fee,
+					liquidity_info,
+				})
+			},
+			None => ChargeTransactionPayment::<T>::from(self.0)
+				.pre_dispatch(who, call, info, len)
+				.map(SponsoredPre::Unsponsored),
+		}
+	}
+
+	fn post_dispatch(
+		maybe_pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match maybe_pre {
+			Some(SponsoredPre::Sponsored { season_id, sponsor, player, fee, liquidity_info }) => {
+				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+					len as u32,
+					info,
+					post_info,
+					Zero::zero(),
+				);
+				<T as pallet_transaction_payment::Config>::OnChargeTransaction::correct_and_deposit_fee(
+					&sponsor,
+					info,
+					post_info,
+					actual_fee,
+					Zero::zero(),
+					liquidity_info,
+				)?;
+				Pallet::<T>::refund_sponsorship(
+					season_id,
+					&player,
+					Self::to_balance(fee.saturating_sub(actual_fee)),
+				);
+				Pallet::<T>::deposit_event(Event::SponsoredFeePaid {
+					season_id,
+					sponsor,
+					player,
+					fee: Self::to_balance(actual_fee),
+				});
+				Ok(())
+			},
+			Some(SponsoredPre::Unsponsored(pre)) => ChargeTransactionPayment::<T>::post_dispatch(
+				Some(pre),
+				info,
+				post_info,
+				len,
+				result,
+			),
+			None => Ok(()),
+		}
+	}
+}
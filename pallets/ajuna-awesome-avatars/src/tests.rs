@@ -18,6 +18,27 @@ use crate::{mock::*, types::*, *};
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::{testing::H256, ArithmeticError, DispatchError};
 
+fn mint_pack(
+	account: MockAccountId,
+	pack_size: MintPackSize,
+	payment: MintPayment,
+) -> DispatchResult {
+	AAvatars::mint(
+		RuntimeOrigin::signed(account),
+		MintOption { pack_size, payment, pack_type: PackType::Material },
+	)
+}
+
+/// Mints a material pack, returning all avatars of the account in `SEASON_ID`.
+fn mint_avatars(
+	account: MockAccountId,
+	pack_size: MintPackSize,
+	payment: MintPayment,
+) -> Vec<AvatarIdOf<Test>> {
+	assert_ok!(mint_pack(account, pack_size, payment));
+	Owners::<Test>::get(account, SEASON_ID).to_vec()
+}
+
 fn create_avatars(season_id: SeasonId, account: MockAccountId, n: u8) -> Vec<AvatarIdOf<Test>> {
 	(0..n)
 		.map(|i| {
@@ -3986,3 +4007,2435 @@ mod ipfs {
 			});
 	}
 }
+
+mod deposit {
+	use super::*;
+
+	#[test]
+	fn mint_reserves_avatar_deposit() {
+		let deposit = 10;
+		let season = Season::default()
+			.mint_fee(MintFees { one: 12, three: 34, six: 56 })
+			.avatar_deposit(deposit);
+		let initial_balance = MockExistentialDeposit::get() + 12 + deposit;
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, initial_balance)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = mint_avatars(ALICE, MintPackSize::One, MintPayment::Normal)[0];
+
+				assert_eq!(AvatarDeposits::<Test>::get(avatar_id), Some((ALICE, deposit)));
+				assert_eq!(Balances::reserved_balance(ALICE), deposit);
+				assert_eq!(Balances::free_balance(ALICE), MockExistentialDeposit::get());
+				assert_eq!(Treasury::<Test>::get(SEASON_ID), 12);
+			});
+	}
+
+	#[test]
+	fn mint_rejects_insufficient_balance_for_deposit() {
+		let season = Season::default()
+			.mint_fee(MintFees { one: 12, three: 34, six: 56 })
+			.avatar_deposit(10);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, MockExistentialDeposit::get() + 12)])
+			.free_mints(&[(BOB, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				for (account, payment) in [(ALICE, MintPayment::Normal), (BOB, MintPayment::Free)] {
+					assert_noop!(
+						AAvatars::mint(
+							RuntimeOrigin::signed(account),
+							MintOption {
+								pack_size: MintPackSize::One,
+								payment,
+								pack_type: PackType::Material,
+							}
+						),
+						Error::<Test>::InsufficientBalance
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn mint_without_deposit_holds_nothing() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, MockExistentialDeposit::get() + 1)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = mint_avatars(ALICE, MintPackSize::One, MintPayment::Normal)[0];
+
+				assert_eq!(AvatarDeposits::<Test>::get(avatar_id), None);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+			});
+	}
+
+	#[test]
+	fn transfer_avatar_moves_deposit_to_new_owner() {
+		let deposit = 10;
+		let season = Season::default().avatar_deposit(deposit);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[
+				(ALICE, MockExistentialDeposit::get() + 1 + deposit + season.fee.transfer_avatar),
+				(BOB, MockExistentialDeposit::get() + deposit),
+			])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = mint_avatars(ALICE, MintPackSize::One, MintPayment::Normal)[0];
+				let alice_free = Balances::free_balance(ALICE);
+
+				assert_ok!(AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id));
+				assert_eq!(AvatarDeposits::<Test>::get(avatar_id), Some((BOB, deposit)));
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(
+					Balances::free_balance(ALICE),
+					alice_free + deposit - season.fee.transfer_avatar
+				);
+				assert_eq!(Balances::reserved_balance(BOB), deposit);
+				assert_eq!(Balances::free_balance(BOB), MockExistentialDeposit::get());
+			});
+	}
+
+	#[test]
+	fn transfer_avatar_rejects_new_owner_unable_to_cover_deposit() {
+		let deposit = 10;
+		let season = Season::default().avatar_deposit(deposit);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[
+				(ALICE, MockExistentialDeposit::get() + 1 + deposit + season.fee.transfer_avatar),
+				(BOB, MockExistentialDeposit::get()),
+			])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = mint_avatars(ALICE, MintPackSize::One, MintPayment::Normal)[0];
+
+				assert_noop!(
+					AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id),
+					Error::<Test>::InsufficientBalance
+				);
+				assert_eq!(AvatarDeposits::<Test>::get(avatar_id), Some((ALICE, deposit)));
+				assert_eq!(Balances::reserved_balance(ALICE), deposit);
+			});
+	}
+
+	#[test]
+	fn forge_releases_deposit_of_consumed_avatars() {
+		let deposit = 10;
+		let season = Season::default().avatar_deposit(deposit);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, MockExistentialDeposit::get() + 3 + 6 * deposit)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Normal);
+				assert_eq!(Balances::reserved_balance(ALICE), 6 * deposit);
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..5].to_vec()
+				));
+				for avatar_id in &avatar_ids[1..5] {
+					assert_eq!(AvatarDeposits::<Test>::get(avatar_id), None);
+				}
+				assert_eq!(AvatarDeposits::<Test>::get(avatar_ids[0]), Some((ALICE, deposit)));
+				assert_eq!(AvatarDeposits::<Test>::get(avatar_ids[5]), Some((ALICE, deposit)));
+				assert_eq!(Balances::reserved_balance(ALICE), 2 * deposit);
+				assert_eq!(
+					Balances::free_balance(ALICE),
+					MockExistentialDeposit::get() + 4 * deposit
+				);
+			});
+	}
+
+	#[test]
+	fn burn_avatar_works() {
+		let deposit = 10;
+		let season = Season::default().avatar_deposit(deposit);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, MockExistentialDeposit::get() + 1 + deposit)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = mint_avatars(ALICE, MintPackSize::One, MintPayment::Normal)[0];
+
+				assert_ok!(AAvatars::burn_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarBurned { avatar_id },
+				));
+				assert!(Avatars::<Test>::get(avatar_id).is_none());
+				assert!(Owners::<Test>::get(ALICE, SEASON_ID).is_empty());
+				assert_eq!(AvatarDeposits::<Test>::get(avatar_id), None);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), MockExistentialDeposit::get() + deposit);
+			});
+	}
+
+	#[test]
+	fn burn_avatar_works_for_grandfathered_avatars() {
+		let initial_balance = MockExistentialDeposit::get();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default().avatar_deposit(10))])
+			.balances(&[(ALICE, initial_balance)])
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(SEASON_ID, ALICE, 1)[0];
+				assert_ok!(AAvatars::burn_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert!(Avatars::<Test>::get(avatar_id).is_none());
+				assert_eq!(Balances::free_balance(ALICE), initial_balance);
+			});
+	}
+
+	#[test]
+	fn burn_avatar_rejects_unowned_avatars() {
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(SEASON_ID, ALICE, 1)[0];
+				assert_noop!(
+					AAvatars::burn_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::Ownership
+				);
+				assert_noop!(
+					AAvatars::burn_avatar(RuntimeOrigin::signed(BOB), H256::random()),
+					Error::<Test>::UnknownAvatar
+				);
+			});
+	}
+
+	#[test]
+	fn burn_avatar_rejects_avatars_in_use() {
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				let avatar_ids = create_avatars(SEASON_ID, ALICE, 3);
+
+				Trade::<Test>::insert(SEASON_ID, avatar_ids[0], 1);
+				assert_noop!(
+					AAvatars::burn_avatar(RuntimeOrigin::signed(ALICE), avatar_ids[0]),
+					Error::<Test>::AvatarInTrade
+				);
+
+				LockedAvatars::<Test>::insert(avatar_ids[1], ());
+				assert_noop!(
+					AAvatars::burn_avatar(RuntimeOrigin::signed(ALICE), avatar_ids[1]),
+					Error::<Test>::AvatarLocked
+				);
+
+				Preparation::<Test>::insert(avatar_ids[2], IpfsUrl::default());
+				assert_noop!(
+					AAvatars::burn_avatar(RuntimeOrigin::signed(ALICE), avatar_ids[2]),
+					Error::<Test>::AlreadyPrepared
+				);
+			});
+	}
+}
+
+mod dissolve {
+	use super::*;
+
+	fn create_avatar(
+		season_id: SeasonId,
+		account: MockAccountId,
+		rarity: RarityTier,
+	) -> AvatarIdOf<Test> {
+		let avatar = Avatar::default().season_id(season_id).dna(&[rarity.as_byte() << 4; 32]);
+		let avatar_id = H256::random();
+		Avatars::<Test>::insert(avatar_id, (account, avatar));
+		Owners::<Test>::try_append(account, season_id, avatar_id).unwrap();
+		avatar_id
+	}
+
+	fn dissolve_souls() -> Vec<(RarityTier, SoulCount)> {
+		vec![(RarityTier::Common, 1), (RarityTier::Uncommon, 3), (RarityTier::Rare, 10)]
+	}
+
+	#[test]
+	fn dissolve_into_leader_works() {
+		let season = Season::default().dissolve_souls(&dissolve_souls());
+
+		ExtBuilder::default().seasons(&[(SEASON_ID, season)]).build().execute_with(|| {
+			let leader_id = create_avatar(SEASON_ID, ALICE, RarityTier::Common);
+			let avatar_ids = vec![
+				create_avatar(SEASON_ID, ALICE, RarityTier::Common),
+				create_avatar(SEASON_ID, ALICE, RarityTier::Uncommon),
+				create_avatar(SEASON_ID, ALICE, RarityTier::Rare),
+			];
+
+			assert_ok!(AAvatars::dissolve(
+				RuntimeOrigin::signed(ALICE),
+				avatar_ids.clone().try_into().unwrap(),
+				DissolveTarget::Leader(leader_id),
+			));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::AvatarsDissolved {
+					avatar_ids: avatar_ids.clone(),
+					target: DissolveTarget::Leader(leader_id),
+					souls: 14,
+				},
+			));
+
+			assert_eq!(Avatars::<Test>::get(leader_id).unwrap().1.souls, 14);
+			assert_eq!(Owners::<Test>::get(ALICE, SEASON_ID).to_vec(), vec![leader_id]);
+			assert!(avatar_ids.iter().all(|id| Avatars::<Test>::get(id).is_none()));
+			assert_eq!(SoulPoints::<Test>::get(ALICE, SEASON_ID), 0);
+		});
+	}
+
+	#[test]
+	fn dissolve_into_balance_works() {
+		let season_1 = Season::default().dissolve_souls(&dissolve_souls());
+		let season_2 = Season::default()
+			.early_start(season_1.end + 1)
+			.start(season_1.end + 2)
+			.end(season_1.end + 3)
+			.dissolve_souls(&[(RarityTier::Common, 7)]);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season_1), (2, season_2)])
+			.build()
+			.execute_with(|| {
+				let avatar_ids = vec![
+					create_avatar(1, BOB, RarityTier::Common),
+					create_avatar(1, BOB, RarityTier::Rare),
+					create_avatar(2, BOB, RarityTier::Common),
+				];
+
+				assert_ok!(AAvatars::dissolve(
+					RuntimeOrigin::signed(BOB),
+					avatar_ids.clone().try_into().unwrap(),
+					DissolveTarget::Balance,
+				));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarsDissolved {
+						avatar_ids,
+						target: DissolveTarget::Balance,
+						souls: 18,
+					},
+				));
+
+				assert_eq!(SoulPoints::<Test>::get(BOB, 1), 11);
+				assert_eq!(SoulPoints::<Test>::get(BOB, 2), 7);
+				assert!(Owners::<Test>::get(BOB, 1).is_empty());
+				assert!(Owners::<Test>::get(BOB, 2).is_empty());
+			});
+	}
+
+	#[test]
+	fn dissolve_releases_avatar_deposits() {
+		let deposit = 10;
+		let all_tiers = [
+			RarityTier::Common,
+			RarityTier::Uncommon,
+			RarityTier::Rare,
+			RarityTier::Epic,
+			RarityTier::Legendary,
+			RarityTier::Mythical,
+		];
+		let season = Season::default()
+			.avatar_deposit(deposit)
+			.dissolve_souls(&all_tiers.map(|tier| (tier, 1)));
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, MockExistentialDeposit::get() + 3 + 6 * deposit)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption {
+						pack_size: MintPackSize::Six,
+						payment: MintPayment::Normal,
+						pack_type: PackType::Material,
+					}
+				));
+				let avatar_ids = Owners::<Test>::get(ALICE, SEASON_ID);
+				let leader_souls = Avatars::<Test>::get(avatar_ids[4]).unwrap().1.souls;
+
+				assert_ok!(AAvatars::dissolve(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[..4].to_vec().try_into().unwrap(),
+					DissolveTarget::Leader(avatar_ids[4]),
+				));
+				assert_eq!(Balances::reserved_balance(ALICE), 2 * deposit);
+				assert!(avatar_ids[..4].iter().all(|id| AvatarDeposits::<Test>::get(id).is_none()));
+				assert_eq!(Avatars::<Test>::get(avatar_ids[4]).unwrap().1.souls, leader_souls + 4);
+			});
+	}
+
+	#[test]
+	fn dissolve_rejects_invalid_avatar_lists() {
+		let season = Season::default().dissolve_souls(&dissolve_souls());
+
+		ExtBuilder::default().seasons(&[(SEASON_ID, season)]).build().execute_with(|| {
+			let avatar_id = create_avatar(SEASON_ID, ALICE, RarityTier::Common);
+
+			assert_noop!(
+				AAvatars::dissolve(
+					RuntimeOrigin::signed(ALICE),
+					BoundedVec::default(),
+					DissolveTarget::Balance,
+				),
+				Error::<Test>::NoAvatarsToDissolve
+			);
+			assert_noop!(
+				AAvatars::dissolve(
+					RuntimeOrigin::signed(ALICE),
+					vec![avatar_id, avatar_id].try_into().unwrap(),
+					DissolveTarget::Balance,
+				),
+				Error::<Test>::DuplicatedAvatarToDissolve
+			);
+			assert_noop!(
+				AAvatars::dissolve(
+					RuntimeOrigin::signed(ALICE),
+					vec![avatar_id].try_into().unwrap(),
+					DissolveTarget::Leader(avatar_id),
+				),
+				Error::<Test>::LeaderSacrificed
+			);
+			assert_noop!(
+				AAvatars::dissolve(
+					RuntimeOrigin::signed(BOB),
+					vec![avatar_id].try_into().unwrap(),
+					DissolveTarget::Balance,
+				),
+				Error::<Test>::Ownership
+			);
+		});
+	}
+
+	#[test]
+	fn dissolve_rejects_unconvertible_rarities() {
+		let season = Season::default().dissolve_souls(&dissolve_souls());
+
+		ExtBuilder::default().seasons(&[(SEASON_ID, season)]).build().execute_with(|| {
+			let avatar_ids = vec![
+				create_avatar(SEASON_ID, ALICE, RarityTier::Common),
+				create_avatar(SEASON_ID, ALICE, RarityTier::Legendary),
+			];
+			assert_noop!(
+				AAvatars::dissolve(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids.try_into().unwrap(),
+					DissolveTarget::Balance,
+				),
+				Error::<Test>::AvatarCannotBeDissolved
+			);
+		});
+	}
+
+	#[test]
+	fn dissolve_rejects_avatars_in_use() {
+		let season = Season::default().dissolve_souls(&dissolve_souls());
+
+		ExtBuilder::default().seasons(&[(SEASON_ID, season)]).build().execute_with(|| {
+			let avatar_ids = (0..3)
+				.map(|_| create_avatar(SEASON_ID, ALICE, RarityTier::Common))
+				.collect::<Vec<_>>();
+			Trade::<Test>::insert(SEASON_ID, avatar_ids[0], 1);
+			LockedAvatars::<Test>::insert(avatar_ids[1], ());
+			Preparation::<Test>::insert(avatar_ids[2], IpfsUrl::default());
+
+			for (avatar_id, error) in [
+				(avatar_ids[0], DispatchError::from(Error::<Test>::AvatarInTrade)),
+				(avatar_ids[1], Error::<Test>::AvatarLocked.into()),
+				(avatar_ids[2], Error::<Test>::AlreadyPrepared.into()),
+			] {
+				assert_noop!(
+					AAvatars::dissolve(
+						RuntimeOrigin::signed(ALICE),
+						vec![avatar_id].try_into().unwrap(),
+						DissolveTarget::Balance,
+					),
+					error
+				);
+
+				let other_id = create_avatar(SEASON_ID, ALICE, RarityTier::Common);
+				assert_noop!(
+					AAvatars::dissolve(
+						RuntimeOrigin::signed(ALICE),
+						vec![other_id].try_into().unwrap(),
+						DissolveTarget::Leader(avatar_id),
+					),
+					error
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn dissolve_rejects_leader_of_another_season() {
+		let season_1 = Season::default().dissolve_souls(&dissolve_souls());
+		let season_2 = Season::default()
+			.early_start(season_1.end + 1)
+			.start(season_1.end + 2)
+			.end(season_1.end + 3)
+			.dissolve_souls(&dissolve_souls());
+
+		ExtBuilder::default()
+			.seasons(&[(1, season_1), (2, season_2)])
+			.build()
+			.execute_with(|| {
+				let leader_id = create_avatar(1, ALICE, RarityTier::Common);
+				let avatar_id = create_avatar(2, ALICE, RarityTier::Common);
+				assert_noop!(
+					AAvatars::dissolve(
+						RuntimeOrigin::signed(ALICE),
+						vec![avatar_id].try_into().unwrap(),
+						DissolveTarget::Leader(leader_id),
+					),
+					Error::<Test>::IncorrectAvatarSeason
+				);
+			});
+	}
+}
+
+mod quests {
+	use super::*;
+
+	fn mint_quest(count: Stat) -> QuestOf<Test> {
+		Quest {
+			condition: QuestCondition::MintPacks { pack_type: PackType::Material, count },
+			reward: QuestReward::FreeMints(7),
+		}
+	}
+
+	#[test]
+	fn set_quest_works() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				let quest = Some(mint_quest(5));
+				assert_ok!(AAvatars::set_quest(
+					RuntimeOrigin::signed(ALICE),
+					SEASON_ID,
+					3,
+					quest.clone()
+				));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::QuestSet {
+					season_id: SEASON_ID,
+					quest_id: 3,
+					quest: quest.clone(),
+				}));
+				assert_eq!(Quests::<Test>::get(SEASON_ID, 3), quest);
+
+				assert_ok!(AAvatars::set_quest(RuntimeOrigin::signed(ALICE), SEASON_ID, 3, None));
+				assert_eq!(Quests::<Test>::get(SEASON_ID, 3), None);
+			});
+	}
+
+	#[test]
+	fn set_quest_rejects_invalid_quests() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					AAvatars::set_quest(RuntimeOrigin::signed(BOB), SEASON_ID, 0, None),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					AAvatars::set_quest(RuntimeOrigin::signed(ALICE), SEASON_ID + 1, 0, None),
+					Error::<Test>::UnknownSeason
+				);
+				assert_noop!(
+					AAvatars::set_quest(RuntimeOrigin::signed(ALICE), SEASON_ID, MAX_QUESTS, None),
+					Error::<Test>::QuestIdTooHigh
+				);
+				assert_noop!(
+					AAvatars::set_quest(
+						RuntimeOrigin::signed(ALICE),
+						SEASON_ID,
+						0,
+						Some(mint_quest(0))
+					),
+					Error::<Test>::InvalidQuestCondition
+				);
+			});
+	}
+
+	#[test]
+	fn mint_progresses_matching_quests() {
+		let season = Season::default();
+		let special_quest = Quest {
+			condition: QuestCondition::MintPacks { pack_type: PackType::Special, count: 1 },
+			reward: QuestReward::FreeMints(1),
+		};
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				Quests::<Test>::insert(SEASON_ID, 0, mint_quest(2));
+				Quests::<Test>::insert(SEASON_ID, 1, special_quest);
+				run_to_block(season.start);
+
+				mint_avatars(ALICE, MintPackSize::Three, MintPayment::Free);
+				assert_eq!(PlayerQuests::<Test>::get((ALICE, SEASON_ID, 0)).progress, 1);
+				assert_eq!(PlayerQuests::<Test>::get((ALICE, SEASON_ID, 1)).progress, 0);
+
+				mint_avatars(ALICE, MintPackSize::One, MintPayment::Free);
+				assert_eq!(PlayerQuests::<Test>::get((ALICE, SEASON_ID, 0)).progress, 2);
+				System::assert_has_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::QuestCompleted {
+						player: ALICE,
+						season_id: SEASON_ID,
+						quest_id: 0,
+					},
+				));
+			});
+	}
+
+	#[test]
+	fn forge_progresses_matching_quests() {
+		let season = Season::default();
+		let forge_quest = Quest {
+			condition: QuestCondition::Forge { item_type: None, count: 1 },
+			reward: QuestReward::FreeMints(1),
+		};
+		let pet_quest = Quest {
+			condition: QuestCondition::Forge { item_type: Some(ItemType::Pet), count: 1 },
+			reward: QuestReward::FreeMints(1),
+		};
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				Quests::<Test>::insert(SEASON_ID, 0, forge_quest);
+				Quests::<Test>::insert(SEASON_ID, 1, pet_quest);
+				run_to_block(season.start);
+				mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+
+				let avatar_ids = Owners::<Test>::get(ALICE, SEASON_ID);
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..5].to_vec()
+				));
+				assert_eq!(PlayerQuests::<Test>::get((ALICE, SEASON_ID, 0)).progress, 1);
+				// Avatars of the first generation have no item types.
+				assert_eq!(PlayerQuests::<Test>::get((ALICE, SEASON_ID, 1)).progress, 0);
+			});
+	}
+
+	#[test]
+	fn buy_progresses_matching_quests() {
+		let season = Season::default();
+		let price = 1_000;
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, 1_000_000), (BOB, 1_000_000)])
+			.build()
+			.execute_with(|| {
+				let quest = Quest {
+					condition: QuestCondition::Buy { count: 1 },
+					reward: QuestReward::FreeMints(1),
+				};
+				Quests::<Test>::insert(SEASON_ID, 0, quest);
+				run_to_block(season.start);
+
+				let avatar_id = create_avatars(SEASON_ID, BOB, 1)[0];
+				Trade::<Test>::insert(SEASON_ID, avatar_id, price);
+				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(ALICE), avatar_id));
+
+				assert_eq!(PlayerQuests::<Test>::get((ALICE, SEASON_ID, 0)).progress, 1);
+				assert_eq!(PlayerQuests::<Test>::get((BOB, SEASON_ID, 0)).progress, 0);
+			});
+	}
+
+	#[test]
+	fn reach_rarity_progress_works() {
+		let condition =
+			QuestCondition::ReachRarity { item_type: None, rarity: RarityTier::Legendary };
+		let avatar_of = |rarity: RarityTier| Avatar::default().dna(&[rarity.as_byte() << 4; 32]);
+
+		assert_eq!(condition.target(), 1);
+		assert_eq!(condition.progress_for(&QuestAction::Forge(&avatar_of(RarityTier::Epic))), 0);
+		for rarity in [RarityTier::Legendary, RarityTier::Mythical] {
+			assert_eq!(condition.progress_for(&QuestAction::Forge(&avatar_of(rarity))), 1);
+		}
+		assert_eq!(condition.progress_for(&QuestAction::Buy), 0);
+
+		let pet_condition = QuestCondition::ReachRarity {
+			item_type: Some(ItemType::Pet),
+			rarity: RarityTier::Rare,
+		};
+		let avatar = avatar_of(RarityTier::Mythical);
+		assert_eq!(pet_condition.progress_for(&QuestAction::Forge(&avatar)), 0);
+	}
+
+	#[test]
+	fn claim_quest_reward_works_for_free_mints() {
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.free_mints(&[(BOB, 3)])
+			.build()
+			.execute_with(|| {
+				Quests::<Test>::insert(SEASON_ID, 0, mint_quest(2));
+				PlayerQuests::<Test>::insert(
+					(BOB, SEASON_ID, 0),
+					QuestProgress { progress: 2, claimed: false },
+				);
+
+				assert_ok!(AAvatars::claim_quest_reward(RuntimeOrigin::signed(BOB), SEASON_ID, 0));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::QuestRewardClaimed {
+						player: BOB,
+						season_id: SEASON_ID,
+						quest_id: 0,
+						reward: QuestReward::FreeMints(7),
+					},
+				));
+				assert_eq!(PlayerConfigs::<Test>::get(BOB).free_mints, 3 + 7);
+				assert!(PlayerQuests::<Test>::get((BOB, SEASON_ID, 0)).claimed);
+
+				assert_noop!(
+					AAvatars::claim_quest_reward(RuntimeOrigin::signed(BOB), SEASON_ID, 0),
+					Error::<Test>::QuestRewardAlreadyClaimed
+				);
+			});
+	}
+
+	#[test]
+	fn claim_quest_reward_works_for_tokens() {
+		let reward = 1_000;
+		let quest = Quest {
+			condition: QuestCondition::Buy { count: 1 },
+			reward: QuestReward::Tokens(reward),
+		};
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.balances(&[(BOB, MockExistentialDeposit::get())])
+			.build()
+			.execute_with(|| {
+				AAvatars::deposit_into_treasury(&SEASON_ID, reward + 1);
+				Quests::<Test>::insert(SEASON_ID, 0, quest);
+				PlayerQuests::<Test>::insert(
+					(BOB, SEASON_ID, 0),
+					QuestProgress { progress: 1, claimed: false },
+				);
+
+				assert_ok!(AAvatars::claim_quest_reward(RuntimeOrigin::signed(BOB), SEASON_ID, 0));
+				assert_eq!(Treasury::<Test>::get(SEASON_ID), 1);
+				assert_eq!(Balances::free_balance(BOB), MockExistentialDeposit::get() + reward);
+			});
+	}
+
+	#[test]
+	fn claim_quest_reward_rejects_insufficient_treasury() {
+		let quest = Quest {
+			condition: QuestCondition::Buy { count: 1 },
+			reward: QuestReward::Tokens(1_000),
+		};
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				AAvatars::deposit_into_treasury(&SEASON_ID, 999);
+				Quests::<Test>::insert(SEASON_ID, 0, quest);
+				PlayerQuests::<Test>::insert(
+					(BOB, SEASON_ID, 0),
+					QuestProgress { progress: 1, claimed: false },
+				);
+
+				assert_noop!(
+					AAvatars::claim_quest_reward(RuntimeOrigin::signed(BOB), SEASON_ID, 0),
+					Error::<Test>::InsufficientTreasury
+				);
+			});
+	}
+
+	#[test]
+	fn claim_quest_reward_rejects_unknown_or_incomplete_quests() {
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					AAvatars::claim_quest_reward(RuntimeOrigin::signed(BOB), SEASON_ID, 0),
+					Error::<Test>::UnknownQuest
+				);
+
+				Quests::<Test>::insert(SEASON_ID, 0, mint_quest(2));
+				PlayerQuests::<Test>::insert(
+					(BOB, SEASON_ID, 0),
+					QuestProgress { progress: 1, claimed: false },
+				);
+				assert_noop!(
+					AAvatars::claim_quest_reward(RuntimeOrigin::signed(BOB), SEASON_ID, 0),
+					Error::<Test>::QuestNotCompleted
+				);
+			});
+	}
+}
+
+mod retirement {
+	use super::*;
+
+	fn create_avatar(season_id: SeasonId, account: MockAccountId, dna: &[u8]) -> AvatarIdOf<Test> {
+		let mut avatar = Avatar::default().season_id(season_id).dna(dna);
+		avatar.souls = 42;
+		let avatar_id = H256::random();
+		Avatars::<Test>::insert(avatar_id, (account, avatar));
+		Owners::<Test>::try_append(account, season_id, avatar_id).unwrap();
+		avatar_id
+	}
+
+	fn finish_season(season_id: SeasonId) {
+		CurrentSeasonStatus::<Test>::mutate(|status| status.season_id = season_id + 1);
+	}
+
+	#[test]
+	fn retire_avatars_works() {
+		let season = Season::default();
+		let max_tier_dna = [RarityTier::Mythical.as_byte() << 4; 32];
+		let rare_dna = [RarityTier::Rare.as_byte() << 4; 32];
+
+		ExtBuilder::default().seasons(&[(SEASON_ID, season)]).build().execute_with(|| {
+			let avatar_ids = vec![
+				create_avatar(SEASON_ID, ALICE, &max_tier_dna),
+				create_avatar(SEASON_ID, ALICE, &rare_dna),
+			];
+			let kept_id = create_avatar(SEASON_ID, ALICE, &rare_dna);
+			finish_season(SEASON_ID);
+			run_to_block(10);
+
+			assert_ok!(AAvatars::retire_avatars(
+				RuntimeOrigin::signed(ALICE),
+				avatar_ids.clone().try_into().unwrap()
+			));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::AvatarsRetired {
+				player: ALICE,
+				avatar_ids: avatar_ids.clone(),
+			}));
+
+			assert_eq!(Owners::<Test>::get(ALICE, SEASON_ID).to_vec(), vec![kept_id]);
+			assert!(avatar_ids.iter().all(|id| Avatars::<Test>::get(id).is_none()));
+			assert_eq!(
+				Trophies::<Test>::get(ALICE, avatar_ids[0]),
+				Some(Trophy {
+					season_id: SEASON_ID,
+					encoding: DnaEncoding::V1,
+					dna: max_tier_dna.to_vec().try_into().unwrap(),
+					souls: 42,
+					rarity: RarityTier::Mythical,
+					max_tier: true,
+					retired_at: 10,
+				})
+			);
+			let rare_trophy = Trophies::<Test>::get(ALICE, avatar_ids[1]).unwrap();
+			assert_eq!(rare_trophy.rarity, RarityTier::Rare);
+			assert!(!rare_trophy.max_tier);
+
+			let mut trophies = AAvatars::trophies_of(&ALICE);
+			trophies.sort_by_key(|(avatar_id, _)| *avatar_id);
+			let mut expected_ids = avatar_ids;
+			expected_ids.sort();
+			assert_eq!(trophies.into_iter().map(|(id, _)| id).collect::<Vec<_>>(), expected_ids);
+			assert!(AAvatars::trophies_of(&BOB).is_empty());
+		});
+	}
+
+	#[test]
+	fn retire_avatars_releases_avatar_deposits() {
+		let deposit = 10;
+		let season = Season::default().avatar_deposit(deposit);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, MockExistentialDeposit::get() + 1 + deposit)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption {
+						pack_size: MintPackSize::One,
+						payment: MintPayment::Normal,
+						pack_type: PackType::Material,
+					}
+				));
+				let avatar_ids = Owners::<Test>::get(ALICE, SEASON_ID);
+				run_to_block(season.end + 1);
+
+				assert_ok!(AAvatars::retire_avatars(RuntimeOrigin::signed(ALICE), avatar_ids));
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), MockExistentialDeposit::get() + deposit);
+			});
+	}
+
+	#[test]
+	fn retire_avatars_rejects_avatars_of_ongoing_seasons() {
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				let avatar_ids = create_avatars(SEASON_ID, ALICE, 1);
+				assert_noop!(
+					AAvatars::retire_avatars(
+						RuntimeOrigin::signed(ALICE),
+						avatar_ids.try_into().unwrap()
+					),
+					Error::<Test>::CannotRetireDuringSeason
+				);
+			});
+	}
+
+	#[test]
+	fn retire_avatars_rejects_invalid_avatars() {
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				let avatar_ids = create_avatars(SEASON_ID, ALICE, 4);
+				finish_season(SEASON_ID);
+
+				assert_noop!(
+					AAvatars::retire_avatars(RuntimeOrigin::signed(ALICE), BoundedVec::default()),
+					Error::<Test>::NoAvatarsToRetire
+				);
+				assert_noop!(
+					AAvatars::retire_avatars(
+						RuntimeOrigin::signed(BOB),
+						vec![avatar_ids[0]].try_into().unwrap()
+					),
+					Error::<Test>::Ownership
+				);
+
+				Trade::<Test>::insert(SEASON_ID, avatar_ids[1], 1);
+				LockedAvatars::<Test>::insert(avatar_ids[2], ());
+				Preparation::<Test>::insert(avatar_ids[3], IpfsUrl::default());
+				for (avatar_id, error) in [
+					(avatar_ids[1], Error::<Test>::AvatarInTrade),
+					(avatar_ids[2], Error::<Test>::AvatarLocked),
+					(avatar_ids[3], Error::<Test>::AlreadyPrepared),
+				] {
+					assert_noop!(
+						AAvatars::retire_avatars(
+							RuntimeOrigin::signed(ALICE),
+							vec![avatar_ids[0], avatar_id].try_into().unwrap()
+						),
+						error
+					);
+				}
+			});
+	}
+}
+
+mod forge_costs {
+	use super::*;
+
+	#[test]
+	fn forge_charges_currency_fee_into_treasury() {
+		let fee = 5;
+		let season = Season::default().forge_fees(&[(ForgeType::None, ForgeFee::Currency(fee))]);
+		let initial_balance = MockExistentialDeposit::get() + 100;
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, initial_balance)])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..5].to_vec()
+				));
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - fee);
+				assert_eq!(Treasury::<Test>::get(SEASON_ID), fee);
+			});
+	}
+
+	#[test]
+	fn forge_charges_free_mints_fee() {
+		let season = Season::default().forge_fees(&[(ForgeType::None, ForgeFee::FreeMints(3))]);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+				assert_eq!(PlayerConfigs::<Test>::get(ALICE).free_mints, 4);
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..5].to_vec()
+				));
+				assert_eq!(PlayerConfigs::<Test>::get(ALICE).free_mints, 1);
+				assert_eq!(Treasury::<Test>::get(SEASON_ID), 0);
+			});
+	}
+
+	#[test]
+	fn forge_rejects_unpaid_fees() {
+		let currency_season =
+			Season::default().forge_fees(&[(ForgeType::None, ForgeFee::Currency(1_000))]);
+		let free_mint_season =
+			Season::default().forge_fees(&[(ForgeType::None, ForgeFee::FreeMints(1))]);
+
+		for (season, error) in [
+			(currency_season, Error::<Test>::InsufficientBalance),
+			(free_mint_season, Error::<Test>::InsufficientFreeMints),
+		] {
+			ExtBuilder::default()
+				.seasons(&[(SEASON_ID, season.clone())])
+				.balances(&[(ALICE, MockExistentialDeposit::get())])
+				.free_mints(&[(ALICE, 6)])
+				.build()
+				.execute_with(|| {
+					run_to_block(season.start);
+					let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+
+					assert_noop!(
+						AAvatars::forge(
+							RuntimeOrigin::signed(ALICE),
+							avatar_ids[0],
+							avatar_ids[1..5].to_vec()
+						),
+						error
+					);
+				});
+		}
+	}
+
+	#[test]
+	fn forge_ignores_fees_of_other_forge_types() {
+		let season = Season::default().forge_fees(&[(ForgeType::Stack, ForgeFee::Currency(1_000))]);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..5].to_vec()
+				));
+				assert_eq!(Treasury::<Test>::get(SEASON_ID), 0);
+			});
+	}
+
+	#[test]
+	fn forge_respects_cooldown() {
+		let cooldown = 5;
+		let season = Season::default().end(20).forge_cooldown(cooldown);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..2].to_vec()
+				));
+
+				run_to_block(season.start + cooldown - 1);
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						avatar_ids[0],
+						avatar_ids[2..3].to_vec()
+					),
+					Error::<Test>::ForgeCooldown
+				);
+
+				run_to_block(season.start + cooldown);
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[2..3].to_vec()
+				));
+			});
+	}
+
+	#[test]
+	fn set_season_rejects_duplicated_forge_types() {
+		let season = Season::default().forge_fees(&[
+			(ForgeType::Stack, ForgeFee::Currency(1)),
+			(ForgeType::Stack, ForgeFee::FreeMints(1)),
+		]);
+
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_season(RuntimeOrigin::signed(ALICE), SEASON_ID, season),
+				Error::<Test>::DuplicatedForgeType
+			);
+		});
+	}
+}
+
+mod mint_limits {
+	use super::*;
+
+	#[test]
+	fn per_block_limit_is_shared_by_all_accounts() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 10), (BOB, 10)])
+			.build()
+			.execute_with(|| {
+				GlobalConfigs::<Test>::mutate(|config| config.mint.max_per_block = 3);
+				run_to_block(season.start);
+
+				assert_ok!(mint_pack(ALICE, MintPackSize::Three, MintPayment::Free));
+				assert_noop!(
+					mint_pack(BOB, MintPackSize::Three, MintPayment::Free),
+					Error::<Test>::BlockMintLimitReached
+				);
+
+				run_to_block(season.start + 1);
+				assert_ok!(mint_pack(BOB, MintPackSize::Three, MintPayment::Free));
+				assert_eq!(BlockMints::<Test>::get(), (season.start + 1, 3));
+			});
+	}
+
+	#[test]
+	fn per_period_limit_resets_every_period() {
+		let season = Season::default().mint_limits(MintLimits {
+			period: 1,
+			max_per_period: 3,
+			..Default::default()
+		});
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 10), (BOB, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+
+				assert_ok!(mint_pack(ALICE, MintPackSize::Three, MintPayment::Free));
+				assert_noop!(
+					mint_pack(BOB, MintPackSize::Three, MintPayment::Free),
+					Error::<Test>::PeriodMintLimitReached
+				);
+
+				run_to_block(season.start + 1);
+				assert_ok!(mint_pack(BOB, MintPackSize::Three, MintPayment::Free));
+			});
+	}
+
+	#[test]
+	fn per_account_limit_does_not_affect_other_accounts() {
+		let season = Season::default().mint_limits(MintLimits {
+			period: 10,
+			max_per_account: 3,
+			..Default::default()
+		});
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 10), (BOB, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+
+				assert_ok!(mint_pack(ALICE, MintPackSize::Three, MintPayment::Free));
+				assert_noop!(
+					mint_pack(ALICE, MintPackSize::Three, MintPayment::Free),
+					Error::<Test>::AccountMintLimitReached
+				);
+				assert_ok!(mint_pack(BOB, MintPackSize::Three, MintPayment::Free));
+				assert_eq!(AccountPeriodMints::<Test>::get(ALICE, SEASON_ID), (0, 3));
+			});
+	}
+
+	#[test]
+	fn early_minters_require_identity_when_configured() {
+		let season = Season::default()
+			.early_start(2)
+			.start(3)
+			.end(4)
+			.mint_limits(MintLimits { early_requires_identity: true, ..Default::default() });
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 10), (BOB, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.early_start);
+				assert_noop!(
+					mint_pack(ALICE, MintPackSize::Three, MintPayment::Free),
+					Error::<Test>::IdentityNotVerified
+				);
+
+				VerifiedAccounts::mutate(|accounts| accounts.push(ALICE));
+				assert_ok!(mint_pack(ALICE, MintPackSize::Three, MintPayment::Free));
+
+				run_to_block(season.start);
+				assert_ok!(mint_pack(BOB, MintPackSize::Three, MintPayment::Free));
+			});
+	}
+}
+
+mod asset_payments {
+	use super::*;
+
+	const ASSET_ID: MockAssetId = 7;
+
+	fn create_asset(holders: &[(MockAccountId, MockBalance)]) {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET_ID, ALICE, true, 1));
+		for (account, amount) in holders {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), ASSET_ID, *account, *amount));
+		}
+	}
+
+	fn asset_season() -> Season<MockBlockNumber, MockBalance, MockAssetId> {
+		Season::default()
+			.early_start(5)
+			.start(10)
+			.end(15)
+			.mint_asset_fees(&[(ASSET_ID, MintFees { one: 10, three: 20, six: 30 })])
+	}
+
+	#[test]
+	fn mint_with_asset_pays_into_asset_treasury() {
+		let season = asset_season();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.build()
+			.execute_with(|| {
+				create_asset(&[(BOB, 100)]);
+				run_to_block(season.start);
+
+				assert_ok!(mint_pack(BOB, MintPackSize::Three, MintPayment::Asset(ASSET_ID)));
+				assert_eq!(Owners::<Test>::get(BOB, SEASON_ID).len(), 3);
+				assert_eq!(Assets::balance(ASSET_ID, BOB), 80);
+				assert_eq!(Assets::balance(ASSET_ID, AAvatars::treasury_account_id()), 20);
+				assert_eq!(AssetTreasury::<Test>::get(SEASON_ID, ASSET_ID), 20);
+				assert_eq!(Treasury::<Test>::get(SEASON_ID), 0);
+			});
+	}
+
+	#[test]
+	fn mint_with_asset_rejects_unsupported_asset() {
+		let season = asset_season();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.build()
+			.execute_with(|| {
+				create_asset(&[(BOB, 100)]);
+				run_to_block(season.start);
+
+				assert_noop!(
+					mint_pack(BOB, MintPackSize::Three, MintPayment::Asset(ASSET_ID + 1)),
+					Error::<Test>::UnsupportedMintAsset
+				);
+			});
+	}
+
+	#[test]
+	fn mint_with_asset_rejects_insufficient_balance() {
+		let season = asset_season();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.build()
+			.execute_with(|| {
+				create_asset(&[(BOB, 19)]);
+				run_to_block(season.start);
+
+				assert_noop!(
+					mint_pack(BOB, MintPackSize::Three, MintPayment::Asset(ASSET_ID)),
+					Error::<Test>::InsufficientBalance
+				);
+			});
+	}
+
+	#[test]
+	fn claim_asset_treasury_works() {
+		let season = asset_season();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.build()
+			.execute_with(|| {
+				create_asset(&[(BOB, 100)]);
+				Treasurer::<Test>::insert(SEASON_ID, CHARLIE);
+				run_to_block(season.start);
+				assert_ok!(mint_pack(BOB, MintPackSize::Three, MintPayment::Asset(ASSET_ID)));
+
+				assert_noop!(
+					AAvatars::claim_asset_treasury(
+						RuntimeOrigin::signed(CHARLIE),
+						SEASON_ID,
+						ASSET_ID
+					),
+					Error::<Test>::CannotClaimDuringSeason
+				);
+
+				run_to_block(season.end + 1);
+				assert_noop!(
+					AAvatars::claim_asset_treasury(RuntimeOrigin::signed(BOB), SEASON_ID, ASSET_ID),
+					DispatchError::BadOrigin
+				);
+				assert_ok!(AAvatars::claim_asset_treasury(
+					RuntimeOrigin::signed(CHARLIE),
+					SEASON_ID,
+					ASSET_ID
+				));
+				assert_eq!(Assets::balance(ASSET_ID, CHARLIE), 20);
+				assert_eq!(AssetTreasury::<Test>::get(SEASON_ID, ASSET_ID), 0);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AssetTreasuryClaimed {
+						season_id: SEASON_ID,
+						asset_id: ASSET_ID,
+						treasurer: CHARLIE,
+						amount: 20,
+					},
+				));
+
+				assert_noop!(
+					AAvatars::claim_asset_treasury(
+						RuntimeOrigin::signed(CHARLIE),
+						SEASON_ID,
+						ASSET_ID
+					),
+					Error::<Test>::CannotClaimZero
+				);
+			});
+	}
+
+	#[test]
+	fn set_season_rejects_duplicated_mint_assets() {
+		let season = Season::default().mint_asset_fees(&[
+			(ASSET_ID, MintFees { one: 1, three: 2, six: 3 }),
+			(ASSET_ID, MintFees { one: 4, three: 5, six: 6 }),
+		]);
+
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_season(RuntimeOrigin::signed(ALICE), SEASON_ID, season),
+				Error::<Test>::DuplicatedMintAsset
+			);
+		});
+	}
+}
+
+mod rentals {
+	use super::*;
+
+	fn terms(duration: MockBlockNumber, max_forges: Option<Stat>) -> RentalTermsOf<Test> {
+		RentalTerms { price: 10, duration, max_forges }
+	}
+
+	#[test]
+	fn rent_avatar_pays_owner_upfront() {
+		let season = Season::default();
+		let initial_balance = MockExistentialDeposit::get() + 100;
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, initial_balance), (BOB, initial_balance)])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free)[0];
+
+				assert_ok!(AAvatars::offer_rental(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					terms(5, None)
+				));
+				assert_ok!(AAvatars::rent_avatar(RuntimeOrigin::signed(BOB), avatar_id));
+
+				assert_eq!(Balances::free_balance(ALICE), initial_balance + 10);
+				assert_eq!(Balances::free_balance(BOB), initial_balance - 10);
+				assert_eq!(RentalOffers::<Test>::get(avatar_id), None);
+				assert_eq!(
+					Rentals::<Test>::get(avatar_id),
+					Some(RentalAgreement {
+						renter: BOB,
+						expires_at: season.start + 5,
+						forges_left: None
+					})
+				);
+				assert_eq!(Avatars::<Test>::get(avatar_id).unwrap().0, ALICE);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarRented {
+						avatar_id,
+						owner: ALICE,
+						renter: BOB,
+						expires_at: season.start + 5,
+					},
+				));
+			});
+	}
+
+	#[test]
+	fn renter_can_forge_with_rented_leader() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(BOB, MockExistentialDeposit::get() + 100)])
+			.free_mints(&[(ALICE, 6), (BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let leader_id = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free)[0];
+				let sacrifice_ids = mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+
+				assert_ok!(AAvatars::offer_rental(
+					RuntimeOrigin::signed(ALICE),
+					leader_id,
+					terms(5, Some(1))
+				));
+				assert_ok!(AAvatars::rent_avatar(RuntimeOrigin::signed(BOB), leader_id));
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(BOB),
+					leader_id,
+					sacrifice_ids[0..4].to_vec()
+				));
+
+				assert_eq!(Avatars::<Test>::get(leader_id).unwrap().0, ALICE);
+				assert!(Owners::<Test>::get(ALICE, SEASON_ID).contains(&leader_id));
+				assert!(!Owners::<Test>::get(BOB, SEASON_ID).contains(&leader_id));
+				// The single allowed forge has been used up, reverting the avatar to its owner.
+				assert_eq!(Rentals::<Test>::get(leader_id), None);
+			});
+	}
+
+	#[test]
+	fn rented_avatar_cannot_be_used_by_owner_until_expiry() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(BOB, MockExistentialDeposit::get() + 100)])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+				let rented_id = avatar_ids[0];
+
+				assert_ok!(AAvatars::offer_rental(
+					RuntimeOrigin::signed(ALICE),
+					rented_id,
+					terms(1, None)
+				));
+				assert_ok!(AAvatars::rent_avatar(RuntimeOrigin::signed(BOB), rented_id));
+
+				assert_noop!(
+					AAvatars::set_price(RuntimeOrigin::signed(ALICE), rented_id, 1),
+					Error::<Test>::AvatarRented
+				);
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						rented_id,
+						avatar_ids[1..5].to_vec()
+					),
+					Error::<Test>::AvatarRented
+				);
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						avatar_ids[1],
+						vec![rented_id, avatar_ids[2], avatar_ids[3], avatar_ids[4]]
+					),
+					Error::<Test>::AvatarRented
+				);
+				assert_noop!(
+					AAvatars::set_price(RuntimeOrigin::signed(BOB), rented_id, 1),
+					Error::<Test>::Ownership
+				);
+
+				run_to_block(season.start + 1);
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), rented_id, 1));
+			});
+	}
+
+	#[test]
+	fn renter_cannot_forge_after_expiry() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(BOB, MockExistentialDeposit::get() + 100)])
+			.free_mints(&[(ALICE, 6), (BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let leader_id = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free)[0];
+				let sacrifice_ids = mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+
+				assert_ok!(AAvatars::offer_rental(
+					RuntimeOrigin::signed(ALICE),
+					leader_id,
+					terms(1, None)
+				));
+				assert_ok!(AAvatars::rent_avatar(RuntimeOrigin::signed(BOB), leader_id));
+
+				run_to_block(season.start + 1);
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(BOB),
+						leader_id,
+						sacrifice_ids[0..4].to_vec()
+					),
+					Error::<Test>::Ownership
+				);
+
+				assert_ok!(AAvatars::end_rental(RuntimeOrigin::signed(CHARLIE), leader_id));
+				assert_eq!(Rentals::<Test>::get(leader_id), None);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::RentalEnded { avatar_id: leader_id },
+				));
+			});
+	}
+
+	#[test]
+	fn rental_calls_reject_invalid_requests() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(BOB, MockExistentialDeposit::get() + 100)])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free)[0];
+
+				assert_noop!(
+					AAvatars::offer_rental(RuntimeOrigin::signed(BOB), avatar_id, terms(5, None)),
+					Error::<Test>::Ownership
+				);
+				assert_noop!(
+					AAvatars::offer_rental(RuntimeOrigin::signed(ALICE), avatar_id, terms(0, None)),
+					Error::<Test>::InvalidRentalTerms
+				);
+				assert_noop!(
+					AAvatars::offer_rental(
+						RuntimeOrigin::signed(ALICE),
+						avatar_id,
+						terms(5, Some(0))
+					),
+					Error::<Test>::InvalidRentalTerms
+				);
+				assert_noop!(
+					AAvatars::rent_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::UnknownRentalOffer
+				);
+
+				assert_ok!(AAvatars::offer_rental(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					terms(5, None)
+				));
+				assert_noop!(
+					AAvatars::rent_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::CannotRentOwnAvatar
+				);
+				assert_noop!(
+					AAvatars::end_rental(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::UnknownRental
+				);
+
+				assert_ok!(AAvatars::rent_avatar(RuntimeOrigin::signed(BOB), avatar_id));
+				assert_noop!(
+					AAvatars::end_rental(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::RentalNotExpired
+				);
+
+				assert_noop!(
+					AAvatars::cancel_rental_offer(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::UnknownRentalOffer
+				);
+			});
+	}
+}
+
+mod sponsorship {
+	use super::*;
+	use crate::sponsored_payment::ChargeSponsoredTransactionPayment;
+	use frame_support::{
+		dispatch::{DispatchInfo, Pays, PostDispatchInfo},
+		traits::Contains,
+		weights::Weight,
+	};
+	use parity_scale_codec::{Decode, Encode};
+	use sp_runtime::traits::SignedExtension;
+
+	#[derive(Clone, Eq, PartialEq)]
+	struct SponsoredCalls;
+	impl Contains<RuntimeCall> for SponsoredCalls {
+		fn contains(call: &RuntimeCall) -> bool {
+			matches!(call, RuntimeCall::AAvatars(_))
+		}
+	}
+
+	type Extension = ChargeSponsoredTransactionPayment<Test, SponsoredCalls>;
+
+	const LEN: usize = 10;
+	const INITIAL_BALANCE: MockBalance = 1_000_000_000_000;
+
+	fn sponsored_call() -> RuntimeCall {
+		RuntimeCall::AAvatars(crate::Call::remove_price { avatar_id: H256::default() })
+	}
+
+	fn info(weight: u64) -> DispatchInfo {
+		DispatchInfo { weight: Weight::from_parts(weight, 0), ..Default::default() }
+	}
+
+	fn fee_of(info: &DispatchInfo) -> MockBalance {
+		TransactionPayment::compute_fee(LEN as u32, info, 0)
+	}
+
+	fn apply(who: MockAccountId, call: &RuntimeCall, tip: MockBalance, info: &DispatchInfo) {
+		let pre = Extension::from(tip).pre_dispatch(&who, call, info, LEN).unwrap();
+		assert_ok!(Extension::post_dispatch(
+			Some(pre),
+			info,
+			&PostDispatchInfo::default(),
+			LEN,
+			&Ok(())
+		));
+	}
+
+	#[test]
+	fn extension_is_compatible_with_charge_transaction_payment() {
+		type Charge = pallet_transaction_payment::ChargeTransactionPayment<Test>;
+		assert_eq!(Extension::IDENTIFIER, <Charge as SignedExtension>::IDENTIFIER);
+
+		let tip: MockBalance = 123;
+		assert_eq!(Extension::from(tip).encode(), Charge::from(tip).encode());
+		assert_eq!(
+			Extension::decode(&mut &Charge::from(tip).encode()[..]),
+			Ok(Extension::from(tip))
+		);
+	}
+
+	#[test]
+	fn set_sponsorship_works() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				assert_ok!(AAvatars::set_sponsorship(
+					RuntimeOrigin::signed(ALICE),
+					SEASON_ID,
+					100,
+					10
+				));
+				let sponsorship = Sponsorship { sponsor: ALICE, budget: 100, max_per_account: 10 };
+				assert_eq!(Sponsorships::<Test>::get(SEASON_ID), Some(sponsorship.clone()));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::SponsorshipSet {
+						season_id: SEASON_ID,
+						sponsorship: Some(sponsorship),
+					},
+				));
+
+				assert_ok!(AAvatars::set_sponsorship(
+					RuntimeOrigin::signed(ALICE),
+					SEASON_ID,
+					0,
+					10
+				));
+				assert_eq!(Sponsorships::<Test>::get(SEASON_ID), None);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::SponsorshipSet { season_id: SEASON_ID, sponsorship: None },
+				));
+			});
+	}
+
+	#[test]
+	fn set_sponsorship_rejects_invalid_requests() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, Season::default())])
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					AAvatars::set_sponsorship(RuntimeOrigin::signed(BOB), SEASON_ID, 100, 10),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					AAvatars::set_sponsorship(RuntimeOrigin::signed(ALICE), SEASON_ID + 1, 100, 10),
+					Error::<Test>::UnknownSeason
+				);
+			});
+	}
+
+	#[test]
+	fn sponsored_calls_are_paid_by_the_sponsor() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let budget = INITIAL_BALANCE / 2;
+				assert_ok!(AAvatars::set_sponsorship(
+					RuntimeOrigin::signed(ALICE),
+					SEASON_ID,
+					budget,
+					budget
+				));
+
+				let info = info(1_000);
+				let fee = fee_of(&info);
+				apply(BOB, &sponsored_call(), 0, &info);
+
+				assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - fee);
+				assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+				assert_eq!(Sponsorships::<Test>::get(SEASON_ID).unwrap().budget, budget - fee);
+				assert_eq!(SponsoredFees::<Test>::get(SEASON_ID, BOB), fee);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::SponsoredFeePaid {
+						season_id: SEASON_ID,
+						sponsor: ALICE,
+						player: BOB,
+						fee,
+					},
+				));
+			});
+	}
+
+	#[test]
+	fn unused_sponsored_fees_are_refunded() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let budget = INITIAL_BALANCE / 2;
+				assert_ok!(AAvatars::set_sponsorship(
+					RuntimeOrigin::signed(ALICE),
+					SEASON_ID,
+					budget,
+					budget
+				));
+
+				let info = info(1_000);
+				let call = sponsored_call();
+				let pre = Extension::from(0).pre_dispatch(&BOB, &call, &info, LEN).unwrap();
+				let post_info = PostDispatchInfo {
+					actual_weight: Some(Weight::from_parts(1, 0)),
+					pays_fee: Pays::Yes,
+				};
+				assert_ok!(Extension::post_dispatch(Some(pre), &info, &post_info, LEN, &Ok(())));
+
+				let actual_fee = fee_of(&info) - 999;
+				assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - actual_fee);
+				assert_eq!(
+					Sponsorships::<Test>::get(SEASON_ID).unwrap().budget,
+					budget - actual_fee
+				);
+				assert_eq!(SponsoredFees::<Test>::get(SEASON_ID, BOB), actual_fee);
+			});
+	}
+
+	#[test]
+	fn players_pay_once_sponsorship_limits_are_reached() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[
+				(ALICE, INITIAL_BALANCE),
+				(BOB, INITIAL_BALANCE),
+				(CHARLIE, INITIAL_BALANCE),
+			])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let info = info(1_000);
+				let fee = fee_of(&info);
+				assert_ok!(AAvatars::set_sponsorship(
+					RuntimeOrigin::signed(ALICE),
+					SEASON_ID,
+					fee * 3,
+					fee * 2
+				));
+
+				// Per-account limit.
+				apply(BOB, &sponsored_call(), 0, &info);
+				apply(BOB, &sponsored_call(), 0, &info);
+				assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+				apply(BOB, &sponsored_call(), 0, &info);
+				assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - fee);
+
+				// Budget limit.
+				apply(CHARLIE, &sponsored_call(), 0, &info);
+				assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE);
+				apply(CHARLIE, &sponsored_call(), 0, &info);
+				assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - fee);
+
+				assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - fee * 3);
+				assert_eq!(Sponsorships::<Test>::get(SEASON_ID).unwrap().budget, 0);
+			});
+	}
+
+	#[test]
+	fn other_transactions_are_paid_by_the_signer() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let budget = INITIAL_BALANCE / 2;
+				assert_ok!(AAvatars::set_sponsorship(
+					RuntimeOrigin::signed(ALICE),
+					SEASON_ID,
+					budget,
+					budget
+				));
+
+				let info = info(1_000);
+				let fee = fee_of(&info);
+				let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+				apply(BOB, &remark, 0, &info);
+				assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - fee);
+
+				// Tipped transactions are never sponsored.
+				let tip = 7;
+				apply(BOB, &sponsored_call(), tip, &info);
+				assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - fee * 2 - tip);
+
+				assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+				assert_eq!(Sponsorships::<Test>::get(SEASON_ID).unwrap().budget, budget);
+			});
+	}
+}
+
+mod stepped_migration {
+	use super::*;
+	use crate::migration::stepped::StartSteppedMigration;
+	use frame_support::traits::{Hooks, OnRuntimeUpgrade};
+
+	const MIGRATION_ID: MigrationId = *b"mock-avatars-v1 ";
+
+	fn progress() -> MigrationProgressOf<Test> {
+		OngoingMigration::<Test>::get().unwrap()
+	}
+
+	#[test]
+	fn stepped_migration_runs_across_blocks() {
+		ExtBuilder::default().build().execute_with(|| {
+			let mut avatar_ids = create_avatars(SEASON_ID, ALICE, 5);
+
+			StartSteppedMigration::<Test>::on_runtime_upgrade();
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::MigrationStarted { id: MIGRATION_ID },
+			));
+			assert_eq!(progress().cursor, None);
+
+			AAvatars::on_idle(1, MIGRATION_STEP_WEIGHT * 2);
+			assert_eq!(MigratedAvatars::get().len(), 2);
+			assert_eq!(progress().steps, 2);
+
+			AAvatars::on_idle(2, MIGRATION_STEP_WEIGHT * 2);
+			assert_eq!(MigratedAvatars::get().len(), 4);
+			assert_eq!(progress().steps, 4);
+
+			AAvatars::on_idle(3, MIGRATION_STEP_WEIGHT * 2);
+			assert_eq!(OngoingMigration::<Test>::get(), None);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::MigrationCompleted { id: MIGRATION_ID, steps: 6 },
+			));
+
+			let mut migrated = MigratedAvatars::get();
+			migrated.sort();
+			avatar_ids.sort();
+			assert_eq!(migrated, avatar_ids);
+		});
+	}
+
+	#[test]
+	fn stepped_migration_waits_for_enough_weight() {
+		ExtBuilder::default().build().execute_with(|| {
+			create_avatars(SEASON_ID, ALICE, 2);
+			StartSteppedMigration::<Test>::on_runtime_upgrade();
+
+			AAvatars::on_idle(1, MIGRATION_STEP_WEIGHT / 2);
+			assert_eq!(MigratedAvatars::get().len(), 0);
+			assert_eq!(progress().steps, 0);
+
+			AAvatars::on_idle(2, MIGRATION_STEP_WEIGHT);
+			assert_eq!(MigratedAvatars::get().len(), 1);
+			assert_eq!(progress().steps, 1);
+		});
+	}
+
+	#[test]
+	fn stepped_migration_is_not_restarted_while_in_progress() {
+		ExtBuilder::default().build().execute_with(|| {
+			create_avatars(SEASON_ID, ALICE, 3);
+			StartSteppedMigration::<Test>::on_runtime_upgrade();
+			AAvatars::on_idle(1, MIGRATION_STEP_WEIGHT);
+			let progress_before = progress();
+
+			StartSteppedMigration::<Test>::on_runtime_upgrade();
+			assert_eq!(progress(), progress_before);
+		});
+	}
+
+	#[test]
+	fn failed_stepped_migration_stops() {
+		ExtBuilder::default().build().execute_with(|| {
+			create_avatars(SEASON_ID, ALICE, 3);
+			StartSteppedMigration::<Test>::on_runtime_upgrade();
+			AAvatars::on_idle(1, MIGRATION_STEP_WEIGHT);
+
+			FailingMigration::set(true);
+			AAvatars::on_idle(2, MIGRATION_STEP_WEIGHT);
+			assert!(progress().failed);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::MigrationFailed { id: MIGRATION_ID, steps: 1 },
+			));
+
+			FailingMigration::set(false);
+			AAvatars::on_idle(3, MIGRATION_STEP_WEIGHT * 10);
+			assert_eq!(MigratedAvatars::get().len(), 1);
+			assert!(progress().failed);
+		});
+	}
+
+	#[test]
+	fn failed_stepped_migration_can_be_retried() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let mut avatar_ids = create_avatars(SEASON_ID, ALICE, 3);
+			StartSteppedMigration::<Test>::on_runtime_upgrade();
+			AAvatars::on_idle(1, MIGRATION_STEP_WEIGHT);
+
+			assert_noop!(
+				AAvatars::retry_migration(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::MigrationNotFailed
+			);
+
+			FailingMigration::set(true);
+			AAvatars::on_idle(2, MIGRATION_STEP_WEIGHT);
+			assert!(progress().failed);
+
+			FailingMigration::set(false);
+			assert_noop!(
+				AAvatars::retry_migration(RuntimeOrigin::signed(BOB)),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(AAvatars::retry_migration(RuntimeOrigin::signed(ALICE)));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::MigrationRetried { id: MIGRATION_ID },
+			));
+			assert!(!progress().failed);
+
+			AAvatars::on_idle(3, MIGRATION_STEP_WEIGHT * 10);
+			assert_eq!(OngoingMigration::<Test>::get(), None);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::MigrationCompleted { id: MIGRATION_ID, steps: 4 },
+			));
+
+			let mut migrated = MigratedAvatars::get();
+			migrated.sort();
+			avatar_ids.sort();
+			assert_eq!(migrated, avatar_ids);
+		});
+	}
+
+	#[test]
+	fn failed_stepped_migration_can_be_aborted() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 12)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+
+				StartSteppedMigration::<Test>::on_runtime_upgrade();
+				FailingMigration::set(true);
+				AAvatars::on_idle(1, MIGRATION_STEP_WEIGHT);
+				assert!(progress().failed);
+				assert_noop!(
+					mint_pack(ALICE, MintPackSize::Six, MintPayment::Free),
+					Error::<Test>::MigrationInProgress
+				);
+
+				FailingMigration::set(false);
+				assert_noop!(
+					AAvatars::abort_migration(RuntimeOrigin::signed(BOB)),
+					DispatchError::BadOrigin
+				);
+				assert_ok!(AAvatars::abort_migration(RuntimeOrigin::signed(ALICE)));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::MigrationAborted { id: MIGRATION_ID, steps: 0 },
+				));
+				assert_eq!(OngoingMigration::<Test>::get(), None);
+				assert_noop!(
+					AAvatars::abort_migration(RuntimeOrigin::signed(ALICE)),
+					Error::<Test>::NoMigrationInProgress
+				);
+				assert_noop!(
+					AAvatars::retry_migration(RuntimeOrigin::signed(ALICE)),
+					Error::<Test>::NoMigrationInProgress
+				);
+
+				assert_ok!(mint_pack(ALICE, MintPackSize::Six, MintPayment::Free));
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..5].to_vec()
+				));
+			});
+	}
+
+	#[test]
+	fn minting_and_forging_are_paused_during_stepped_migration() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 12)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption {
+						pack_size: MintPackSize::Six,
+						payment: MintPayment::Free,
+						pack_type: PackType::Material,
+					}
+				));
+				let avatar_ids = Owners::<Test>::get(ALICE, SEASON_ID);
+
+				StartSteppedMigration::<Test>::on_runtime_upgrade();
+				assert_noop!(
+					AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption {
+							pack_size: MintPackSize::Six,
+							payment: MintPayment::Free,
+							pack_type: PackType::Material,
+						}
+					),
+					Error::<Test>::MigrationInProgress
+				);
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						avatar_ids[0],
+						avatar_ids[1..5].to_vec()
+					),
+					Error::<Test>::MigrationInProgress
+				);
+			});
+	}
+}
+
+mod season_archive {
+	use super::*;
+	use frame_support::{traits::Hooks, weights::Weight};
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	fn archive() -> SeasonArchiveOf<Test> {
+		SeasonArchives::<Test>::get(SEASON_ID).unwrap()
+	}
+
+	#[test]
+	fn archive_season_prunes_per_season_data() {
+		let deposit = 5;
+		let season = Season::default().avatar_deposit(deposit);
+		let initial_balance = MockExistentialDeposit::get() + 6 * deposit;
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(BOB, initial_balance)])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_ids[0], 10));
+				assert_eq!(Balances::reserved_balance(BOB), 6 * deposit);
+
+				run_to_block(season.end + 1);
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 100));
+
+				let summary = SeasonSummary {
+					players: 1,
+					minted: 6,
+					forged: 0,
+					leaderboard: (H256::default(), BOB, SeasonInfo { minted: 6, forged: 0 })
+						.using_encoded(BlakeTwo256::hash),
+				};
+				assert_eq!(
+					archive(),
+					SeasonArchive { stage: ArchiveStage::Archived, summary: summary.clone() }
+				);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::SeasonArchived { season_id: SEASON_ID, summary },
+				));
+
+				assert_eq!(SeasonStats::<Test>::iter_prefix(SEASON_ID).count(), 0);
+				assert_eq!(Trade::<Test>::iter_prefix(SEASON_ID).count(), 0);
+				assert!(!PlayerSeasonConfigs::<Test>::contains_key(BOB, SEASON_ID));
+
+				// Avatars are kept, but their ownership lists are pruned and deposits refunded.
+				assert!(!Owners::<Test>::contains_key(BOB, SEASON_ID));
+				assert!(!SeasonPlayers::<Test>::contains_key(SEASON_ID, BOB));
+				assert!(avatar_ids.iter().all(|id| Avatars::<Test>::get(id).is_some()));
+				assert!(avatar_ids.iter().all(|id| AvatarDeposits::<Test>::get(id).is_none()));
+				assert_eq!(Balances::reserved_balance(BOB), 0);
+				assert_eq!(Balances::free_balance(BOB), initial_balance);
+			});
+	}
+
+	#[test]
+	fn archive_season_refunds_deposits_of_transferred_avatars() {
+		let deposit = 5;
+		let transfer_fee = 1;
+		let season = Season::default().avatar_deposit(deposit).transfer_avatar_fee(transfer_fee);
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[
+				(BOB, MockExistentialDeposit::get() + 6 * deposit + transfer_fee),
+				(CHARLIE, MockExistentialDeposit::get() + deposit),
+			])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+				assert_ok!(AAvatars::transfer_avatar(
+					RuntimeOrigin::signed(BOB),
+					CHARLIE,
+					avatar_ids[0]
+				));
+				assert_eq!(Balances::reserved_balance(CHARLIE), deposit);
+
+				run_to_block(season.end + 1);
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 1));
+				// Forging is blocked as soon as the archival starts.
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(BOB),
+						avatar_ids[1],
+						avatar_ids[2..6].to_vec()
+					),
+					Error::<Test>::SeasonArchived
+				);
+
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 100));
+				assert!(archive().is_archived());
+				assert_eq!(archive().summary.players, 1);
+				for player in [BOB, CHARLIE] {
+					assert!(!Owners::<Test>::contains_key(player, SEASON_ID));
+					assert_eq!(Balances::reserved_balance(player), 0);
+				}
+				assert_eq!(SeasonPlayers::<Test>::iter_prefix(SEASON_ID).count(), 0);
+				assert_eq!(AvatarDeposits::<Test>::iter().count(), 0);
+			});
+	}
+
+	#[test]
+	fn archive_season_resumes_across_calls() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(BOB, 6), (CHARLIE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+				mint_avatars(CHARLIE, MintPackSize::Six, MintPayment::Free);
+
+				run_to_block(season.end + 1);
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 1));
+				assert_eq!(archive().stage, ArchiveStage::SeasonStats);
+				assert_eq!(archive().summary.players, 1);
+				assert_eq!(SeasonStats::<Test>::iter_prefix(SEASON_ID).count(), 1);
+
+				let mut calls = 1;
+				while !archive().is_archived() {
+					assert_ok!(AAvatars::archive_season(
+						RuntimeOrigin::signed(ALICE),
+						SEASON_ID,
+						1
+					));
+					calls += 1;
+				}
+				assert!(calls > 4);
+				assert_eq!(archive().summary.players, 2);
+				assert_eq!(archive().summary.minted, 12);
+				assert!(!PlayerSeasonConfigs::<Test>::contains_key(BOB, SEASON_ID));
+				assert!(!PlayerSeasonConfigs::<Test>::contains_key(CHARLIE, SEASON_ID));
+			});
+	}
+
+	#[test]
+	fn seasons_are_archived_automatically_after_grace_period() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+
+				run_to_block(season.end + ArchiveGracePeriod::get() - 1);
+				AAvatars::on_idle(System::block_number(), Weight::MAX);
+				assert_eq!(SeasonArchives::<Test>::get(SEASON_ID), None);
+				assert_eq!(NextSeasonToArchive::<Test>::get(), 0);
+
+				run_to_block(season.end + ArchiveGracePeriod::get());
+				AAvatars::on_idle(System::block_number(), Weight::MAX);
+				assert!(archive().is_archived());
+				assert_eq!(NextSeasonToArchive::<Test>::get(), SEASON_ID + 1);
+				assert!(!PlayerSeasonConfigs::<Test>::contains_key(BOB, SEASON_ID));
+			});
+	}
+
+	#[test]
+	fn archive_season_prunes_configs_of_players_without_stats() {
+		let season = Season::default().upgrade_storage_fee(1);
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.balances(&[(BOB, MockExistentialDeposit::get() + 1)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(BOB), None, None));
+				assert!(PlayerSeasonConfigs::<Test>::contains_key(BOB, SEASON_ID));
+				assert_eq!(SeasonStats::<Test>::iter_prefix(SEASON_ID).count(), 0);
+
+				run_to_block(season.end + 1);
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 100));
+				assert!(archive().is_archived());
+				assert_eq!(archive().summary.players, 0);
+				assert!(!PlayerSeasonConfigs::<Test>::contains_key(BOB, SEASON_ID));
+				assert_eq!(SeasonPlayers::<Test>::iter_prefix(SEASON_ID).count(), 0);
+			});
+	}
+
+	#[test]
+	fn season_archived_event_is_emitted_once() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+
+				run_to_block(season.end + 1);
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 1));
+				assert!(!archive().is_archived());
+				// Trading is blocked as soon as the archival starts.
+				assert_noop!(
+					AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_ids[0], 10),
+					Error::<Test>::SeasonArchived
+				);
+
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 100));
+				assert!(archive().is_archived());
+
+				// Archived seasons are skipped, even before their grace period passes.
+				System::reset_events();
+				AAvatars::on_idle(System::block_number(), Weight::MAX);
+				assert_eq!(NextSeasonToArchive::<Test>::get(), SEASON_ID + 1);
+
+				run_to_block(season.end + ArchiveGracePeriod::get());
+				AAvatars::on_idle(System::block_number(), Weight::MAX);
+				assert!(!System::events().iter().any(|record| matches!(
+					record.event,
+					mock::RuntimeEvent::AAvatars(crate::Event::SeasonArchived { .. })
+				)));
+			});
+	}
+
+	#[test]
+	fn archive_season_rejects_invalid_requests() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(BOB, MintPackSize::Six, MintPayment::Free);
+
+				assert_noop!(
+					AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 100),
+					Error::<Test>::SeasonNotFinished
+				);
+
+				run_to_block(season.end + 1);
+				assert_noop!(
+					AAvatars::archive_season(RuntimeOrigin::signed(BOB), SEASON_ID, 100),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID + 1, 100),
+					Error::<Test>::UnknownSeason
+				);
+
+				assert_ok!(AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 100));
+				assert_noop!(
+					AAvatars::archive_season(RuntimeOrigin::signed(ALICE), SEASON_ID, 100),
+					Error::<Test>::SeasonAlreadyArchived
+				);
+				assert_noop!(
+					AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_ids[0], 10),
+					Error::<Test>::SeasonArchived
+				);
+			});
+	}
+}
+
+mod forge_weight {
+	use super::*;
+	use crate::weights::WeightInfo;
+
+	#[test]
+	fn forge_charges_upper_bound_of_all_forge_types() {
+		let max_weight = AAvatars::max_forge_weight();
+		assert!(max_weight.all_gte(<Test as Config>::WeightInfo::forge(MaxAvatarsPerPlayer::get())));
+		assert!(max_weight.all_gte(<Test as Config>::WeightInfo::forge_build()));
+		for n in 1..=4 {
+			assert!(max_weight.all_gte(<Test as Config>::WeightInfo::forge_assemble(n)));
+			assert!(max_weight.all_gte(<Test as Config>::WeightInfo::forge_glimmer(n)));
+		}
+	}
+
+	#[test]
+	fn forge_returns_weight_of_first_generation_forge() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+
+				let post_info = AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..5].to_vec(),
+				)
+				.unwrap();
+				assert_eq!(post_info.actual_weight, Some(<Test as Config>::WeightInfo::forge(6)));
+			});
+	}
+
+	#[test]
+	fn forge_returns_weight_of_resolved_forge_type() {
+		let season = Season::default()
+			.mint_logic(LogicGeneration::Second)
+			.forge_logic(LogicGeneration::Second);
+
+		ExtBuilder::default()
+			.seasons(&[(SEASON_ID, season.clone())])
+			.free_mints(&[(ALICE, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = mint_avatars(ALICE, MintPackSize::Six, MintPayment::Free);
+				let leader = Avatars::<Test>::get(avatar_ids[0]).unwrap().1;
+				let sacrifices = avatar_ids[1..3]
+					.iter()
+					.map(|avatar_id| Avatars::<Test>::get(avatar_id).unwrap().1)
+					.collect::<Vec<_>>();
+				let forge_type = AAvatars::forge_type_of(&leader, &sacrifices);
+
+				let post_info = AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					avatar_ids[0],
+					avatar_ids[1..3].to_vec(),
+				)
+				.unwrap();
+				assert_eq!(post_info.actual_weight, Some(AAvatars::forge_weight(&forge_type, 2)));
+				assert!(AAvatars::max_forge_weight().all_gte(post_info.actual_weight.unwrap()));
+			});
+	}
+}
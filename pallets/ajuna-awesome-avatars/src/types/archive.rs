@@ -0,0 +1,66 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::types::Stat;
+use frame_support::pallet_prelude::*;
+
+/// Per-season storage pruned by each stage of an archival, in order.
+///
+/// Every stage drains a map keyed by season first, so each step touches a single entry of the
+/// archived season regardless of how many other seasons there are. The `Players` stage releases
+/// the deposit of a single avatar per step, pruning a player's avatars and config along with the
+/// last one.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ArchiveStage {
+	#[default]
+	SeasonStats,
+	Trade,
+	Players,
+	Archived,
+}
+
+impl ArchiveStage {
+	pub(crate) fn next(self) -> Self {
+		match self {
+			Self::SeasonStats => Self::Trade,
+			Self::Trade => Self::Players,
+			Self::Players | Self::Archived => Self::Archived,
+		}
+	}
+}
+
+/// Compact summary kept for a season once its per-season data is pruned.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+pub struct SeasonSummary<Hash> {
+	pub players: u32,
+	pub minted: Stat,
+	pub forged: Stat,
+	/// Hash chained over the stats of every player, in storage order.
+	pub leaderboard: Hash,
+}
+
+/// Progress of a season's archival, along with the summary collected so far.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+pub struct SeasonArchive<Hash> {
+	pub stage: ArchiveStage,
+	pub summary: SeasonSummary<Hash>,
+}
+
+impl<Hash> SeasonArchive<Hash> {
+	pub fn is_archived(&self) -> bool {
+		self.stage == ArchiveStage::Archived
+	}
+}
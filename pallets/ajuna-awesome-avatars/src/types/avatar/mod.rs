@@ -22,9 +22,10 @@ mod versions;
 pub use force::*;
 pub use rarity_tier::*;
 pub(crate) use versions::*;
+pub use versions::{ForgeType, ItemType};
 
 use frame_support::pallet_prelude::*;
-use sp_std::{ops::Range, prelude::*};
+use sp_std::prelude::*;
 
 pub type IpfsUrl = BoundedVec<u8, MaxIpfsUrl>;
 pub struct MaxIpfsUrl;
@@ -70,29 +71,23 @@ impl Avatar {
 		}
 	}
 
+	pub fn rarity_tier(&self) -> RarityTier {
+		RarityTier::from_byte(self.rarity())
+	}
+
 	pub(crate) fn force(&self) -> u8 {
 		match self.encoding {
 			DnaEncoding::V1 => AttributeMapperV1::force(self),
 			DnaEncoding::V2 => AttributeMapperV2::force(self),
 		}
 	}
-}
-
-pub(crate) trait ByteConvertible: Clone {
-	fn from_byte(byte: u8) -> Self;
-	fn as_byte(&self) -> u8;
-}
-
-impl ByteConvertible for u8 {
-	fn from_byte(byte: u8) -> Self {
-		byte
-	}
 
-	fn as_byte(&self) -> u8 {
-		*self
+	pub fn item_type(&self) -> Option<ItemType> {
+		match self.encoding {
+			DnaEncoding::V1 => None,
+			DnaEncoding::V2 => Some(AttributeMapperV2::item_type(self)),
+		}
 	}
 }
 
-pub(crate) trait Ranged {
-	fn range() -> Range<usize>;
-}
+pub(crate) use pallet_ajuna_awesome_avatars_dna::{ByteConvertible, Ranged};
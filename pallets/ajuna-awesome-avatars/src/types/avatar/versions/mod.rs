@@ -2,7 +2,8 @@ mod v1;
 mod v2;
 
 pub(crate) use v1::{AttributeMapperV1, ForgerV1, MinterV1};
-pub(crate) use v2::{AttributeMapperV2, ForgerV2, MinterV2};
+pub(crate) use v2::{AttributeMapperV2, ForgerV2, MinterV2, MAX_SACRIFICE};
+pub use v2::{ForgeType, ItemType};
 
 use crate::*;
 use frame_support::pallet_prelude::*;
@@ -20,7 +21,7 @@ pub(crate) trait Minter<T: Config> {
 	fn mint(
 		player: &T::AccountId,
 		season_id: &SeasonId,
-		mint_option: &MintOption,
+		mint_option: &MintOptionOf<T>,
 	) -> Result<Vec<AvatarIdOf<T>>, DispatchError>;
 }
 
@@ -6,11 +6,11 @@ pub(crate) struct AttributeMapperV1;
 
 impl AttributeMapper for AttributeMapperV1 {
 	fn rarity(target: &Avatar) -> u8 {
-		target.dna.iter().map(|x| *x >> 4).min().unwrap_or_default()
+		pallet_ajuna_awesome_avatars_dna::v1::rarity(&target.dna)
 	}
 
 	fn force(target: &Avatar) -> u8 {
-		(target.dna.last().unwrap_or(&0) & 0b0000_1111).saturating_add(1)
+		pallet_ajuna_awesome_avatars_dna::v1::force(&target.dna)
 	}
 }
 
@@ -20,7 +20,7 @@ impl<T: Config> Minter<T> for MinterV1<T> {
 	fn mint(
 		player: &T::AccountId,
 		season_id: &SeasonId,
-		mint_option: &MintOption,
+		mint_option: &MintOptionOf<T>,
 	) -> Result<Vec<AvatarIdOf<T>>, DispatchError> {
 		let is_batched = mint_option.pack_size.is_batched();
 		let season = Seasons::<T>::get(season_id).ok_or(Error::<T>::UnknownSeason)?;
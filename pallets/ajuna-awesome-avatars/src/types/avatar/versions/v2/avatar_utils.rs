@@ -4,6 +4,7 @@ use crate::{
 	ByteConvertible, Config, Force, Ranged, RarityTier,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_ajuna_awesome_avatars_dna::v2 as layout;
 use sp_runtime::{traits::Hash, SaturatedConversion};
 use sp_std::{
 	cmp::Ordering,
@@ -294,45 +295,7 @@ impl WrappedAvatar {
 	}
 }
 
-#[derive(Copy, Clone)]
-pub enum AvatarAttr {
-	ItemType,
-	ItemSubType,
-	ClassType1,
-	ClassType2,
-	CustomType1,
-	CustomType2,
-	RarityTier,
-	Quantity,
-}
-
-#[derive(Copy, Clone)]
-pub enum SpecIdx {
-	Byte1,
-	Byte2,
-	Byte3,
-	Byte4,
-	Byte5,
-	Byte6,
-	Byte7,
-	Byte8,
-	#[allow(dead_code)]
-	Byte9,
-	#[allow(dead_code)]
-	Byte10,
-	#[allow(dead_code)]
-	Byte11,
-	#[allow(dead_code)]
-	Byte12,
-	#[allow(dead_code)]
-	Byte13,
-	#[allow(dead_code)]
-	Byte14,
-	#[allow(dead_code)]
-	Byte15,
-	#[allow(dead_code)]
-	Byte16,
-}
+pub use pallet_ajuna_awesome_avatars_dna::v2::{AvatarAttr, SpecIdx};
 
 #[derive(Default)]
 pub(crate) struct AvatarBuilder {
@@ -849,47 +812,20 @@ impl AvatarBuilder {
 pub(crate) struct DnaUtils;
 
 impl DnaUtils {
-	fn read_strand(avatar: &Avatar, position: usize, byte_type: ByteType) -> u8 {
-		Self::read_at(avatar.dna.as_slice(), position, byte_type)
-	}
-
-	fn write_strand(avatar: &mut Avatar, position: usize, byte_type: ByteType, value: u8) {
-		match byte_type {
-			ByteType::Full => avatar.dna[position] = value,
-			ByteType::High =>
-				avatar.dna[position] =
-					(avatar.dna[position] & (ByteType::High as u8)) | (value << 4),
-			ByteType::Low =>
-				avatar.dna[position] = (avatar.dna[position] & (ByteType::Low as u8)) |
-					(value & (ByteType::High as u8)),
-		}
-	}
-
 	fn read_at(dna: &[u8], position: usize, byte_type: ByteType) -> u8 {
-		match byte_type {
-			ByteType::Full => dna[position],
-			ByteType::High => Self::high_nibble_of(dna[position]),
-			ByteType::Low => Self::low_nibble_of(dna[position]),
-		}
+		layout::read_at(dna, position, byte_type)
 	}
 
 	fn write_at(dna: &mut [u8], position: usize, byte_type: ByteType, value: u8) {
-		match byte_type {
-			ByteType::Full => dna[position] = value,
-			ByteType::High =>
-				dna[position] = (dna[position] & (ByteType::High as u8)) | (value << 4),
-			ByteType::Low =>
-				dna[position] =
-					(dna[position] & (ByteType::Low as u8)) | (value & (ByteType::High as u8)),
-		}
+		layout::write_at(dna, position, byte_type, value)
 	}
 
 	pub fn high_nibble_of(byte: u8) -> u8 {
-		byte >> 4
+		layout::high_nibble_of(byte)
 	}
 
 	pub fn low_nibble_of(byte: u8) -> u8 {
-		byte & 0x0F
+		layout::low_nibble_of(byte)
 	}
 
 	pub fn read_attribute<T>(avatar: &Avatar, attribute: AvatarAttr) -> T
@@ -900,16 +836,7 @@ impl DnaUtils {
 	}
 
 	pub fn read_attribute_raw(avatar: &Avatar, attribute: AvatarAttr) -> u8 {
-		match attribute {
-			AvatarAttr::ItemType => Self::read_strand(avatar, 0, ByteType::High),
-			AvatarAttr::ItemSubType => Self::read_strand(avatar, 0, ByteType::Low),
-			AvatarAttr::ClassType1 => Self::read_strand(avatar, 1, ByteType::High),
-			AvatarAttr::ClassType2 => Self::read_strand(avatar, 1, ByteType::Low),
-			AvatarAttr::CustomType1 => Self::read_strand(avatar, 2, ByteType::High),
-			AvatarAttr::CustomType2 => Self::read_strand(avatar, 4, ByteType::Full),
-			AvatarAttr::RarityTier => Self::read_strand(avatar, 2, ByteType::Low),
-			AvatarAttr::Quantity => Self::read_strand(avatar, 3, ByteType::Full),
-		}
+		layout::read_attribute(avatar.dna.as_slice(), attribute)
 	}
 
 	pub fn write_attribute<T>(avatar: &mut Avatar, attribute: AvatarAttr, value: &T)
@@ -920,43 +847,15 @@ impl DnaUtils {
 	}
 
 	pub fn write_attribute_raw(avatar: &mut Avatar, attribute: AvatarAttr, value: u8) {
-		match attribute {
-			AvatarAttr::ItemType => Self::write_strand(avatar, 0, ByteType::High, value),
-			AvatarAttr::ItemSubType => Self::write_strand(avatar, 0, ByteType::Low, value),
-			AvatarAttr::ClassType1 => Self::write_strand(avatar, 1, ByteType::High, value),
-			AvatarAttr::ClassType2 => Self::write_strand(avatar, 1, ByteType::Low, value),
-			AvatarAttr::CustomType1 => Self::write_strand(avatar, 2, ByteType::High, value),
-			AvatarAttr::CustomType2 => Self::write_strand(avatar, 4, ByteType::Full, value),
-			AvatarAttr::RarityTier => Self::write_strand(avatar, 2, ByteType::Low, value),
-			AvatarAttr::Quantity => Self::write_strand(avatar, 3, ByteType::Full, value),
-		}
+		layout::write_attribute(&mut avatar.dna[..], attribute, value)
 	}
 
 	pub fn read_specs(avatar: &Avatar) -> [u8; 16] {
-		let mut out = [0; 16];
-		out.copy_from_slice(&avatar.dna[5..21]);
-		out
+		layout::read_specs(avatar.dna.as_slice())
 	}
 
 	pub fn read_spec_raw(avatar: &Avatar, index: SpecIdx) -> u8 {
-		match index {
-			SpecIdx::Byte1 => Self::read_strand(avatar, 5, ByteType::Full),
-			SpecIdx::Byte2 => Self::read_strand(avatar, 6, ByteType::Full),
-			SpecIdx::Byte3 => Self::read_strand(avatar, 7, ByteType::Full),
-			SpecIdx::Byte4 => Self::read_strand(avatar, 8, ByteType::Full),
-			SpecIdx::Byte5 => Self::read_strand(avatar, 9, ByteType::Full),
-			SpecIdx::Byte6 => Self::read_strand(avatar, 10, ByteType::Full),
-			SpecIdx::Byte7 => Self::read_strand(avatar, 11, ByteType::Full),
-			SpecIdx::Byte8 => Self::read_strand(avatar, 12, ByteType::Full),
-			SpecIdx::Byte9 => Self::read_strand(avatar, 13, ByteType::Full),
-			SpecIdx::Byte10 => Self::read_strand(avatar, 14, ByteType::Full),
-			SpecIdx::Byte11 => Self::read_strand(avatar, 15, ByteType::Full),
-			SpecIdx::Byte12 => Self::read_strand(avatar, 16, ByteType::Full),
-			SpecIdx::Byte13 => Self::read_strand(avatar, 17, ByteType::Full),
-			SpecIdx::Byte14 => Self::read_strand(avatar, 18, ByteType::Full),
-			SpecIdx::Byte15 => Self::read_strand(avatar, 19, ByteType::Full),
-			SpecIdx::Byte16 => Self::read_strand(avatar, 20, ByteType::Full),
-		}
+		layout::read_spec(avatar.dna.as_slice(), index)
 	}
 
 	pub fn read_spec<T>(avatar: &Avatar, spec_byte: SpecIdx) -> T
@@ -967,38 +866,19 @@ impl DnaUtils {
 	}
 
 	pub fn write_specs(avatar: &mut Avatar, value: [u8; 16]) {
-		(avatar.dna[5..21]).copy_from_slice(&value);
+		layout::write_specs(&mut avatar.dna[..], value)
 	}
 
 	pub fn write_spec(avatar: &mut Avatar, spec_byte: SpecIdx, value: u8) {
-		match spec_byte {
-			SpecIdx::Byte1 => Self::write_strand(avatar, 5, ByteType::Full, value),
-			SpecIdx::Byte2 => Self::write_strand(avatar, 6, ByteType::Full, value),
-			SpecIdx::Byte3 => Self::write_strand(avatar, 7, ByteType::Full, value),
-			SpecIdx::Byte4 => Self::write_strand(avatar, 8, ByteType::Full, value),
-			SpecIdx::Byte5 => Self::write_strand(avatar, 9, ByteType::Full, value),
-			SpecIdx::Byte6 => Self::write_strand(avatar, 10, ByteType::Full, value),
-			SpecIdx::Byte7 => Self::write_strand(avatar, 11, ByteType::Full, value),
-			SpecIdx::Byte8 => Self::write_strand(avatar, 12, ByteType::Full, value),
-			SpecIdx::Byte9 => Self::write_strand(avatar, 13, ByteType::Full, value),
-			SpecIdx::Byte10 => Self::write_strand(avatar, 14, ByteType::Full, value),
-			SpecIdx::Byte11 => Self::write_strand(avatar, 15, ByteType::Full, value),
-			SpecIdx::Byte12 => Self::write_strand(avatar, 16, ByteType::Full, value),
-			SpecIdx::Byte13 => Self::write_strand(avatar, 17, ByteType::Full, value),
-			SpecIdx::Byte14 => Self::write_strand(avatar, 18, ByteType::Full, value),
-			SpecIdx::Byte15 => Self::write_strand(avatar, 19, ByteType::Full, value),
-			SpecIdx::Byte16 => Self::write_strand(avatar, 20, ByteType::Full, value),
-		}
+		layout::write_spec(&mut avatar.dna[..], spec_byte, value)
 	}
 
 	pub fn read_progress(avatar: &Avatar) -> [u8; 11] {
-		let mut out = [0; 11];
-		out.copy_from_slice(&avatar.dna[21..32]);
-		out
+		layout::read_progress(avatar.dna.as_slice())
 	}
 
 	pub fn write_progress(avatar: &mut Avatar, value: [u8; 11]) {
-		(avatar.dna[21..32]).copy_from_slice(&value);
+		layout::write_progress(&mut avatar.dna[..], value)
 	}
 
 	pub fn is_progress_match(
@@ -20,14 +20,20 @@ pub use types::*;
 
 use super::*;
 use crate::{
-	pallet::SeasonOf,
-	types::{MintOption, SeasonId},
+	pallet::{MintOptionOf, SeasonOf},
+	types::SeasonId,
 	Config,
 };
 use sp_runtime::DispatchError;
 
 pub(crate) struct AttributeMapperV2;
 
+impl AttributeMapperV2 {
+	pub(crate) fn item_type(target: &Avatar) -> ItemType {
+		DnaUtils::read_attribute(target, AvatarAttr::ItemType)
+	}
+}
+
 impl AttributeMapper for AttributeMapperV2 {
 	fn rarity(target: &Avatar) -> u8 {
 		DnaUtils::read_attribute_raw(target, AvatarAttr::RarityTier)
@@ -45,7 +51,7 @@ impl<T: Config> Minter<T> for MinterV2<T> {
 	fn mint(
 		player: &T::AccountId,
 		season_id: &SeasonId,
-		mint_option: &MintOption,
+		mint_option: &MintOptionOf<T>,
 	) -> Result<Vec<AvatarIdOf<T>>, DispatchError> {
 		let mut hash_provider =
 			HashProvider::<T, 32>::new(&Pallet::<T>::random_hash(b"avatar_minter_v2", player));
@@ -158,8 +164,9 @@ impl<T: Config> MinterV2<T> {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Ord, PartialOrd, Eq)]
-pub(crate) enum ForgeType {
+/// The kind of forge performed, determined by the leader and sacrifices of a forge.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, PartialEq, Ord, PartialOrd, Eq)]
+pub enum ForgeType {
 	None,
 	Stack,
 	Tinker,
@@ -228,6 +235,18 @@ impl<T: Config> Forger<T> for ForgerV2<T> {
 }
 
 impl<T: Config> ForgerV2<T> {
+	pub(crate) fn forge_type_of(leader: &Avatar, sacrifices: &[Avatar]) -> ForgeType {
+		let wrapped_leader = WrappedAvatar::new(leader.clone());
+		let wrapped_sacrifices = sacrifices
+			.iter()
+			.map(|avatar| WrappedAvatar::new(avatar.clone()))
+			.collect::<Vec<_>>();
+		Self::determine_forge_type(
+			&wrapped_leader,
+			wrapped_sacrifices.iter().collect::<Vec<_>>().as_slice(),
+		)
+	}
+
 	fn determine_forge_type(leader: &WrappedAvatar, sacrifices: &[&WrappedAvatar]) -> ForgeType {
 		match leader.get_item_type() {
 			ItemType::Pet => match leader.get_item_sub_type::<PetItemType>() {
@@ -41,12 +41,14 @@ impl MintPackSize {
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
-pub enum MintPayment {
+pub enum MintPayment<AssetId> {
 	/// Mint using free mint credits.
 	#[default]
 	Free,
 	/// Normal minting consuming currency.
 	Normal,
+	/// Minting paid with one of the assets accepted by the season.
+	Asset(AssetId),
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
@@ -59,20 +61,44 @@ pub enum PackType {
 
 /// Minting options
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
-pub struct MintOption {
+pub struct MintOption<AssetId> {
 	/// The choice of payment for minting.
-	pub payment: MintPayment,
+	pub payment: MintPayment<AssetId>,
 	/// The choice of pack to mint.
 	pub pack_type: PackType,
 	/// The number of avatars to mint.
 	pub pack_size: MintPackSize,
 }
 
+/// Recipient of the soul points obtained by dissolving avatars.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq, Eq)]
+pub enum DissolveTarget<AvatarId> {
+	/// Add the soul points to an avatar owned by the player.
+	Leader(AvatarId),
+	/// Add the soul points to the player's soul balance of the dissolved avatars' season.
+	Balance,
+}
+
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
 pub struct MintConfig<BlockNumber> {
 	pub open: bool,
 	pub cooldown: BlockNumber,
 	pub free_mint_fee_multiplier: MintCount,
+	/// Maximum number of avatars minted per block across all accounts. Zero disables the limit.
+	pub max_per_block: MintCount,
+}
+
+/// Verifies the identity of accounts, for seasons restricting early minting to them.
+pub trait IdentityVerifier<AccountId> {
+	/// Whether `who` has an identity with a positive judgement.
+	fn is_verified(who: &AccountId) -> bool;
+}
+
+/// Verifies no account, so that seasons requiring identities cannot be minted early.
+impl<AccountId> IdentityVerifier<AccountId> for () {
+	fn is_verified(_who: &AccountId) -> bool {
+		false
+	}
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
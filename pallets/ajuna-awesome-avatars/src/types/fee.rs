@@ -14,8 +14,12 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::types::MintPackSize;
-use frame_support::pallet_prelude::*;
+use crate::types::{ForgeType, MintCount, MintPackSize};
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::{fungibles, Preservation},
+};
+use sp_runtime::{traits::Zero, TokenError};
 
 /// Minting fee per pack of avatars.
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
@@ -35,12 +39,97 @@ impl<Balance> MintFees<Balance> {
 	}
 }
 
+/// Payment required to forge with a given forge type.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub enum ForgeFee<Balance> {
+	/// Currency paid into the season's treasury.
+	Currency(Balance),
+	/// Free mints consumed from the player's free mint balance.
+	FreeMints(MintCount),
+}
+
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
-pub struct Fee<Balance> {
+pub struct Fee<Balance, AssetId> {
 	pub mint: MintFees<Balance>,
+	/// Minting fees per pack for each asset accepted as payment besides the native currency.
+	pub mint_assets: BoundedVec<(AssetId, MintFees<Balance>), ConstU32<8>>,
 	pub transfer_avatar: Balance,
 	pub buy_minimum: Balance,
 	pub buy_percent: u8,
 	pub upgrade_storage: Balance,
 	pub prepare_avatar: Balance,
+	/// Amount reserved from the owner for each avatar created, released when the avatar is
+	/// removed from storage. A zero amount disables storage deposits.
+	pub avatar_deposit: Balance,
+	/// Fees charged for forging, per forge type. Forge types without an entry are free. Forges of
+	/// the first logic generation are treated as `ForgeType::None`.
+	pub forge: BoundedVec<(ForgeType, ForgeFee<Balance>), ConstU32<13>>,
+}
+
+impl<Balance: Clone, AssetId: PartialEq> Fee<Balance, AssetId> {
+	pub fn mint_asset_fee_for(
+		&self,
+		asset_id: &AssetId,
+		pack_size: &MintPackSize,
+	) -> Option<Balance> {
+		self.mint_assets
+			.iter()
+			.find(|(id, _)| id == asset_id)
+			.map(|(_, fees)| fees.clone().fee_for(pack_size))
+	}
+
+	pub fn forge_fee_for(&self, forge_type: &ForgeType) -> Option<&ForgeFee<Balance>> {
+		self.forge.iter().find(|(ty, _)| ty == forge_type).map(|(_, fee)| fee)
+	}
+}
+
+/// Moves fungible assets used to pay for mints.
+pub trait AssetPayment<AccountId, AssetId, Balance> {
+	/// The amount of `asset_id` held by `who`.
+	fn balance(asset_id: &AssetId, who: &AccountId) -> Balance;
+
+	/// Transfer `amount` of `asset_id` from `from` to `to`.
+	fn transfer(
+		asset_id: &AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+/// Accepts no assets, for runtimes without asset payments.
+impl<AccountId, AssetId, Balance: Zero> AssetPayment<AccountId, AssetId, Balance> for () {
+	fn balance(_asset_id: &AssetId, _who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	fn transfer(
+		_asset_id: &AssetId,
+		_from: &AccountId,
+		_to: &AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
+		Err(TokenError::UnknownAsset.into())
+	}
+}
+
+/// Pays with assets of a `fungibles` implementation, such as `pallet_assets`.
+pub struct FungiblesPayment<F>(PhantomData<F>);
+
+impl<AccountId, F> AssetPayment<AccountId, F::AssetId, F::Balance> for FungiblesPayment<F>
+where
+	F: fungibles::Mutate<AccountId>,
+{
+	fn balance(asset_id: &F::AssetId, who: &AccountId) -> F::Balance {
+		F::balance(asset_id.clone(), who)
+	}
+
+	fn transfer(
+		asset_id: &F::AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: F::Balance,
+	) -> DispatchResult {
+		F::transfer(asset_id.clone(), from, to, amount, Preservation::Expendable).map(|_| ())
+	}
 }
@@ -15,13 +15,23 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 mod account;
+mod archive;
 mod avatar;
 mod config;
 mod fee;
+mod quest;
+mod rental;
 mod season;
+mod sponsorship;
+mod trophy;
 
 pub use account::*;
+pub use archive::*;
 pub use avatar::*;
 pub use config::*;
 pub use fee::*;
+pub use quest::*;
+pub use rental::*;
 pub use season::*;
+pub use sponsorship::*;
+pub use trophy::*;
@@ -0,0 +1,101 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::types::{Avatar, ByteConvertible, ItemType, MintCount, PackType, RarityTier, Stat};
+use frame_support::pallet_prelude::*;
+
+pub type QuestId = u8;
+
+/// The maximum number of quests that can be defined for a season.
+pub const MAX_QUESTS: QuestId = 16;
+
+/// Condition a player must fulfil to complete a quest.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub enum QuestCondition {
+	/// Mint a number of packs of the given type.
+	MintPacks { pack_type: PackType, count: Stat },
+	/// Forge a number of times, optionally with a leader of the given item type.
+	Forge { item_type: Option<ItemType>, count: Stat },
+	/// Forge a leader, optionally of the given item type, up to at least the given rarity.
+	ReachRarity { item_type: Option<ItemType>, rarity: RarityTier },
+	/// Buy a number of avatars from other players.
+	Buy { count: Stat },
+}
+
+/// Reward paid to a player claiming a completed quest.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub enum QuestReward<Balance> {
+	/// Free mints credited to the player.
+	FreeMints(MintCount),
+	/// Tokens paid out of the season's treasury.
+	Tokens(Balance),
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct Quest<Balance> {
+	pub condition: QuestCondition,
+	pub reward: QuestReward<Balance>,
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+pub struct QuestProgress {
+	pub progress: Stat,
+	pub claimed: bool,
+}
+
+/// Player actions that can advance quests.
+pub(crate) enum QuestAction<'a> {
+	Mint(&'a PackType),
+	Forge(&'a Avatar),
+	Buy,
+}
+
+impl QuestCondition {
+	/// The progress required to complete the quest.
+	pub(crate) fn target(&self) -> Stat {
+		match self {
+			Self::MintPacks { count, .. } | Self::Forge { count, .. } | Self::Buy { count } =>
+				*count,
+			Self::ReachRarity { .. } => 1,
+		}
+	}
+
+	/// The progress made towards the quest by the given action.
+	pub(crate) fn progress_for(&self, action: &QuestAction) -> Stat {
+		let is_progressed = match (self, action) {
+			(Self::MintPacks { pack_type, .. }, QuestAction::Mint(minted_pack_type)) =>
+				pack_type == *minted_pack_type,
+			(Self::Forge { item_type, .. }, QuestAction::Forge(leader)) =>
+				Self::is_matching_item_type(item_type, leader),
+			(Self::ReachRarity { item_type, rarity }, QuestAction::Forge(leader)) =>
+				Self::is_matching_item_type(item_type, leader) &&
+					RarityTier::from_byte(leader.rarity()) >= *rarity,
+			(Self::Buy { .. }, QuestAction::Buy) => true,
+			_ => false,
+		};
+		Stat::from(is_progressed)
+	}
+
+	fn is_matching_item_type(item_type: &Option<ItemType>, avatar: &Avatar) -> bool {
+		item_type.is_none() || item_type == &avatar.item_type()
+	}
+}
+
+impl QuestProgress {
+	pub(crate) fn is_complete(&self, condition: &QuestCondition) -> bool {
+		self.progress >= condition.target()
+	}
+}
@@ -0,0 +1,61 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::types::Stat;
+use frame_support::pallet_prelude::*;
+use sp_runtime::traits::AtLeast32Bit;
+
+/// Terms under which an owner offers an avatar for rent.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct RentalTerms<BlockNumber, Balance> {
+	/// Rent paid upfront by the renter to the owner.
+	pub price: Balance,
+	/// Number of blocks the rental lasts for.
+	pub duration: BlockNumber,
+	/// Number of forges the renter may perform with the avatar, if limited.
+	pub max_forges: Option<Stat>,
+}
+
+/// Agreement letting a renter use someone else's avatar as a forge leader.
+///
+/// The owner keeps ownership of the avatar, but can't use it while the agreement is active. Rented
+/// avatars cannot be sacrificed, transferred or traded.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct RentalAgreement<AccountId, BlockNumber> {
+	pub renter: AccountId,
+	pub expires_at: BlockNumber,
+	/// Number of forges left to the renter, if limited.
+	pub forges_left: Option<Stat>,
+}
+
+impl<AccountId, BlockNumber: AtLeast32Bit + Copy> RentalAgreement<AccountId, BlockNumber> {
+	pub(crate) fn new<Balance>(
+		renter: AccountId,
+		terms: &RentalTerms<BlockNumber, Balance>,
+		now: BlockNumber,
+	) -> Self {
+		Self {
+			renter,
+			expires_at: now.saturating_add(terms.duration),
+			forges_left: terms.max_forges,
+		}
+	}
+
+	/// Whether the rental is over, after which the avatar reverts to its owner.
+	pub(crate) fn has_expired(&self, now: BlockNumber) -> bool {
+		now >= self.expires_at || self.forges_left == Some(0)
+	}
+}
@@ -15,12 +15,15 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	types::{fee::Fee, Avatar, LogicGeneration, RarityTier, SeasonId},
+	types::{
+		fee::Fee, Avatar, ByteConvertible, LogicGeneration, MintCount, RarityTier, SeasonId,
+		SoulCount, Stat,
+	},
 	Config, Error, MAX_PERCENTAGE,
 };
 use frame_support::pallet_prelude::*;
-use sp_runtime::traits::{AtLeast32Bit, UniqueSaturatedInto, Zero};
-use sp_std::borrow::ToOwned;
+use sp_runtime::traits::{AtLeast32Bit, Saturating, UniqueSaturatedInto, Zero};
+use sp_std::{borrow::ToOwned, prelude::*};
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Default, PartialEq)]
 pub struct SeasonStatus {
@@ -40,8 +43,33 @@ pub type RarityPercent = u8;
 pub type SacrificeCount = u8;
 pub type TradeFilter = u32;
 
+/// Caps on the number of avatars minted during a season, to slow down bots.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+pub struct MintLimits<BlockNumber> {
+	/// Length in blocks of the periods mints are capped over, starting at the season's early
+	/// start. Zero disables the per-period caps.
+	pub period: BlockNumber,
+	/// Maximum number of avatars minted per period across all accounts. Zero disables the cap.
+	pub max_per_period: Stat,
+	/// Maximum number of avatars minted per period by a single account. Zero disables the cap.
+	pub max_per_account: MintCount,
+	/// Whether accounts minting during the early phase must have a verified identity.
+	pub early_requires_identity: bool,
+}
+
+impl<BlockNumber: AtLeast32Bit + Copy> MintLimits<BlockNumber> {
+	/// The index of the period `now` falls into, if per-period caps are enabled.
+	pub(crate) fn period_of(
+		&self,
+		early_start: BlockNumber,
+		now: BlockNumber,
+	) -> Option<BlockNumber> {
+		(!self.period.is_zero()).then(|| now.saturating_sub(early_start) / self.period)
+	}
+}
+
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
-pub struct Season<BlockNumber, Balance> {
+pub struct Season<BlockNumber, Balance, AssetId> {
 	pub name: BoundedVec<u8, ConstU32<100>>,
 	pub description: BoundedVec<u8, ConstU32<1_000>>,
 	pub early_start: BlockNumber,
@@ -59,12 +87,18 @@ pub struct Season<BlockNumber, Balance> {
 	pub per_period: BlockNumber,
 	pub periods: u16,
 	pub trade_filters: BoundedVec<TradeFilter, ConstU32<100>>,
-	pub fee: Fee<Balance>,
+	/// Soul points obtained by dissolving an avatar of a given rarity. Avatars of rarities
+	/// missing from the table cannot be dissolved.
+	pub dissolve_souls: BoundedVec<(RarityTier, SoulCount), ConstU32<6>>,
+	/// Number of blocks a player must wait between two forges. Zero disables the cooldown.
+	pub forge_cooldown: BlockNumber,
+	pub mint_limits: MintLimits<BlockNumber>,
+	pub fee: Fee<Balance, AssetId>,
 	pub mint_logic: LogicGeneration,
 	pub forge_logic: LogicGeneration,
 }
 
-impl<BlockNumber: AtLeast32Bit, Balance> Season<BlockNumber, Balance> {
+impl<BlockNumber: AtLeast32Bit, Balance, AssetId: PartialEq> Season<BlockNumber, Balance, AssetId> {
 	pub(crate) fn is_active(&self, now: BlockNumber) -> bool {
 		now >= self.start && now <= self.end
 	}
@@ -79,6 +113,9 @@ impl<BlockNumber: AtLeast32Bit, Balance> Season<BlockNumber, Balance> {
 		self.validate_max_variations::<T>()?;
 		self.validate_max_components::<T>()?;
 		self.validate_tiers::<T>()?;
+		self.validate_dissolve_souls::<T>()?;
+		self.validate_forge_fees::<T>()?;
+		self.validate_mint_assets::<T>()?;
 		self.validate_percentages::<T>()?;
 		self.validate_periods::<T>()?;
 		Ok(())
@@ -95,6 +132,14 @@ impl<BlockNumber: AtLeast32Bit, Balance> Season<BlockNumber, Balance> {
 		self.tiers.clone().into_iter().max().unwrap_or_default()
 	}
 
+	pub fn dissolve_souls_of(&self, avatar: &Avatar) -> Option<SoulCount> {
+		let rarity = RarityTier::from_byte(avatar.rarity());
+		self.dissolve_souls
+			.iter()
+			.find(|(tier, _)| tier == &rarity)
+			.map(|(_, souls)| *souls)
+	}
+
 	pub(crate) fn is_tradable(&self, avatar: &Avatar) -> bool {
 		// No filter means we allow everything to be traded.
 		if self.trade_filters.is_empty() {
@@ -163,6 +208,34 @@ impl<BlockNumber: AtLeast32Bit, Balance> Season<BlockNumber, Balance> {
 		Ok(())
 	}
 
+	fn validate_dissolve_souls<T: Config>(&self) -> DispatchResult {
+		let l = self.dissolve_souls.len();
+		let mut tiers =
+			self.dissolve_souls.iter().map(|(tier, _)| tier.clone()).collect::<Vec<_>>();
+		tiers.sort();
+		tiers.dedup();
+		ensure!(l == tiers.len(), Error::<T>::DuplicatedRarityTier);
+		Ok(())
+	}
+
+	fn validate_forge_fees<T: Config>(&self) -> DispatchResult {
+		let l = self.fee.forge.len();
+		let mut forge_types = self.fee.forge.iter().map(|(ty, _)| ty.clone()).collect::<Vec<_>>();
+		forge_types.sort();
+		forge_types.dedup();
+		ensure!(l == forge_types.len(), Error::<T>::DuplicatedForgeType);
+		Ok(())
+	}
+
+	fn validate_mint_assets<T: Config>(&self) -> DispatchResult {
+		let assets = &self.fee.mint_assets;
+		let is_unique = assets.iter().enumerate().all(|(i, (asset_id, _))| {
+			assets.iter().skip(i + 1).all(|(other, _)| other != asset_id)
+		});
+		ensure!(is_unique, Error::<T>::DuplicatedMintAsset);
+		Ok(())
+	}
+
 	fn validate_percentages<T: Config>(&self) -> DispatchResult {
 		let p_1 = self
 			.single_mint_probs
@@ -211,7 +284,7 @@ mod test {
 	use crate::{mock::*, types::*};
 	use frame_support::{assert_err, assert_ok};
 
-	impl Default for Season<MockBlockNumber, MockBalance> {
+	impl Default for Season<MockBlockNumber, MockBalance, MockAssetId> {
 		fn default() -> Self {
 			Self {
 				name: b"cool season".to_vec().try_into().unwrap(),
@@ -240,13 +313,19 @@ mod test {
 				per_period: 10,
 				periods: 12,
 				trade_filters: BoundedVec::default(),
+				dissolve_souls: BoundedVec::default(),
+				forge_cooldown: 0,
+				mint_limits: MintLimits::default(),
 				fee: Fee {
 					mint: MintFees { one: 1, three: 2, six: 3 },
+					mint_assets: BoundedVec::default(),
 					transfer_avatar: Default::default(),
 					buy_minimum: Default::default(),
 					buy_percent: Default::default(),
 					upgrade_storage: Default::default(),
 					prepare_avatar: Default::default(),
+					avatar_deposit: Default::default(),
+					forge: BoundedVec::default(),
 				},
 				mint_logic: LogicGeneration::First,
 				forge_logic: LogicGeneration::First,
@@ -254,7 +333,7 @@ mod test {
 		}
 	}
 
-	impl Season<MockBlockNumber, MockBalance> {
+	impl Season<MockBlockNumber, MockBalance, MockAssetId> {
 		pub fn early_start(mut self, early_start: MockBlockNumber) -> Self {
 			self.early_start = early_start;
 			self
@@ -315,6 +394,10 @@ mod test {
 			self.trade_filters = trade_filters.try_into().unwrap();
 			self
 		}
+		pub fn dissolve_souls(mut self, dissolve_souls: &[(RarityTier, SoulCount)]) -> Self {
+			self.dissolve_souls = dissolve_souls.to_vec().try_into().unwrap();
+			self
+		}
 		pub fn mint_fee(mut self, fee: MintFees<MockBalance>) -> Self {
 			self.fee.mint = fee;
 			self
@@ -339,6 +422,30 @@ mod test {
 			self.fee.prepare_avatar = fee;
 			self
 		}
+		pub fn avatar_deposit(mut self, deposit: MockBalance) -> Self {
+			self.fee.avatar_deposit = deposit;
+			self
+		}
+		pub fn forge_cooldown(mut self, forge_cooldown: MockBlockNumber) -> Self {
+			self.forge_cooldown = forge_cooldown;
+			self
+		}
+		pub fn mint_limits(mut self, mint_limits: MintLimits<MockBlockNumber>) -> Self {
+			self.mint_limits = mint_limits;
+			self
+		}
+		pub fn mint_asset_fees(mut self, fees: &[(MockAssetId, MintFees<MockBalance>)]) -> Self {
+			self.fee.mint_assets = fees.to_vec().try_into().unwrap();
+			self
+		}
+		pub fn forge_fees(mut self, fees: &[(ForgeType, ForgeFee<MockBalance>)]) -> Self {
+			self.fee.forge = fees.to_vec().try_into().unwrap();
+			self
+		}
+		pub fn forge_logic(mut self, logic: LogicGeneration) -> Self {
+			self.forge_logic = logic;
+			self
+		}
 		pub fn mint_logic(mut self, logic: LogicGeneration) -> Self {
 			self.mint_logic = logic;
 			self
@@ -0,0 +1,27 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::*;
+
+/// Account paying the transaction fees of players for a season's sponsored calls.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct Sponsorship<AccountId, Balance> {
+	pub sponsor: AccountId,
+	/// Fees the sponsor is still willing to pay.
+	pub budget: Balance,
+	/// Maximum fees paid for a single player over the season.
+	pub max_per_account: Balance,
+}
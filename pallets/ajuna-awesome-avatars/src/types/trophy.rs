@@ -0,0 +1,33 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::types::{Dna, DnaEncoding, RarityTier, SeasonId, SoulCount};
+use frame_support::pallet_prelude::*;
+
+/// Soulbound record of an avatar retired from a finished season.
+///
+/// Trophies cannot be forged, traded or transferred, and don't take up any storage tier slots.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct Trophy<BlockNumber> {
+	pub season_id: SeasonId,
+	pub encoding: DnaEncoding,
+	pub dna: Dna,
+	pub souls: SoulCount,
+	pub rarity: RarityTier,
+	/// Whether the avatar reached the highest rarity tier of its season.
+	pub max_tier: bool,
+	pub retired_at: BlockNumber,
+}
@@ -54,6 +54,33 @@ pub trait WeightInfo {
 	fn prepare_avatar() -> Weight;
 	fn unprepare_avatar() -> Weight;
 	fn prepare_ipfs() -> Weight;
+	fn burn_avatar(n: u32, ) -> Weight;
+	fn dissolve(n: u32, ) -> Weight;
+	fn set_quest() -> Weight;
+	fn claim_quest_reward() -> Weight;
+	fn retire_avatars(n: u32, ) -> Weight;
+	fn claim_asset_treasury() -> Weight;
+	fn offer_rental() -> Weight;
+	fn cancel_rental_offer() -> Weight;
+	fn rent_avatar() -> Weight;
+	fn end_rental() -> Weight;
+	fn set_sponsorship() -> Weight;
+	fn archive_season(n: u32, ) -> Weight;
+	fn retry_migration() -> Weight;
+	fn abort_migration() -> Weight;
+	fn forge_none(n: u32, ) -> Weight;
+	fn forge_stack(n: u32, ) -> Weight;
+	fn forge_tinker(n: u32, ) -> Weight;
+	fn forge_build() -> Weight;
+	fn forge_assemble(n: u32, ) -> Weight;
+	fn forge_breed(n: u32, ) -> Weight;
+	fn forge_equip(n: u32, ) -> Weight;
+	fn forge_mate(n: u32, ) -> Weight;
+	fn forge_feed(n: u32, ) -> Weight;
+	fn forge_glimmer(n: u32, ) -> Weight;
+	fn forge_spark(n: u32, ) -> Weight;
+	fn forge_statue(n: u32, ) -> Weight;
+	fn forge_flask(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_ajuna_awesome_avatars using the Substrate node and recommended hardware.
@@ -77,6 +104,18 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
 	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars BlockMints (r:1 w:1)
+	/// Proof: AwesomeAvatars BlockMints (max_values: Some(1), max_size: Some(6), added: 501, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars PeriodMints (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AccountPeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars AccountPeriodMints (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:0 w:6)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Avatars (r:0 w:6)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 194]`.
@@ -88,8 +127,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 		Weight::from_parts(370_101_230, 9901)
 			// Standard Error: 126_241
 			.saturating_add(Weight::from_parts(854_307, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().writes(36_u64))
 	}
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
@@ -111,6 +150,18 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
 	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars BlockMints (r:1 w:1)
+	/// Proof: AwesomeAvatars BlockMints (max_values: Some(1), max_size: Some(6), added: 501, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars PeriodMints (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AccountPeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars AccountPeriodMints (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:0 w:6)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Avatars (r:0 w:6)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 194]`.
@@ -120,8 +171,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 		//  Estimated: `9901`
 		// Minimum execution time: 343_117_000 picoseconds.
 		Weight::from_parts(729_037_169, 9901)
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().reads(46_u64))
+			.saturating_add(T::DbWeight::get().writes(37_u64))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
@@ -181,6 +232,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 200]`.
 	fn transfer_avatar_normal(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -189,7 +242,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 		// Minimum execution time: 309_016_000 picoseconds.
 		Weight::from_parts(665_852_667, 18812)
 			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
@@ -213,6 +266,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 200]`.
 	fn transfer_avatar_organizer(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -221,7 +276,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 		// Minimum execution time: 303_703_000 picoseconds.
 		Weight::from_parts(722_069_457, 18812)
 			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
@@ -292,6 +347,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:2 w:2)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:2)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 200]`.
 	fn buy(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -302,7 +359,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			// Standard Error: 139_305
 			.saturating_add(Weight::from_parts(740_792, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 	}
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
@@ -314,6 +371,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	fn upgrade_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1517`
@@ -321,7 +380,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 		// Minimum execution time: 204_408_000 picoseconds.
 		Weight::from_parts(242_709_000, 7528)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: AwesomeAvatars Organizer (r:0 w:1)
 	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
@@ -446,6 +505,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
 	/// Storage: Nft Account (r:0 w:1)
 	/// Proof: Nft Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 200]`.
 	fn lock_avatar(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -456,7 +517,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			// Standard Error: 170_806
 			.saturating_add(Weight::from_parts(555_196, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(20_u64))
-			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
 	}
 	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
@@ -494,6 +555,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	/// Proof: Nft ItemAttributesApprovalsOf (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
 	/// Storage: Nft PendingSwapOf (r:0 w:1)
 	/// Proof: Nft PendingSwapOf (max_values: None, max_size: Some(127), added: 2602, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 200]`.
 	fn unlock_avatar(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -502,7 +565,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 		// Minimum execution time: 423_878_000 picoseconds.
 		Weight::from_parts(910_723_452, 18812)
 			.saturating_add(T::DbWeight::get().reads(20_u64))
-			.saturating_add(T::DbWeight::get().writes(19_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
 	}
 	/// Storage: AwesomeAvatars ServiceAccount (r:0 w:1)
 	/// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
@@ -569,73 +632,291 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
+	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:1 w:1)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn burn_avatar(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1526 + n * (32 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 93_418_000 picoseconds.
+		Weight::from_parts(118_326_401, 9901)
+			// Standard Error: 41_973
+			.saturating_add(Weight::from_parts(311_254, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:200 w:200)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:200 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:200 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:200 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:200 w:200)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SoulPoints (r:1 w:1)
+	/// Proof: AwesomeAvatars SoulPoints (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn dissolve(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1380 + n * (279 ±0)`
+		//  Estimated: `9901 + n * (2648 ±0)`
+		// Minimum execution time: 64_218_000 picoseconds.
+		Weight::from_parts(51_307_884, 9901)
+			// Standard Error: 19_871
+			.saturating_add(Weight::from_parts(28_641_207, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:0 w:1)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	fn set_quest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `5132`
+		// Minimum execution time: 18_402_000 picoseconds.
+		Weight::from_parts(19_127_000, 5132)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Quests (r:1 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(26), added: 2501, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_quest_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_711_000 picoseconds.
+		Weight::from_parts(65_283_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
-	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
-	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerConfigs (r:1 w:1)
-	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:200 w:200)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:200 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:200 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:200 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Owners (r:1 w:1)
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
-	/// Storage: Randomness RandomMaterial (r:1 w:0)
-	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:200 w:200)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
-	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:0 w:6)
-	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// The range of component `n` is `[0, 194]`.
-	fn mint_free(n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars Trophies (r:0 w:200)
+	/// Proof: AwesomeAvatars Trophies (max_values: None, max_size: Some(208), added: 2683, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn retire_avatars(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1954 + n * (32 ±0)`
-		//  Estimated: `9901`
-		// Minimum execution time: 233_210_000 picoseconds.
-		Weight::from_parts(370_101_230, 9901)
-			// Standard Error: 126_241
-			.saturating_add(Weight::from_parts(854_307, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(11_u64))
+		//  Measured:  `1412 + n * (279 ±0)`
+		//  Estimated: `9901 + n * (2683 ±0)`
+		// Minimum execution time: 61_906_000 picoseconds.
+		Weight::from_parts(49_802_112, 9901)
+			// Standard Error: 21_340
+			.saturating_add(Weight::from_parts(31_077_419, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
 	}
+	/// Storage: AwesomeAvatars Treasurer (r:1 w:0)
+	/// Proof: AwesomeAvatars Treasurer (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
-	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
-	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerConfigs (r:1 w:0)
-	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	/// Proof: AwesomeAvatars AssetTreasury (max_values: None, max_size: Some(22), added: 2497, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	fn claim_asset_treasury() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1903`
+		//  Estimated: `6208`
+		// Minimum execution time: 151_872_000 picoseconds.
+		Weight::from_parts(186_315_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars RentalOffers (r:0 w:1)
+	/// Proof: AwesomeAvatars RentalOffers (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn offer_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `868`
+		//  Estimated: `3775`
+		// Minimum execution time: 42_310_000 picoseconds.
+		Weight::from_parts(48_902_000, 3775)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars RentalOffers (r:1 w:1)
+	/// Proof: AwesomeAvatars RentalOffers (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn cancel_rental_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912`
+		//  Estimated: `3638`
+		// Minimum execution time: 30_871_000 picoseconds.
+		Weight::from_parts(35_224_000, 3638)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:1 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars RentalOffers (r:1 w:1)
+	/// Proof: AwesomeAvatars RentalOffers (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	fn rent_avatar() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1125`
+		//  Estimated: `6196`
+		// Minimum execution time: 86_445_000 picoseconds.
+		Weight::from_parts(98_137_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: AwesomeAvatars Rentals (r:1 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn end_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `410`
+		//  Estimated: `3522`
+		// Minimum execution time: 24_116_000 picoseconds.
+		Weight::from_parts(27_893_000, 3522)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(365), added: 2840, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Sponsorships (r:0 w:1)
+	/// Proof: AwesomeAvatars Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn set_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3830`
+		// Minimum execution time: 31_572_000 picoseconds.
+		Weight::from_parts(33_118_000, 3830)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(365), added: 2840, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonArchives (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonArchives (max_values: None, max_size: Some(334), added: 2809, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:101 w:100)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:100 w:100)
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
-	/// Storage: Randomness RandomMaterial (r:1 w:0)
-	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
-	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
-	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:0 w:6)
-	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// The range of component `n` is `[0, 194]`.
-	fn mint_normal(_n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars AvatarDeposits (r:100 w:100)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:0 w:100)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn archive_season(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2006 + n * (32 ±0)`
-		//  Estimated: `9901`
-		// Minimum execution time: 343_117_000 picoseconds.
-		Weight::from_parts(729_037_169, 9901)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(12_u64))
+		//  Measured:  `1294 + n * (44 ±0)`
+		//  Estimated: `6701 + n * (8911 ±0)`
+		// Minimum execution time: 38_962_000 picoseconds.
+		Weight::from_parts(41_254_000, 6701)
+			// Standard Error: 4_518
+			.saturating_add(Weight::from_parts(19_734_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:1)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	fn retry_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `381`
+		//  Estimated: `2537`
+		// Minimum execution time: 17_034_000 picoseconds.
+		Weight::from_parts(17_762_000, 2537)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:1)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	fn abort_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `381`
+		//  Estimated: `2537`
+		// Minimum execution time: 16_581_000 picoseconds.
+		Weight::from_parts(17_209_000, 2537)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
@@ -643,444 +924,2072 @@ impl WeightInfo for () {
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:5 w:5)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:5 w:0)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
 	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars LockedAvatars (r:5 w:0)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
 	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:5 w:0)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
 	/// Storage: Randomness RandomMaterial (r:1 w:0)
 	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Owners (r:1 w:1)
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
 	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
-	/// The range of component `n` is `[5, 200]`.
-	fn forge(n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_none(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3016 + n * (38 ±0)`
-		//  Estimated: `14230`
-		// Minimum execution time: 236_460_000 picoseconds.
-		Weight::from_parts(415_831_720, 14230)
-			// Standard Error: 161_670
-			.saturating_add(Weight::from_parts(2_022_927, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(28_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+		//  Measured:  `2400 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 150_000_000 picoseconds.
+		Weight::from_parts(193_118_207, 9901)
+			// Standard Error: 61_000
+			.saturating_add(Weight::from_parts(22_080_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
-	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
 	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
 	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
-	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Owners (r:2 w:2)
-	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
-	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 200]`.
-	fn transfer_avatar_normal(_n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_stack(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `15783`
-		//  Estimated: `18812`
-		// Minimum execution time: 309_016_000 picoseconds.
-		Weight::from_parts(665_852_667, 18812)
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		//  Measured:  `2417 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 110_211_000 picoseconds.
+		Weight::from_parts(154_355_618, 9901)
+			// Standard Error: 63_713
+			.saturating_add(Weight::from_parts(18_409_173, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
-	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
 	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
 	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
-	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Owners (r:2 w:2)
-	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
-	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 200]`.
-	fn transfer_avatar_organizer(_n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_tinker(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `15881`
-		//  Estimated: `18812`
-		// Minimum execution time: 303_703_000 picoseconds.
-		Weight::from_parts(722_069_457, 18812)
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		//  Measured:  `2434 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 125_422_000 picoseconds.
+		Weight::from_parts(170_593_029, 9901)
+			// Standard Error: 66_426
+			.saturating_add(Weight::from_parts(19_338_346, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerConfigs (r:2 w:2)
-	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
-	fn transfer_free_mints() -> Weight {
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:6 w:6)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:6 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:6 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn forge_build() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `129`
-		//  Estimated: `6008`
-		// Minimum execution time: 40_025_000 picoseconds.
-		Weight::from_parts(40_787_000, 6008)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `3221`
+		//  Estimated: `9901`
+		// Minimum execution time: 135_633_000 picoseconds.
+		Weight::from_parts(181_830_440, 9901)
+			.saturating_add(T::DbWeight::get().reads(63_u64))
+			.saturating_add(T::DbWeight::get().writes(30_u64))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
-	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
-	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:0 w:1)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
 	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-	fn set_price() -> Weight {
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_assemble(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2490`
-		//  Estimated: `5132`
-		// Minimum execution time: 129_226_000 picoseconds.
-		Weight::from_parts(152_923_000, 5132)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2468 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 130_844_000 picoseconds.
+		Weight::from_parts(178_067_851, 9901)
+			// Standard Error: 71_852
+			.saturating_add(Weight::from_parts(20_276_692, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:1 w:1)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
 	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-	fn remove_price() -> Weight {
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_breed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1197`
-		//  Estimated: `3638`
-		// Minimum execution time: 78_207_000 picoseconds.
-		Weight::from_parts(125_470_000, 3638)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2485 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 121_055_000 picoseconds.
+		Weight::from_parts(169_305_262, 9901)
+			// Standard Error: 74_565
+			.saturating_add(Weight::from_parts(18_445_865, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:1 w:1)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
 	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-	/// Storage: System Account (r:3 w:3)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_equip(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2502 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 133_266_000 picoseconds.
+		Weight::from_parts(182_542_673, 9901)
+			// Standard Error: 77_278
+			.saturating_add(Weight::from_parts(20_295_038, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
-	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:2 w:2)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 200]`.
-	fn buy(n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_mate(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2947 + n * (65 ±0)`
-		//  Estimated: `18812`
-		// Minimum execution time: 301_996_000 picoseconds.
-		Weight::from_parts(625_667_853, 18812)
-			// Standard Error: 139_305
-			.saturating_add(Weight::from_parts(740_792, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+		//  Measured:  `2519 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 129_477_000 picoseconds.
+		Weight::from_parts(179_780_084, 9901)
+			// Standard Error: 79_991
+			.saturating_add(Weight::from_parts(18_464_211, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
-	fn upgrade_storage() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1517`
-		//  Estimated: `7528`
-		// Minimum execution time: 204_408_000 picoseconds.
-		Weight::from_parts(242_709_000, 7528)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
-	}
-	/// Storage: AwesomeAvatars Organizer (r:0 w:1)
-	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	fn set_organizer() -> Weight {
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_feed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 24_251_000 picoseconds.
-		Weight::from_parts(27_376_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
-	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars CollectionId (r:0 w:1)
-	/// Proof: AwesomeAvatars CollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn set_collection_id() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `96`
-		//  Estimated: `1517`
-		// Minimum execution time: 58_048_000 picoseconds.
-		Weight::from_parts(61_852_000, 1517)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: AwesomeAvatars Treasurer (r:0 w:1)
-	/// Proof: AwesomeAvatars Treasurer (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
-	fn set_treasurer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 37_196_000 picoseconds.
-		Weight::from_parts(52_124_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2536 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 119_688_000 picoseconds.
+		Weight::from_parts(171_017_495, 9901)
+			// Standard Error: 82_704
+			.saturating_add(Weight::from_parts(18_473_384, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars Treasurer (r:1 w:0)
-	/// Proof: AwesomeAvatars Treasurer (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
 	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
-	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn claim_treasury() -> Weight {
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_glimmer(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1771`
-		//  Estimated: `6196`
-		// Minimum execution time: 140_259_000 picoseconds.
-		Weight::from_parts(172_480_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `2553 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 146_899_000 picoseconds.
+		Weight::from_parts(199_254_906, 9901)
+			// Standard Error: 85_417
+			.saturating_add(Weight::from_parts(21_242_557, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
-	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Seasons (r:1 w:1)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
-	fn set_season() -> Weight {
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_spark(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `96`
-		//  Estimated: `5132`
-		// Minimum execution time: 60_296_000 picoseconds.
-		Weight::from_parts(69_887_000, 5132)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2570 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 114_110_000 picoseconds.
+		Weight::from_parts(167_492_317, 9901)
+			// Standard Error: 88_130
+			.saturating_add(Weight::from_parts(18_491_730, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
-	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars GlobalConfigs (r:0 w:1)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	fn update_global_config() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `96`
-		//  Estimated: `1517`
-		// Minimum execution time: 28_576_000 picoseconds.
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_statue(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2587 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 112_321_000 picoseconds.
+		Weight::from_parts(166_729_728, 9901)
+			// Standard Error: 90_843
+			.saturating_add(Weight::from_parts(18_500_903, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_flask(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2604 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 142_532_000 picoseconds.
+		Weight::from_parts(197_967_139, 9901)
+			// Standard Error: 93_556
+			.saturating_add(Weight::from_parts(20_350_076, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars BlockMints (r:1 w:1)
+	/// Proof: AwesomeAvatars BlockMints (max_values: Some(1), max_size: Some(6), added: 501, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars PeriodMints (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AccountPeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars AccountPeriodMints (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:0 w:6)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:0 w:6)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 194]`.
+	fn mint_free(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1954 + n * (32 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 233_210_000 picoseconds.
+		Weight::from_parts(370_101_230, 9901)
+			// Standard Error: 126_241
+			.saturating_add(Weight::from_parts(854_307, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().writes(36_u64))
+	}
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars BlockMints (r:1 w:1)
+	/// Proof: AwesomeAvatars BlockMints (max_values: Some(1), max_size: Some(6), added: 501, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars PeriodMints (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AccountPeriodMints (r:1 w:1)
+	/// Proof: AwesomeAvatars AccountPeriodMints (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:0 w:6)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:0 w:6)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 194]`.
+	fn mint_normal(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2006 + n * (32 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 343_117_000 picoseconds.
+		Weight::from_parts(729_037_169, 9901)
+			.saturating_add(RocksDbWeight::get().reads(46_u64))
+			.saturating_add(RocksDbWeight::get().writes(37_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:5 w:5)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:5 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:5 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:5 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// The range of component `n` is `[5, 200]`.
+	fn forge(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3016 + n * (38 ±0)`
+		//  Estimated: `14230`
+		// Minimum execution time: 236_460_000 picoseconds.
+		Weight::from_parts(415_831_720, 14230)
+			// Standard Error: 161_670
+			.saturating_add(Weight::from_parts(2_022_927, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(28_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn transfer_avatar_normal(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `15783`
+		//  Estimated: `18812`
+		// Minimum execution time: 309_016_000 picoseconds.
+		Weight::from_parts(665_852_667, 18812)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn transfer_avatar_organizer(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `15881`
+		//  Estimated: `18812`
+		// Minimum execution time: 303_703_000 picoseconds.
+		Weight::from_parts(722_069_457, 18812)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerConfigs (r:2 w:2)
+	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	fn transfer_free_mints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `129`
+		//  Estimated: `6008`
+		// Minimum execution time: 40_025_000 picoseconds.
+		Weight::from_parts(40_787_000, 6008)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:0 w:1)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn set_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2490`
+		//  Estimated: `5132`
+		// Minimum execution time: 129_226_000 picoseconds.
+		Weight::from_parts(152_923_000, 5132)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:1)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn remove_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1197`
+		//  Estimated: `3638`
+		// Minimum execution time: 78_207_000 picoseconds.
+		Weight::from_parts(125_470_000, 3638)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:1)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:2 w:2)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:2)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn buy(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2947 + n * (65 ±0)`
+		//  Estimated: `18812`
+		// Minimum execution time: 301_996_000 picoseconds.
+		Weight::from_parts(625_667_853, 18812)
+			// Standard Error: 139_305
+			.saturating_add(Weight::from_parts(740_792, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	fn upgrade_storage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1517`
+		//  Estimated: `7528`
+		// Minimum execution time: 204_408_000 picoseconds.
+		Weight::from_parts(242_709_000, 7528)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:0 w:1)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_organizer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 24_251_000 picoseconds.
+		Weight::from_parts(27_376_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CollectionId (r:0 w:1)
+	/// Proof: AwesomeAvatars CollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_collection_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `96`
+		//  Estimated: `1517`
+		// Minimum execution time: 58_048_000 picoseconds.
+		Weight::from_parts(61_852_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Treasurer (r:0 w:1)
+	/// Proof: AwesomeAvatars Treasurer (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	fn set_treasurer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 37_196_000 picoseconds.
+		Weight::from_parts(52_124_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Treasurer (r:1 w:0)
+	/// Proof: AwesomeAvatars Treasurer (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_treasury() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1771`
+		//  Estimated: `6196`
+		// Minimum execution time: 140_259_000 picoseconds.
+		Weight::from_parts(172_480_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:1)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	fn set_season() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `96`
+		//  Estimated: `5132`
+		// Minimum execution time: 60_296_000 picoseconds.
+		Weight::from_parts(69_887_000, 5132)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:0 w:1)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	fn update_global_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `96`
+		//  Estimated: `1517`
+		// Minimum execution time: 28_576_000 picoseconds.
 		Weight::from_parts(39_765_000, 1517)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
 	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerConfigs (r:1 w:1)
-	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
-	fn set_free_mints() -> Weight {
+	/// Storage: AwesomeAvatars PlayerConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerConfigs (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	fn set_free_mints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `96`
+		//  Estimated: `3499`
+		// Minimum execution time: 29_877_000 picoseconds.
+		Weight::from_parts(69_842_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	/// Proof: AwesomeAvatars CollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nft Item (r:1 w:1)
+	/// Proof: Nft Item (max_values: None, max_size: Some(186), added: 2661, mode: MaxEncodedLen)
+	/// Storage: Nft Collection (r:1 w:1)
+	/// Proof: Nft Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nft CollectionConfigOf (r:1 w:0)
+	/// Proof: Nft CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nft ItemConfigOf (r:1 w:1)
+	/// Proof: Nft ItemConfigOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nft Attribute (r:6 w:6)
+	/// Proof: Nft Attribute (max_values: None, max_size: Some(418), added: 2893, mode: MaxEncodedLen)
+	/// Storage: NftTransfer NftStatuses (r:0 w:1)
+	/// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Nft Account (r:0 w:1)
+	/// Proof: Nft Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn lock_avatar(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1415 + n * (34 ±0)`
+		//  Estimated: `18812`
+		// Minimum execution time: 432_000_000 picoseconds.
+		Weight::from_parts(932_672_427, 18812)
+			// Standard Error: 170_806
+			.saturating_add(Weight::from_parts(555_196, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	/// Proof: AwesomeAvatars CollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: NftTransfer NftStatuses (r:1 w:1)
+	/// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Nft Attribute (r:6 w:6)
+	/// Proof: Nft Attribute (max_values: None, max_size: Some(418), added: 2893, mode: MaxEncodedLen)
+	/// Storage: Nft Collection (r:1 w:1)
+	/// Proof: Nft Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nft ItemConfigOf (r:1 w:1)
+	/// Proof: Nft ItemConfigOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Nft Item (r:1 w:1)
+	/// Proof: Nft Item (max_values: None, max_size: Some(186), added: 2661, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nft ItemMetadataOf (r:1 w:0)
+	/// Proof: Nft ItemMetadataOf (max_values: None, max_size: Some(247), added: 2722, mode: MaxEncodedLen)
+	/// Storage: Nft Account (r:0 w:1)
+	/// Proof: Nft Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Nft ItemPriceOf (r:0 w:1)
+	/// Proof: Nft ItemPriceOf (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	/// Proof: Nft ItemAttributesApprovalsOf (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
+	/// Storage: Nft PendingSwapOf (r:0 w:1)
+	/// Proof: Nft PendingSwapOf (max_values: None, max_size: Some(127), added: 2602, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:0 w:1)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn unlock_avatar(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2202 + n * (34 ±0)`
+		//  Estimated: `18812`
+		// Minimum execution time: 423_878_000 picoseconds.
+		Weight::from_parts(910_723_452, 18812)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Storage: AwesomeAvatars ServiceAccount (r:0 w:1)
+	/// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_service_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_969_000 picoseconds.
+		Weight::from_parts(20_751_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
+	/// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn prepare_avatar() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1861`
+		//  Estimated: `6196`
+		// Minimum execution time: 177_929_000 picoseconds.
+		Weight::from_parts(183_872_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	fn unprepare_avatar() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `344`
+		//  Estimated: `3638`
+		// Minimum execution time: 42_265_000 picoseconds.
+		Weight::from_parts(45_537_000, 3638)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
+	/// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	fn prepare_ipfs() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3579`
+		// Minimum execution time: 40_493_000 picoseconds.
+		Weight::from_parts(41_931_000, 3579)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:1 w:1)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn burn_avatar(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1526 + n * (32 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 93_418_000 picoseconds.
+		Weight::from_parts(118_326_401, 9901)
+			// Standard Error: 41_973
+			.saturating_add(Weight::from_parts(311_254, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:200 w:200)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:200 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:200 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:200 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:200 w:200)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SoulPoints (r:1 w:1)
+	/// Proof: AwesomeAvatars SoulPoints (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn dissolve(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1380 + n * (279 ±0)`
+		//  Estimated: `9901 + n * (2648 ±0)`
+		// Minimum execution time: 64_218_000 picoseconds.
+		Weight::from_parts(51_307_884, 9901)
+			// Standard Error: 19_871
+			.saturating_add(Weight::from_parts(28_641_207, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:0 w:1)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	fn set_quest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `5132`
+		// Minimum execution time: 18_402_000 picoseconds.
+		Weight::from_parts(19_127_000, 5132)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Quests (r:1 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(26), added: 2501, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_quest_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_711_000 picoseconds.
+		Weight::from_parts(65_283_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:200 w:200)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:200 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:200 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:200 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:200 w:200)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trophies (r:0 w:200)
+	/// Proof: AwesomeAvatars Trophies (max_values: None, max_size: Some(208), added: 2683, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn retire_avatars(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1412 + n * (279 ±0)`
+		//  Estimated: `9901 + n * (2683 ±0)`
+		// Minimum execution time: 61_906_000 picoseconds.
+		Weight::from_parts(49_802_112, 9901)
+			// Standard Error: 21_340
+			.saturating_add(Weight::from_parts(31_077_419, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars Treasurer (r:1 w:0)
+	/// Proof: AwesomeAvatars Treasurer (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	/// Proof: AwesomeAvatars AssetTreasury (max_values: None, max_size: Some(22), added: 2497, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	fn claim_asset_treasury() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1903`
+		//  Estimated: `6208`
+		// Minimum execution time: 151_872_000 picoseconds.
+		Weight::from_parts(186_315_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars RentalOffers (r:0 w:1)
+	/// Proof: AwesomeAvatars RentalOffers (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn offer_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `868`
+		//  Estimated: `3775`
+		// Minimum execution time: 42_310_000 picoseconds.
+		Weight::from_parts(48_902_000, 3775)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars RentalOffers (r:1 w:1)
+	/// Proof: AwesomeAvatars RentalOffers (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn cancel_rental_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912`
+		//  Estimated: `3638`
+		// Minimum execution time: 30_871_000 picoseconds.
+		Weight::from_parts(35_224_000, 3638)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:1 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars RentalOffers (r:1 w:1)
+	/// Proof: AwesomeAvatars RentalOffers (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn rent_avatar() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1125`
+		//  Estimated: `6196`
+		// Minimum execution time: 86_445_000 picoseconds.
+		Weight::from_parts(98_137_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: AwesomeAvatars Rentals (r:1 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn end_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `410`
+		//  Estimated: `3522`
+		// Minimum execution time: 24_116_000 picoseconds.
+		Weight::from_parts(27_893_000, 3522)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(365), added: 2840, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Sponsorships (r:0 w:1)
+	/// Proof: AwesomeAvatars Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn set_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3830`
+		// Minimum execution time: 31_572_000 picoseconds.
+		Weight::from_parts(33_118_000, 3830)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(365), added: 2840, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonArchives (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonArchives (max_values: None, max_size: Some(334), added: 2809, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonPlayers (r:101 w:100)
+	/// Proof: AwesomeAvatars SeasonPlayers (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:100 w:100)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:100 w:100)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:0 w:100)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn archive_season(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1294 + n * (44 ±0)`
+		//  Estimated: `6701 + n * (8911 ±0)`
+		// Minimum execution time: 38_962_000 picoseconds.
+		Weight::from_parts(41_254_000, 6701)
+			// Standard Error: 4_518
+			.saturating_add(Weight::from_parts(19_734_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:1)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	fn retry_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `381`
+		//  Estimated: `2537`
+		// Minimum execution time: 17_034_000 picoseconds.
+		Weight::from_parts(17_762_000, 2537)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	/// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:1)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	fn abort_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `381`
+		//  Estimated: `2537`
+		// Minimum execution time: 16_581_000 picoseconds.
+		Weight::from_parts(17_209_000, 2537)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_none(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2400 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 150_000_000 picoseconds.
+		Weight::from_parts(193_118_207, 9901)
+			// Standard Error: 61_000
+			.saturating_add(Weight::from_parts(22_080_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_stack(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2417 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 110_211_000 picoseconds.
+		Weight::from_parts(154_355_618, 9901)
+			// Standard Error: 63_713
+			.saturating_add(Weight::from_parts(18_409_173, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_tinker(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2434 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 125_422_000 picoseconds.
+		Weight::from_parts(170_593_029, 9901)
+			// Standard Error: 66_426
+			.saturating_add(Weight::from_parts(19_338_346, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:6 w:6)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:6 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:6 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn forge_build() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3221`
+		//  Estimated: `9901`
+		// Minimum execution time: 135_633_000 picoseconds.
+		Weight::from_parts(181_830_440, 9901)
+			.saturating_add(RocksDbWeight::get().reads(63_u64))
+			.saturating_add(RocksDbWeight::get().writes(30_u64))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_assemble(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2468 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 130_844_000 picoseconds.
+		Weight::from_parts(178_067_851, 9901)
+			// Standard Error: 71_852
+			.saturating_add(Weight::from_parts(20_276_692, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_breed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `96`
-		//  Estimated: `3499`
-		// Minimum execution time: 29_877_000 picoseconds.
-		Weight::from_parts(69_842_000, 3499)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2485 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 121_055_000 picoseconds.
+		Weight::from_parts(169_305_262, 9901)
+			// Standard Error: 74_565
+			.saturating_add(Weight::from_parts(18_445_865, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
-	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:1 w:0)
-	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
 	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars CollectionId (r:1 w:0)
-	/// Proof: AwesomeAvatars CollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Nft Item (r:1 w:1)
-	/// Proof: Nft Item (max_values: None, max_size: Some(186), added: 2661, mode: MaxEncodedLen)
-	/// Storage: Nft Collection (r:1 w:1)
-	/// Proof: Nft Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nft CollectionConfigOf (r:1 w:0)
-	/// Proof: Nft CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nft ItemConfigOf (r:1 w:1)
-	/// Proof: Nft ItemConfigOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Nft Attribute (r:6 w:6)
-	/// Proof: Nft Attribute (max_values: None, max_size: Some(418), added: 2893, mode: MaxEncodedLen)
-	/// Storage: NftTransfer NftStatuses (r:0 w:1)
-	/// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	/// Storage: Nft Account (r:0 w:1)
-	/// Proof: Nft Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 200]`.
-	fn lock_avatar(n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_equip(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1415 + n * (34 ±0)`
-		//  Estimated: `18812`
-		// Minimum execution time: 432_000_000 picoseconds.
-		Weight::from_parts(932_672_427, 18812)
-			// Standard Error: 170_806
-			.saturating_add(Weight::from_parts(555_196, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(20_u64))
-			.saturating_add(RocksDbWeight::get().writes(17_u64))
+		//  Measured:  `2502 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 133_266_000 picoseconds.
+		Weight::from_parts(182_542_673, 9901)
+			// Standard Error: 77_278
+			.saturating_add(Weight::from_parts(20_295_038, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars Avatars (r:1 w:1)
-	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:1 w:0)
-	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
 	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Owners (r:2 w:2)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
 	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
 	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars CollectionId (r:1 w:0)
-	/// Proof: AwesomeAvatars CollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: NftTransfer NftStatuses (r:1 w:1)
-	/// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	/// Storage: Nft Attribute (r:6 w:6)
-	/// Proof: Nft Attribute (max_values: None, max_size: Some(418), added: 2893, mode: MaxEncodedLen)
-	/// Storage: Nft Collection (r:1 w:1)
-	/// Proof: Nft Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nft ItemConfigOf (r:1 w:1)
-	/// Proof: Nft ItemConfigOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
-	/// Storage: Nft Item (r:1 w:1)
-	/// Proof: Nft Item (max_values: None, max_size: Some(186), added: 2661, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Nft ItemMetadataOf (r:1 w:0)
-	/// Proof: Nft ItemMetadataOf (max_values: None, max_size: Some(247), added: 2722, mode: MaxEncodedLen)
-	/// Storage: Nft Account (r:0 w:1)
-	/// Proof: Nft Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
-	/// Storage: Nft ItemPriceOf (r:0 w:1)
-	/// Proof: Nft ItemPriceOf (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
-	/// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
-	/// Proof: Nft ItemAttributesApprovalsOf (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
-	/// Storage: Nft PendingSwapOf (r:0 w:1)
-	/// Proof: Nft PendingSwapOf (max_values: None, max_size: Some(127), added: 2602, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 200]`.
-	fn unlock_avatar(_n: u32, ) -> Weight {
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_mate(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2202 + n * (34 ±0)`
-		//  Estimated: `18812`
-		// Minimum execution time: 423_878_000 picoseconds.
-		Weight::from_parts(910_723_452, 18812)
-			.saturating_add(RocksDbWeight::get().reads(20_u64))
-			.saturating_add(RocksDbWeight::get().writes(19_u64))
+		//  Measured:  `2519 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 129_477_000 picoseconds.
+		Weight::from_parts(179_780_084, 9901)
+			// Standard Error: 79_991
+			.saturating_add(Weight::from_parts(18_464_211, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars ServiceAccount (r:0 w:1)
-	/// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	fn set_service_account() -> Weight {
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_feed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 19_969_000 picoseconds.
-		Weight::from_parts(20_751_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2536 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 119_688_000 picoseconds.
+		Weight::from_parts(171_017_495, 9901)
+			// Standard Error: 82_704
+			.saturating_add(Weight::from_parts(18_473_384, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
 	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Trade (r:1 w:0)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
 	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_glimmer(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2553 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 146_899_000 picoseconds.
+		Weight::from_parts(199_254_906, 9901)
+			// Standard Error: 85_417
+			.saturating_add(Weight::from_parts(21_242_557, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
-	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
-	/// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
 	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn prepare_avatar() -> Weight {
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_spark(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1861`
-		//  Estimated: `6196`
-		// Minimum execution time: 177_929_000 picoseconds.
-		Weight::from_parts(183_872_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `2570 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 114_110_000 picoseconds.
+		Weight::from_parts(167_492_317, 9901)
+			// Standard Error: 88_130
+			.saturating_add(Weight::from_parts(18_491_730, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars Avatars (r:1 w:0)
-	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	fn unprepare_avatar() -> Weight {
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:0)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_statue(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `344`
-		//  Estimated: `3638`
-		// Minimum execution time: 42_265_000 picoseconds.
-		Weight::from_parts(45_537_000, 3638)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2587 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 112_321_000 picoseconds.
+		Weight::from_parts(166_729_728, 9901)
+			// Standard Error: 90_843
+			.saturating_add(Weight::from_parts(18_500_903, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
-	/// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
-	/// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	/// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	/// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-	/// Storage: AwesomeAvatars Preparation (r:1 w:1)
-	/// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	fn prepare_ipfs() -> Weight {
+	/// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	/// Proof: AwesomeAvatars CurrentSeasonStatus (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	/// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Avatars (r:2 w:2)
+	/// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Trade (r:2 w:0)
+	/// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars LockedAvatars (r:2 w:0)
+	/// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Randomness RandomMaterial (r:1 w:0)
+	/// Proof: Randomness RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Owners (r:1 w:1)
+	/// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:1)
+	/// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	/// Proof: AwesomeAvatars SeasonStats (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	/// Proof: AwesomeAvatars OngoingMigration (max_values: Some(1), max_size: Some(1052), added: 1547, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Rentals (r:5 w:1)
+	/// Proof: AwesomeAvatars Rentals (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	/// Proof: AwesomeAvatars Treasury (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars AvatarDeposits (r:4 w:4)
+	/// Proof: AwesomeAvatars AvatarDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars Quests (r:16 w:0)
+	/// Proof: AwesomeAvatars Quests (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: AwesomeAvatars PlayerQuests (r:16 w:16)
+	/// Proof: AwesomeAvatars PlayerQuests (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4]`.
+	fn forge_flask(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `318`
-		//  Estimated: `3579`
-		// Minimum execution time: 40_493_000 picoseconds.
-		Weight::from_parts(41_931_000, 3579)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `2604 + n * (192 ±0)`
+		//  Estimated: `9901`
+		// Minimum execution time: 142_532_000 picoseconds.
+		Weight::from_parts(197_967_139, 9901)
+			// Standard Error: 93_556
+			.saturating_add(Weight::from_parts(20_350_076, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 }
@@ -0,0 +1,41 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the Battle Mogs pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use pallet_ajuna_battle_mogs::{BattleRecord, FamilyMember, Rating};
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait BattleMogsApi<AccountId, MogwaiId>
+	where
+		AccountId: Codec,
+		MogwaiId: Codec,
+	{
+		/// The Elo rating of an account.
+		fn account_rating(account: AccountId) -> Rating;
+
+		/// The Elo rating and the wins and losses of a mogwai.
+		fn mogwai_rating(mogwai_id: MogwaiId) -> (Rating, BattleRecord);
+
+		/// The family tree of a mogwai, breadth first and up to `depth` generations of
+		/// ancestors, capped at 4.
+		fn family_tree(mogwai_id: MogwaiId, depth: u8) -> Vec<FamilyMember<MogwaiId>>;
+	}
+}
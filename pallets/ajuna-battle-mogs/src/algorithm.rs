@@ -435,6 +435,7 @@ impl Generation {
 		input_rarity_1: RarityType,
 		input_generation_2: MogwaiGeneration,
 		input_rarity_2: RarityType,
+		inbreeding_penalty: u8,
 		random_hash: &[u8],
 	) -> (RarityType, MogwaiGeneration, RarityType) {
 		let mut resulting_gen = MogwaiGeneration::default();
@@ -451,14 +452,18 @@ impl Generation {
 			let (out_rarity_2, out_gen_2) =
 				Self::compute_next_generation_and_rarity(input_generation_2, input_rarity_2, slice);
 
+			let inbreeding_penalty = inbreeding_penalty as u16;
+
 			resulting_gen = MogwaiGeneration::coerce_from(
-				(out_gen_1 as u16 + out_gen_2 as u16 + base_rarity) / 2,
+				((out_gen_1 as u16 + out_gen_2 as u16 + base_rarity) / 2)
+					.saturating_sub(inbreeding_penalty),
 			);
 
 			resulting_rarity = RarityType::from(
-				((out_rarity_1 as u16 +
+				(((out_rarity_1 as u16 +
 					out_rarity_2 as u16 + ((input_rarity_1 as u16 + input_rarity_2 as u16) / 2)) /
-					2) % 5,
+					2) % 5)
+					.saturating_sub(inbreeding_penalty),
 			)
 		}
 
@@ -603,4 +608,32 @@ mod test {
 			assert_eq!(output_dna, expected_output);
 		}
 	}
+
+	mod next_gen {
+		use super::*;
+
+		#[test]
+		fn inbreeding_penalty_lowers_generation_and_rarity() {
+			let hash = [0; 32];
+			let next_gen = |inbreeding_penalty| {
+				Generation::next_gen(
+					MogwaiGeneration::Tenth,
+					RarityType::Epic,
+					MogwaiGeneration::Tenth,
+					RarityType::Epic,
+					inbreeding_penalty,
+					&hash,
+				)
+			};
+
+			assert_eq!(
+				next_gen(0),
+				(RarityType::Legendary, MogwaiGeneration::Fourteenth, RarityType::Rare)
+			);
+			assert_eq!(
+				next_gen(2),
+				(RarityType::Rare, MogwaiGeneration::Twelfth, RarityType::Rare)
+			);
+		}
+	}
 }
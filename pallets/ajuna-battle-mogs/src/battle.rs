@@ -0,0 +1,283 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{MogwaiGeneration, PhaseType, RarityType};
+use frame_support::pallet_prelude::*;
+use scale_info::TypeInfo;
+
+/// The maximum number of attacks exchanged in a single battle.
+pub const MAX_BATTLE_ROUNDS: u32 = 32;
+
+/// The rating every account and mogwai starts with.
+pub const INITIAL_RATING: Rating = 1_200;
+
+pub type BattleLog = BoundedVec<BattleRound, ConstU32<MAX_BATTLE_ROUNDS>>;
+pub type Rating = u32;
+
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum BattleSide {
+	Challenger = 0,
+	Opponent = 1,
+}
+
+impl BattleSide {
+	pub fn flip(self) -> Self {
+		match self {
+			BattleSide::Challenger => Self::Opponent,
+			BattleSide::Opponent => Self::Challenger,
+		}
+	}
+}
+
+/// A single attack of a battle, enough to replay the fight on the client.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct BattleRound {
+	pub attacker: BattleSide,
+	pub damage: u16,
+	pub critical: bool,
+}
+
+#[derive(Encode, Decode, Debug, Default, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CombatStats {
+	pub health: u16,
+	pub attack: u16,
+	pub defense: u16,
+	pub speed: u16,
+}
+
+impl CombatStats {
+	/// Derive the combat stats of a mogwai from its first DNA strand, boosted by its rarity,
+	/// generation and phase.
+	pub fn new(
+		dna: &[[u8; 32]; 2],
+		rarity: RarityType,
+		generation: MogwaiGeneration,
+		phase: PhaseType,
+	) -> Self {
+		let strand = &dna[0];
+		let phase_bonus = match phase {
+			PhaseType::None | PhaseType::Bred | PhaseType::Hatched => 0,
+			PhaseType::Matured => 1,
+			PhaseType::Mastered => 2,
+			PhaseType::Exalted => 3,
+		};
+		let multiplier =
+			100 + 10 * rarity as u32 + 5 * (generation as u32).saturating_sub(1) + 10 * phase_bonus;
+		let scale = |base: u16| (base as u32 * multiplier / 100) as u16;
+
+		Self {
+			health: scale(50 + (strand[0] as u16 + strand[1] as u16) / 4),
+			attack: scale(10 + strand[2] as u16 / 8),
+			defense: scale(5 + strand[3] as u16 / 16),
+			speed: scale(strand[4] as u16 / 16),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattleOutcome {
+	pub winner: BattleSide,
+	pub log: BattleLog,
+}
+
+pub struct Battle;
+
+impl Battle {
+	/// Rolls above this threshold land a critical hit, dealing double damage.
+	const CRITICAL_ROLL: u8 = 230;
+
+	/// Fight a duel between two mogwais, driven entirely by `seed` so the same inputs always
+	/// yield the same outcome.
+	///
+	/// The faster mogwai strikes first, ties favouring the challenger, and both sides alternate
+	/// until one of them is knocked out. If nobody falls within `MAX_BATTLE_ROUNDS`, the side
+	/// with the most remaining health wins, ties favouring the opponent.
+	pub fn resolve(challenger: CombatStats, opponent: CombatStats, seed: &[u8]) -> BattleOutcome {
+		let mut health = [challenger.health, opponent.health];
+		let stats = [challenger, opponent];
+		let mut attacker = if opponent.speed > challenger.speed {
+			BattleSide::Opponent
+		} else {
+			BattleSide::Challenger
+		};
+		let mut log = BattleLog::default();
+
+		for round in 0..MAX_BATTLE_ROUNDS as usize {
+			let roll = if seed.is_empty() { 0 } else { seed[round % seed.len()] };
+			let defender = attacker.flip();
+
+			let attack = stats[attacker as usize].attack;
+			let defense = stats[defender as usize].defense;
+			let critical = roll >= Self::CRITICAL_ROLL;
+			let mut damage = attack.saturating_sub(defense / 2).max(1) + (roll % 4) as u16;
+			if critical {
+				damage = damage.saturating_mul(2);
+			}
+
+			health[defender as usize] = health[defender as usize].saturating_sub(damage);
+			// The log is bounded by the number of rounds, so this can never fail.
+			let _ = log.try_push(BattleRound { attacker, damage, critical });
+
+			if health[defender as usize] == 0 {
+				return BattleOutcome { winner: attacker, log }
+			}
+			attacker = defender;
+		}
+
+		let winner =
+			if health[BattleSide::Challenger as usize] > health[BattleSide::Opponent as usize] {
+				BattleSide::Challenger
+			} else {
+				BattleSide::Opponent
+			};
+		BattleOutcome { winner, log }
+	}
+}
+
+pub struct Elo;
+
+impl Elo {
+	const K_FACTOR: u32 = 32;
+	const RATING_STEP: u32 = 25;
+
+	/// Expected score, per mille, of a player rated `RATING_STEP * index` above its opponent.
+	const EXPECTED_SCORES: [u32; 33] = [
+		500, 536, 571, 606, 640, 673, 703, 733, 760, 785, 808, 830, 849, 867, 882, 896, 909, 920,
+		930, 939, 947, 954, 960, 965, 969, 973, 977, 980, 983, 985, 987, 989, 990,
+	];
+
+	/// The score, per mille, a player rated `rating` is expected to make against `opponent`.
+	pub fn expected_score(rating: Rating, opponent: Rating) -> u32 {
+		let diff = rating.abs_diff(opponent);
+		let index = ((diff + Self::RATING_STEP / 2) / Self::RATING_STEP)
+			.min(Self::EXPECTED_SCORES.len() as u32 - 1) as usize;
+		let score = Self::EXPECTED_SCORES[index];
+
+		if rating >= opponent {
+			score
+		} else {
+			1_000 - score
+		}
+	}
+
+	/// The ratings of the winner and the loser of a match once it has been played.
+	pub fn rate(winner: Rating, loser: Rating) -> (Rating, Rating) {
+		let expected = Self::expected_score(winner, loser);
+		let gain = (Self::K_FACTOR * (1_000 - expected) + 500) / 1_000;
+		(winner.saturating_add(gain), loser.saturating_sub(gain))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn stats(health: u16, attack: u16, defense: u16, speed: u16) -> CombatStats {
+		CombatStats { health, attack, defense, speed }
+	}
+
+	#[test]
+	fn combat_stats_grow_with_rarity_generation_and_phase() {
+		let dna = [[0x80; 32]; 2];
+		let base =
+			CombatStats::new(&dna, RarityType::Common, MogwaiGeneration::First, PhaseType::Hatched);
+		assert_eq!(base, stats(114, 26, 13, 8));
+
+		let rare =
+			CombatStats::new(&dna, RarityType::Rare, MogwaiGeneration::First, PhaseType::Hatched);
+		assert_eq!(rare, stats(136, 31, 15, 9));
+
+		let veteran = CombatStats::new(
+			&dna,
+			RarityType::Common,
+			MogwaiGeneration::Third,
+			PhaseType::Mastered,
+		);
+		assert_eq!(veteran, stats(148, 33, 16, 10));
+	}
+
+	#[test]
+	fn battle_is_deterministic() {
+		let challenger = stats(100, 20, 10, 5);
+		let opponent = stats(100, 20, 10, 5);
+		let seed = [7; 32];
+
+		assert_eq!(
+			Battle::resolve(challenger, opponent, &seed),
+			Battle::resolve(challenger, opponent, &seed)
+		);
+	}
+
+	#[test]
+	fn faster_mogwai_strikes_first() {
+		let outcome = Battle::resolve(stats(100, 20, 10, 5), stats(100, 20, 10, 6), &[0; 32]);
+		assert_eq!(outcome.log[0].attacker, BattleSide::Opponent);
+
+		let outcome = Battle::resolve(stats(100, 20, 10, 6), stats(100, 20, 10, 6), &[0; 32]);
+		assert_eq!(outcome.log[0].attacker, BattleSide::Challenger);
+	}
+
+	#[test]
+	fn stronger_mogwai_wins() {
+		let outcome = Battle::resolve(stats(100, 10, 10, 0), stats(100, 60, 10, 0), &[0; 32]);
+		assert_eq!(outcome.winner, BattleSide::Opponent);
+		assert_eq!(outcome.log.len(), 4);
+		assert_eq!(
+			outcome
+				.log
+				.iter()
+				.filter(|round| round.attacker == BattleSide::Opponent)
+				.count(),
+			2
+		);
+	}
+
+	#[test]
+	fn critical_hits_double_damage() {
+		let outcome = Battle::resolve(stats(100, 20, 10, 0), stats(100, 20, 10, 0), &[231]);
+		assert!(outcome.log[0].critical);
+		assert_eq!(outcome.log[0].damage, (15 + 231 % 4) * 2);
+	}
+
+	#[test]
+	fn stalemate_is_decided_by_remaining_health() {
+		let outcome = Battle::resolve(stats(1_000, 1, 100, 0), stats(1_001, 1, 100, 0), &[0; 32]);
+		assert_eq!(outcome.log.len(), MAX_BATTLE_ROUNDS as usize);
+		assert_eq!(outcome.winner, BattleSide::Opponent);
+
+		let outcome = Battle::resolve(stats(1_000, 1, 100, 0), stats(1_000, 1, 100, 0), &[0; 32]);
+		assert_eq!(outcome.winner, BattleSide::Opponent);
+
+		let outcome = Battle::resolve(stats(1_002, 1, 100, 0), stats(1_000, 1, 100, 0), &[0; 32]);
+		assert_eq!(outcome.winner, BattleSide::Challenger);
+	}
+
+	#[test]
+	fn elo_rates_upsets_higher() {
+		assert_eq!(Elo::rate(1_200, 1_200), (1_216, 1_184));
+		assert_eq!(Elo::rate(1_200, 1_400), (1_224, 1_376));
+		assert_eq!(Elo::rate(1_400, 1_200), (1_408, 1_192));
+	}
+
+	#[test]
+	fn elo_expected_score_is_symmetric_and_clamped() {
+		assert_eq!(Elo::expected_score(1_500, 1_500), 500);
+		assert_eq!(Elo::expected_score(1_600, 1_500) + Elo::expected_score(1_500, 1_600), 1_000);
+		assert_eq!(Elo::expected_score(3_000, 0), 990);
+		assert_eq!(Elo::rate(10, 3_000), (42, 2_968));
+		assert_eq!(Elo::rate(3_000, 10).1, 10);
+	}
+}
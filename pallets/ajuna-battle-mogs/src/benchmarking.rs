@@ -21,9 +21,10 @@
 use crate::{Config as BattleMogsConfig, *};
 use frame_benchmarking::{benchmarks, whitelist_account, whitelisted_caller};
 use frame_system::RawOrigin;
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::traits::{Hash, StaticLookup, UniqueSaturatedInto};
 
 type CurrencyOf<T> = <T as BattleMogsConfig>::Currency;
+type NftCurrencyOf<T> = <T as pallet_nfts::Config>::Currency;
 
 fn account<T: Config>(name: &'static str) -> T::AccountId {
 	let account = frame_benchmarking::account(name, Default::default(), Default::default());
@@ -47,10 +48,77 @@ fn force_mogwai_rarity<T: Config>(mogwai_id: &MogwaiIdOf<T>, rarity: RarityType)
 	});
 }
 
+fn force_promotion_due<T: Config>(mogwai_id: &MogwaiIdOf<T>) {
+	if let Some(threshold) =
+		Mogwais::<T>::get(mogwai_id).and_then(|m| m.phase.promotion_threshold())
+	{
+		MogwaiExperience::<T>::insert(mogwai_id, threshold - 1);
+	}
+}
+
+fn create_hatched_mogwai<T: Config>(owner: &T::AccountId) -> Result<MogwaiIdOf<T>, &'static str> {
+	Pallet::<T>::create_mogwai(RawOrigin::Signed(owner.clone()).into())?;
+	let mogwai_id = *Owners::<T>::get(owner).iter().next().unwrap();
+	force_hatch_mogwai::<T>(&mogwai_id);
+	Ok(mogwai_id)
+}
+
+fn create_queued_mogwai<T: Config>(
+	owner: &T::AccountId,
+	generation: MogwaiGeneration,
+) -> Result<MogwaiIdOf<T>, &'static str> {
+	let mogwai_id = create_hatched_mogwai::<T>(owner)?;
+	Mogwais::<T>::mutate(mogwai_id, |maybe_mogwai| {
+		if let Some(ref mut mogwai) = maybe_mogwai {
+			mogwai.phase = PhaseType::Matured;
+			mogwai.generation = generation;
+			mogwai.rarity = RarityType::Common;
+		}
+	});
+	Pallet::<T>::enqueue_mogwai(
+		RawOrigin::Signed(owner.clone()).into(),
+		mogwai_id,
+		Some(1_000_u32.into()),
+	)?;
+	Ok(mogwai_id)
+}
+
+fn create_collection<T: Config + pallet_nfts::Config>(organizer: &T::AccountId) -> DispatchResult {
+	let collection_deposit = <T as pallet_nfts::Config>::CollectionDeposit::get();
+	NftCurrencyOf::<T>::make_free_balance_be(
+		organizer,
+		collection_deposit + NftCurrencyOf::<T>::minimum_balance(),
+	);
+
+	let collection_config = pallet_nfts::CollectionConfig {
+		settings: pallet_nfts::CollectionSettings::all_enabled(),
+		max_supply: None,
+		mint_settings: pallet_nfts::MintSettings::default(),
+	};
+	pallet_nfts::Pallet::<T>::create(
+		RawOrigin::Signed(organizer.clone()).into(),
+		T::Lookup::unlookup(organizer.clone()),
+		collection_config,
+	)?;
+	Pallet::<T>::set_organizer(RawOrigin::Root.into(), organizer.clone())?;
+	Pallet::<T>::set_collection_id(
+		RawOrigin::Signed(organizer.clone()).into(),
+		CollectionIdOf::<T>::from(0_u32),
+	)
+}
+
+fn fund_item_deposit<T: Config + pallet_nfts::Config>(owner: &T::AccountId) {
+	let item_deposit = <T as pallet_nfts::Config>::ItemDeposit::get();
+	let ed = NftCurrencyOf::<T>::minimum_balance();
+	NftCurrencyOf::<T>::make_free_balance_be(owner, item_deposit + ed);
+}
+
 benchmarks! {
+	where_clause { where T: pallet_nfts::Config }
+
 	set_organizer {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 	}: _(RawOrigin::Root, origin.clone())
 	verify {
 		assert_eq!(Pallet::<T>::organizer(), Some(origin))
@@ -58,17 +126,17 @@ benchmarks! {
 
 	update_config {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
-		let expected_config: [u8; 10] = [0, 1, 0, 0, 0, 0, 0, 0, 0, 0];
-	}: _(RawOrigin::Signed(origin.clone()), 1, Some(1))
+		let expected_config = GameConfig { max_mogwais: 1, ..Default::default() };
+	}: _(RawOrigin::Signed(origin.clone()), GameConfigType::MaxMogwaisInAccount, Some(1))
 	verify {
 		assert_eq!(Pallet::<T>::account_config(origin), Some(expected_config));
 	}
 
 	set_price {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -82,7 +150,7 @@ benchmarks! {
 
 	remove_price {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -96,7 +164,7 @@ benchmarks! {
 
 	create_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 	}: _(RawOrigin::Signed(origin.clone()))
 	verify {
@@ -105,7 +173,7 @@ benchmarks! {
 
 	remove_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -117,7 +185,7 @@ benchmarks! {
 
 	transfer {
 		let origin_1: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin_1, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin_1, CurrencyOf::<T>::minimum_balance() * 20_000_000_u32.into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin_1.clone())?;
 		let origin_2: T::AccountId = account::<T>("origin_2");
 		whitelist_account!(origin_2);
@@ -132,21 +200,32 @@ benchmarks! {
 
 	hatch_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
 
 		frame_system::Pallet::<T>::set_block_number(1000_u32.into());
+		// fill all but the last block searched for room
+		let earliest = frame_system::Pallet::<T>::block_number() + T::HatchDelay::get().max(1_u32.into());
+		for offset in 0..MAX_HATCH_BLOCKS_SEARCHED - 1 {
+			let queue: HatchQueueOf<T> = (0..MAX_HATCHES_PER_BLOCK)
+				.map(|i| T::Hashing::hash_of(&(offset, i)))
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap();
+			HatchQueue::<T>::insert(earliest + offset.into(), queue);
+		}
 	}: _(RawOrigin::Signed(origin.clone()), mogwai_id)
 	verify {
-		assert_eq!(Pallet::<T>::mogwai(mogwai_id).unwrap().phase, PhaseType::Hatched);
+		let hatch_at = earliest + (MAX_HATCH_BLOCKS_SEARCHED - 1).into();
+		assert_eq!(Pallet::<T>::hatch_requests(mogwai_id), Some(hatch_at));
 	}
 
 	sacrifice {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -159,7 +238,7 @@ benchmarks! {
 
 	sacrifice_into {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -171,15 +250,16 @@ benchmarks! {
 		force_hatch_mogwai::<T>(&mogwai_id_2);
 		force_mogwai_rarity::<T>(&mogwai_id_1, RarityType::Epic);
 		force_mogwai_rarity::<T>(&mogwai_id_2, RarityType::Epic);
+		force_promotion_due::<T>(&mogwai_id_2);
 	}: _(RawOrigin::Signed(origin.clone()), mogwai_id_1, mogwai_id_2)
 	verify {
 		assert_eq!(Pallet::<T>::mogwai(mogwai_id_1), None);
-		assert!(Pallet::<T>::mogwai(mogwai_id_2).is_some());
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id_2).unwrap().phase, PhaseType::Matured);
 	}
 
 	buy_mogwai {
 		let origin_1: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin_1, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		CurrencyOf::<T>::make_free_balance_be(&origin_1, CurrencyOf::<T>::minimum_balance() * 20_000_000_u32.into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin_1.clone())?;
 		let origin_2: T::AccountId = account::<T>("origin_2");
 		whitelist_account!(origin_2);
@@ -197,7 +277,7 @@ benchmarks! {
 
 	morph_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -207,19 +287,294 @@ benchmarks! {
 
 	breed_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
 		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
-		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
-		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
-		let mut mogwai_iter = Mogwais::<T>::iter_values();
-		let mogwai_id_1 = mogwai_iter.next().unwrap().id;
-		let mogwai_id_2 = mogwai_iter.next().unwrap().id;
-		force_hatch_mogwai::<T>(&mogwai_id_1);
-		force_hatch_mogwai::<T>(&mogwai_id_2);
+		let stud_owner: T::AccountId = account::<T>("stud_owner");
+		let mogwai_id_1 = create_hatched_mogwai::<T>(&origin)?;
+		let mogwai_id_2 = create_hatched_mogwai::<T>(&stud_owner)?;
+		Pallet::<T>::list_stud(RawOrigin::Signed(stud_owner).into(), mogwai_id_2, 1_000_u32.into(), 1)?;
+		force_promotion_due::<T>(&mogwai_id_1);
+		force_promotion_due::<T>(&mogwai_id_2);
 	}: _(RawOrigin::Signed(origin.clone()), mogwai_id_1, mogwai_id_2)
 	verify {
 		assert_eq!(Pallet::<T>::all_mogwais_count(), 3_u64);
+		assert_eq!(Pallet::<T>::stud_listings(mogwai_id_2), None);
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id_1).unwrap().phase, PhaseType::Matured);
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id_2).unwrap().phase, PhaseType::Matured);
+	}
+
+	challenge_mogwai {
+		let origin_1: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin_1, CurrencyOf::<T>::minimum_balance() * 20_000_000_u32.into());
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin_1.clone())?;
+		let origin_2: T::AccountId = account::<T>("origin_2");
+		whitelist_account!(origin_2);
+
+		let mogwai_id_1 = create_hatched_mogwai::<T>(&origin_1)?;
+		let mogwai_id_2 = create_hatched_mogwai::<T>(&origin_2)?;
+	}: _(RawOrigin::Signed(origin_1.clone()), mogwai_id_1, mogwai_id_2)
+	verify {
+		assert_eq!(Pallet::<T>::challenges(mogwai_id_1).unwrap().opponent_mogwai, mogwai_id_2);
+	}
+
+	cancel_challenge {
+		let origin_1: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin_1, CurrencyOf::<T>::minimum_balance() * 20_000_000_u32.into());
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin_1.clone())?;
+		let origin_2: T::AccountId = account::<T>("origin_2");
+		whitelist_account!(origin_2);
+
+		let mogwai_id_1 = create_hatched_mogwai::<T>(&origin_1)?;
+		let mogwai_id_2 = create_hatched_mogwai::<T>(&origin_2)?;
+		Pallet::<T>::challenge_mogwai(RawOrigin::Signed(origin_1.clone()).into(), mogwai_id_1, mogwai_id_2)?;
+	}: _(RawOrigin::Signed(origin_1.clone()), mogwai_id_1)
+	verify {
+		assert_eq!(Pallet::<T>::challenges(mogwai_id_1), None);
+	}
+
+	accept_challenge {
+		let origin_1: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin_1, CurrencyOf::<T>::minimum_balance() * 20_000_000_u32.into());
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin_1.clone())?;
+		let origin_2: T::AccountId = account::<T>("origin_2");
+		whitelist_account!(origin_2);
+
+		let mogwai_id_1 = create_hatched_mogwai::<T>(&origin_1)?;
+		let mogwai_id_2 = create_hatched_mogwai::<T>(&origin_2)?;
+		Pallet::<T>::challenge_mogwai(RawOrigin::Signed(origin_1.clone()).into(), mogwai_id_1, mogwai_id_2)?;
+		// Both mogwais get promoted by the battle, and both owners get rated.
+		force_promotion_due::<T>(&mogwai_id_1);
+		force_promotion_due::<T>(&mogwai_id_2);
+	}: _(RawOrigin::Signed(origin_2.clone()), mogwai_id_1)
+	verify {
+		let record_1 = Pallet::<T>::battle_records(mogwai_id_1);
+		let record_2 = Pallet::<T>::battle_records(mogwai_id_2);
+		assert_eq!(record_1.wins + record_2.wins, 1);
+		assert_eq!(record_1.losses + record_2.losses, 1);
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id_1).unwrap().phase, PhaseType::Matured);
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id_2).unwrap().phase, PhaseType::Matured);
+		assert!(AccountRatings::<T>::contains_key(&origin_1));
+	}
+
+	enqueue_mogwai {
+		let origin: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
+		let mogwai_id = create_hatched_mogwai::<T>(&origin)?;
+		Mogwais::<T>::mutate(mogwai_id, |maybe_mogwai| {
+			if let Some(ref mut mogwai) = maybe_mogwai {
+				mogwai.phase = PhaseType::Matured;
+			}
+		});
+	}: _(RawOrigin::Signed(origin), mogwai_id, Some(1_000_u32.into()))
+	verify {
+		assert!(Pallet::<T>::queued_mogwais(mogwai_id).is_some());
+	}
+
+	dequeue_mogwai {
+		let origin: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
+		let mogwai_id = create_queued_mogwai::<T>(&origin, MogwaiGeneration::First)?;
+	}: _(RawOrigin::Signed(origin), mogwai_id)
+	verify {
+		assert_eq!(Pallet::<T>::queued_mogwais(mogwai_id), None);
+	}
+
+	match_mogwais {
+		let n in 0 .. T::MaxMatchesPerBlock::get();
+		let b in 1 .. MAX_BRACKETS_MATCHED_PER_BLOCK;
+
+		// The pairs are queued in the last bracket scanned, so the limit doesn't skip any bracket.
+		for i in 0..n {
+			for name in ["player_1", "player_2"] {
+				let player: T::AccountId = frame_benchmarking::account(name, i, 0);
+				CurrencyOf::<T>::make_free_balance_be(&player, 999_999_999_u64.unique_saturated_into());
+				create_queued_mogwai::<T>(&player, MogwaiGeneration::coerce_from(b as u16))?;
+			}
+		}
+
+		// Fill every bracket with entries of a single owner, which can't be paired.
+		let owner = account::<T>("owner");
+		for generation in 1..=b {
+			let bracket = MatchBracket {
+				generation: MogwaiGeneration::coerce_from(generation as u16),
+				rarity: RarityType::Common,
+			};
+			MatchQueue::<T>::mutate(bracket, |queue| {
+				for i in queue.len() as u32..MAX_QUEUE_SIZE {
+					let mogwai_id = T::Hashing::hash_of(&(generation, i));
+					let entry = MatchQueueEntry { owner: owner.clone(), mogwai_id, wager: None };
+					queue.try_push(entry).expect("queue has room up to its bound");
+				}
+			});
+			PendingBrackets::<T>::insert(bracket, ());
+		}
+	}: {
+		Pallet::<T>::match_mogwais(T::MaxMatchesPerBlock::get())
+	}
+	verify {
+		assert_eq!(QueuedMogwais::<T>::iter().count(), 0);
+		assert_eq!(MatchQueue::<T>::iter().count(), b as usize);
+	}
+
+	set_achievement_reward {
+		let organizer: T::AccountId = whitelisted_caller();
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), organizer.clone())?;
+		let reward = AchievementReward {
+			tokens: 1_000_u32.into(),
+			eggs: MAX_REWARD_EGGS,
+			config_upgrade: Some(GameConfigType::MaxMogwaisInAccount),
+		};
+	}: _(RawOrigin::Signed(organizer), AccountAchievement::EggHatcher, Some(reward))
+	verify {
+		assert_eq!(Pallet::<T>::achievement_rewards(AccountAchievement::EggHatcher), Some(reward));
+	}
+
+	claim_achievement {
+		let organizer: T::AccountId = account::<T>("organizer");
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), organizer.clone())?;
+		let origin: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
+
+		let achievement = AccountAchievement::EggHatcher;
+		let reward = AchievementReward {
+			tokens: 1_000_u32.into(),
+			eggs: MAX_REWARD_EGGS,
+			config_upgrade: Some(GameConfigType::MaxMogwaisInAccount),
+		};
+		Pallet::<T>::set_achievement_reward(RawOrigin::Signed(organizer).into(), achievement, Some(reward))?;
+		AccountAchievements::<T>::insert(&origin, achievement, AchievementState::Completed);
+	}: _(RawOrigin::Signed(origin.clone()), achievement)
+	verify {
+		assert!(Pallet::<T>::claimed_achievements(&origin, achievement).is_some());
+		assert_eq!(Owners::<T>::get(&origin).len(), MAX_REWARD_EGGS as usize);
+	}
+
+	set_account_name {
+		let origin: T::AccountId = whitelisted_caller();
+		AccountConfig::<T>::insert(&origin, GameConfig { account_naming: 1, ..Default::default() });
+		let name: AccountNameOf = sp_std::vec![b'x'; MAX_ACCOUNT_NAME_LENGTH as usize].try_into().unwrap();
+	}: _(RawOrigin::Signed(origin.clone()), name.clone())
+	verify {
+		assert_eq!(Pallet::<T>::account_name(origin), Some(name));
+	}
+
+	transfer_mogwai {
+		let organizer: T::AccountId = account::<T>("organizer");
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), organizer.clone())?;
+		Pallet::<T>::set_transfer_fee(RawOrigin::Signed(organizer).into(), Some(1_000_u32.into()))?;
+
+		let owner: T::AccountId = account::<T>("owner");
+		let operator: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&operator, CurrencyOf::<T>::minimum_balance() * 20_000_000_u32.into());
+		let to: T::AccountId = account::<T>("to");
+
+		let mogwai_id = create_hatched_mogwai::<T>(&owner)?;
+		Pallet::<T>::set_price(RawOrigin::Signed(owner.clone()).into(), mogwai_id, 1_000_u32.into())?;
+		Pallet::<T>::approve_mogwai(RawOrigin::Signed(owner.clone()).into(), mogwai_id, Some(operator.clone()))?;
+	}: _(RawOrigin::Signed(operator), to.clone(), mogwai_id)
+	verify {
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id).unwrap().owner, to);
+		assert_eq!(Pallet::<T>::mogwai_approvals(mogwai_id), None);
+	}
+
+	approve_mogwai {
+		let owner: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&owner, CurrencyOf::<T>::minimum_balance() * 20_000_000_u32.into());
+		let operator: T::AccountId = account::<T>("operator");
+		let mogwai_id = create_hatched_mogwai::<T>(&owner)?;
+	}: _(RawOrigin::Signed(owner), mogwai_id, Some(operator.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::mogwai_approvals(mogwai_id), Some(operator));
+	}
+
+	set_transfer_fee {
+		let organizer: T::AccountId = whitelisted_caller();
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), organizer.clone())?;
+		let fee: BalanceOf<T> = 1_000_u32.into();
+	}: _(RawOrigin::Signed(organizer), Some(fee))
+	verify {
+		assert_eq!(Pallet::<T>::transfer_fee(), Some(fee));
+	}
+
+	list_stud {
+		let origin: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
+		let mogwai_id = create_hatched_mogwai::<T>(&origin)?;
+	}: _(RawOrigin::Signed(origin), mogwai_id, 1_000_u32.into(), 10)
+	verify {
+		assert!(Pallet::<T>::stud_listings(mogwai_id).is_some());
+	}
+
+	unlist_stud {
+		let origin: T::AccountId = whitelisted_caller();
+		CurrencyOf::<T>::make_free_balance_be(&origin, CurrencyOf::<T>::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
+		let mogwai_id = create_hatched_mogwai::<T>(&origin)?;
+		Pallet::<T>::list_stud(RawOrigin::Signed(origin.clone()).into(), mogwai_id, 1_000_u32.into(), 10)?;
+	}: _(RawOrigin::Signed(origin), mogwai_id)
+	verify {
+		assert_eq!(Pallet::<T>::stud_listings(mogwai_id), None);
+	}
+
+	resolve_hatches {
+		let n in 0 .. MAX_HATCHES_PER_BLOCK;
+		frame_system::Pallet::<T>::set_block_number(1000_u32.into());
+		let mut mogwai_ids = Vec::new();
+		for i in 0..n {
+			let player: T::AccountId = frame_benchmarking::account("player", i, 0);
+			CurrencyOf::<T>::make_free_balance_be(&player, 999_999_999_u64.unique_saturated_into());
+			Pallet::<T>::create_mogwai(RawOrigin::Signed(player.clone()).into())?;
+			let mogwai_id = *Owners::<T>::get(&player).iter().next().unwrap();
+			Mogwais::<T>::mutate(mogwai_id, |maybe_mogwai| {
+				if let Some(ref mut mogwai) = maybe_mogwai {
+					mogwai.genesis = Zero::zero();
+				}
+			});
+			Pallet::<T>::hatch_mogwai(RawOrigin::Signed(player).into(), mogwai_id)?;
+			mogwai_ids.push(mogwai_id);
+		}
+		let hatch_at = frame_system::Pallet::<T>::block_number() + T::HatchDelay::get().max(1_u32.into());
+	}: {
+		Pallet::<T>::resolve_hatches(hatch_at)
+	}
+	verify {
+		for mogwai_id in mogwai_ids {
+			assert_eq!(Pallet::<T>::mogwai(mogwai_id).unwrap().phase, PhaseType::Hatched);
+		}
+	}
+
+	set_collection_id {
+		let organizer: T::AccountId = whitelisted_caller();
+		Pallet::<T>::set_organizer(RawOrigin::Root.into(), organizer.clone())?;
+		let collection_id = CollectionIdOf::<T>::from(1_u32);
+	}: _(RawOrigin::Signed(organizer), collection_id.clone())
+	verify {
+		assert_eq!(Pallet::<T>::collection_id(), Some(collection_id));
+	}
+
+	lock_mogwai {
+		create_collection::<T>(&account::<T>("organizer"))?;
+		let player = account::<T>("player");
+		let mogwai_id = create_hatched_mogwai::<T>(&player)?;
+		fund_item_deposit::<T>(&player);
+		let ipfs_url = IpfsUrlOf::try_from(b"ipfs://mogwai".to_vec()).unwrap();
+	}: _(RawOrigin::Signed(player), mogwai_id, ipfs_url)
+	verify {
+		assert!(LockedMogwais::<T>::contains_key(mogwai_id));
+		assert!(Pallet::<T>::mogwai(mogwai_id).is_none());
+	}
+
+	unlock_mogwai {
+		create_collection::<T>(&account::<T>("organizer"))?;
+		let player = account::<T>("player");
+		let mogwai_id = create_hatched_mogwai::<T>(&player)?;
+		fund_item_deposit::<T>(&player);
+		let ipfs_url = IpfsUrlOf::try_from(b"ipfs://mogwai".to_vec()).unwrap();
+		Pallet::<T>::lock_mogwai(RawOrigin::Signed(player.clone()).into(), mogwai_id, ipfs_url)?;
+	}: _(RawOrigin::Signed(player), mogwai_id)
+	verify {
+		assert!(!LockedMogwais::<T>::contains_key(mogwai_id));
+		assert!(Pallet::<T>::mogwai(mogwai_id).is_some());
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder.build(), crate::mock::Test);
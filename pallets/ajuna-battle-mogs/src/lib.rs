@@ -21,11 +21,15 @@ pub use pallet::*;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, Randomness, ReservableCurrency, WithdrawReasons},
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, Randomness, ReservableCurrency,
+		WithdrawReasons,
+	},
 };
 use frame_system::pallet_prelude::*;
+use pallet_ajuna_nft_transfer::traits::NftHandler;
 use sp_runtime::{
-	traits::{Hash, Saturating, TrailingZeroInput, Zero},
+	traits::{Hash, One, Saturating, TrailingZeroInput, Zero},
 	DispatchResult, SaturatedConversion,
 };
 use sp_std::{mem::MaybeUninit, prelude::*, ptr::copy_nonoverlapping, vec::Vec};
@@ -39,13 +43,16 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-mod migration;
+pub mod migration;
 
 mod algorithm;
+mod battle;
+mod nft;
 mod types;
 pub mod weights;
 
 pub use algorithm::*;
+pub use battle::*;
 pub use types::*;
 
 type BalanceOf<T> =
@@ -69,11 +76,36 @@ pub mod pallet {
 		PhaseType,
 		<T as frame_system::Config>::AccountId,
 	>;
+	pub(crate) type BattleChallengeOf<T> =
+		BattleChallenge<<T as frame_system::Config>::AccountId, MogwaiIdOf<T>, BlockNumberFor<T>>;
+	pub(crate) type MatchQueueEntryOf<T> =
+		MatchQueueEntry<<T as frame_system::Config>::AccountId, MogwaiIdOf<T>, BalanceOf<T>>;
+	pub(crate) type MatchQueueOf<T> = BoundedVec<MatchQueueEntryOf<T>, ConstU32<MAX_QUEUE_SIZE>>;
 	pub(crate) type BoundedMogwaiIdsOf<T> =
 		BoundedBTreeSet<MogwaiIdOf<T>, ConstU32<MAX_MOGWAIS_PER_PLAYER>>;
+	pub(crate) type AchievementRewardOf<T> = AchievementReward<BalanceOf<T>>;
+	pub(crate) type StudListingOf<T> = StudListing<BalanceOf<T>>;
+	pub(crate) type HatchQueueOf<T> = BoundedVec<MogwaiIdOf<T>, ConstU32<MAX_HATCHES_PER_BLOCK>>;
+	pub(crate) type CollectionIdOf<T> = <<T as Config>::NftHandler as NftHandler<
+		<T as frame_system::Config>::AccountId,
+		MogwaiIdOf<T>,
+		<T as Config>::KeyLimit,
+		<T as Config>::ValueLimit,
+		MogwaiOf<T>,
+	>>::CollectionId;
+	pub(crate) type IpfsUrlOf = BoundedVec<u8, ConstU32<MAX_IPFS_URL_LENGTH>>;
+	pub(crate) type AccountNameOf = BoundedVec<u8, ConstU32<MAX_ACCOUNT_NAME_LENGTH>>;
 	pub(crate) type MogwaiCount = u64;
 
 	pub(crate) const MAX_MOGWAIS_PER_PLAYER: u32 = 24;
+	pub(crate) const MAX_QUEUE_SIZE: u32 = 64;
+	pub(crate) const MAX_BRACKETS_MATCHED_PER_BLOCK: u32 = 4;
+	pub(crate) const MAX_REWARD_EGGS: u8 = 3;
+	pub(crate) const MAX_ACCOUNT_NAME_LENGTH: u32 = 32;
+	pub(crate) const MAX_FAMILY_TREE_DEPTH: u8 = 4;
+	pub(crate) const MAX_HATCHES_PER_BLOCK: u32 = 64;
+	pub(crate) const MAX_HATCH_BLOCKS_SEARCHED: u32 = 16;
+	pub(crate) const MAX_IPFS_URL_LENGTH: u32 = 128;
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
@@ -87,6 +119,37 @@ pub mod pallet {
 		/// Something that provides randomness in the runtime.
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
+		/// The maximum number of queued matches resolved at the start of each block.
+		#[pallet::constant]
+		type MaxMatchesPerBlock: Get<u32>;
+
+		/// The number of blocks both parents must rest after breeding.
+		#[pallet::constant]
+		type BreedingCooldown: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks between a hatch request and its resolution. Eggs are baked with
+		/// the hash of the block preceding the resolution, so a delay of at least 2 keeps that
+		/// hash out of reach when the request is made.
+		#[pallet::constant]
+		type HatchDelay: Get<BlockNumberFor<Self>>;
+
+		/// The maximum length of an NFT attribute key.
+		#[pallet::constant]
+		type KeyLimit: Get<u32>;
+
+		/// The maximum length of an NFT attribute value.
+		#[pallet::constant]
+		type ValueLimit: Get<u32>;
+
+		/// Converts locked mogwais to and from their NFT representation.
+		type NftHandler: NftHandler<
+			Self::AccountId,
+			MogwaiIdOf<Self>,
+			Self::KeyLimit,
+			Self::ValueLimit,
+			MogwaiOf<Self>,
+		>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -103,7 +166,13 @@ pub mod pallet {
 	#[pallet::getter(fn account_config)]
 	/// A map of the current configuration of an account.
 	pub type AccountConfig<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, [u8; 10], OptionQuery>;
+		StorageMap<_, Blake2_128Concat, T::AccountId, GameConfig, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn account_name)]
+	/// The names chosen by accounts that unlocked account naming.
+	pub type AccountNames<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AccountNameOf, OptionQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn account_achievements)]
@@ -117,10 +186,81 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn achievement_rewards)]
+	/// The reward granted for each completed achievement, as configured by the organizer.
+	pub type AchievementRewards<T: Config> =
+		StorageMap<_, Identity, AccountAchievement, AchievementRewardOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claimed_achievements)]
+	/// The achievements whose reward has already been claimed by an account.
+	pub type ClaimedAchievements<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Identity,
+		AccountAchievement,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn mogwai)]
 	/// A map of mogwais accessible by the mogwai hash.
 	pub type Mogwais<T: Config> = StorageMap<_, Identity, MogwaiIdOf<T>, MogwaiOf<T>, OptionQuery>;
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_pedigrees)]
+	/// The ancestry of each mogwai, kept after a mogwai is removed so that the family trees of
+	/// its descendants stay complete.
+	pub type MogwaiPedigrees<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, Pedigree<MogwaiIdOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn stud_listings)]
+	/// The mogwais their owners offer for breeding with other players' mogwais.
+	pub type StudListings<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, StudListingOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn breeding_cooldowns)]
+	/// The block from which a mogwai can breed again.
+	pub type BreedingCooldowns<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_id)]
+	/// The NFT collection locked mogwais are minted into.
+	pub type CollectionId<T: Config> = StorageValue<_, CollectionIdOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn locked_mogwais)]
+	/// The mogwais currently living as NFTs outside of the game.
+	pub type LockedMogwais<T: Config> = StorageMap<_, Identity, MogwaiIdOf<T>, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn hatch_requests)]
+	/// The block at which each requested hatch gets resolved.
+	pub type HatchRequests<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn hatch_queue)]
+	/// The eggs to hatch at the start of each block.
+	pub type HatchQueue<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, HatchQueueOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_approvals)]
+	/// The account allowed to transfer a mogwai on behalf of its owner.
+	pub type MogwaiApprovals<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_fee)]
+	/// The fee paid to the organizer for player-initiated transfers, if any.
+	pub type TransferFee<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn mogwai_prices)]
 	/// A map of mogwais that are up for sale.
@@ -143,6 +283,59 @@ pub mod pallet {
 	pub type OwnedMogwaisCount<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, MogwaiCount, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_experience)]
+	/// The experience each mogwai earned, promoting its phase once thresholds are reached.
+	pub type MogwaiExperience<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, ExperiencePoints, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn challenges)]
+	/// Pending battle challenges, keyed by the challenging mogwai.
+	pub type Challenges<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, BattleChallengeOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn battle_records)]
+	/// The wins and losses of each mogwai.
+	pub type BattleRecords<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, BattleRecord, ValueQuery>;
+
+	/// Default value for ratings
+	#[pallet::type_value]
+	pub fn RatingDefault<T: Config>() -> Rating {
+		INITIAL_RATING
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn account_rating)]
+	/// The Elo rating of each account, updated after every match of its mogwais.
+	pub type AccountRatings<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Rating, ValueQuery, RatingDefault<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_rating)]
+	/// The Elo rating of each mogwai.
+	pub type MogwaiRatings<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, Rating, ValueQuery, RatingDefault<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn match_queue)]
+	/// Mogwais waiting to be matched, per bracket.
+	pub type MatchQueue<T: Config> =
+		StorageMap<_, Identity, MatchBracket, MatchQueueOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queued_mogwais)]
+	/// The bracket each queued mogwai is waiting in.
+	pub type QueuedMogwais<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, MatchBracket, OptionQuery>;
+
+	#[pallet::storage]
+	/// Brackets whose queue changed since they were last matched. Only these brackets are
+	/// scanned for pairs, so mogwais that can't be paired aren't rescanned every block.
+	pub type PendingBrackets<T: Config> = StorageMap<_, Identity, MatchBracket, (), OptionQuery>;
+
 	/// Default value for Nonce
 	#[pallet::type_value]
 	pub fn NonceDefault<T: Config>() -> u64 {
@@ -159,7 +352,25 @@ pub mod pallet {
 		OrganizerSet(T::AccountId),
 
 		/// A account configuration has been changed.
-		AccountConfigChanged(T::AccountId, [u8; GameConfig::PARAM_COUNT]),
+		AccountConfigChanged(T::AccountId, GameConfig),
+
+		/// An account has changed its name.
+		AccountNameSet(T::AccountId, AccountNameOf),
+
+		/// An account has been approved, or disapproved, to transfer a mogwai.
+		MogwaiApproved(T::AccountId, T::Hash, Option<T::AccountId>),
+
+		/// The fee of player-initiated transfers has been changed.
+		TransferFeeSet(Option<BalanceOf<T>>),
+
+		/// A mogwai has been listed for breeding.
+		StudListed(T::AccountId, T::Hash, StudListingOf<T>),
+
+		/// A mogwai has been taken off the breeding listings.
+		StudUnlisted(T::AccountId, T::Hash),
+
+		/// A listed mogwai has been bred with, paying its owner.
+		StudUsed(T::AccountId, T::Hash, BalanceOf<T>),
 
 		/// A price has been set for a mogwai.
 		ForSale(T::AccountId, T::Hash, BalanceOf<T>),
@@ -173,12 +384,24 @@ pub mod pallet {
 		/// A mogwai was removed, by the Emperor himself!
 		MogwaiRemoved(T::AccountId, T::Hash),
 
-		/// A mogwai was transfered, by the Emperor himself!
+		/// A mogwai was transfered, by the Emperor himself or its owner.
 		MogwaiTransfered(T::AccountId, T::AccountId, T::Hash),
 
 		/// A mogwai has been bought.
 		MogwaiBought(T::AccountId, T::AccountId, T::Hash, BalanceOf<T>),
 
+		/// The NFT collection of locked mogwais has been set.
+		CollectionIdSet(CollectionIdOf<T>),
+
+		/// A mogwai has been locked into an NFT.
+		MogwaiLocked(T::AccountId, T::Hash),
+
+		/// A mogwai has been unlocked from its NFT back into the game.
+		MogwaiUnlocked(T::AccountId, T::Hash),
+
+		/// A mogwai egg will hatch at the given block.
+		MogwaiHatchRequested(T::AccountId, T::Hash, BlockNumberFor<T>),
+
 		/// A mogwai has been hatched.
 		MogwaiHatched(T::AccountId, T::Hash),
 
@@ -193,6 +416,36 @@ pub mod pallet {
 
 		/// A mogwai has been bred.
 		MogwaiBred(T::Hash),
+
+		/// A mogwai has challenged an other one to a battle.
+		MogwaiChallenged(T::AccountId, T::Hash, T::Hash),
+
+		/// A battle challenge has been withdrawn.
+		ChallengeCancelled(T::AccountId, T::Hash),
+
+		/// A battle between two mogwais has been fought, with the winner and the replay log.
+		MogwaiBattled(T::Hash, T::Hash, T::Hash, BattleLog),
+
+		/// A mogwai has joined the matchmaking queue of a bracket.
+		MogwaiQueued(T::AccountId, T::Hash, MatchBracket),
+
+		/// A mogwai has left the matchmaking queue.
+		MogwaiDequeued(T::AccountId, T::Hash),
+
+		/// Two queued mogwais have been matched against each other.
+		MogwaisMatched(T::Hash, T::Hash),
+
+		/// A mogwai has earned enough experience to be promoted to a new phase.
+		MogwaiPromoted(T::Hash, PhaseType),
+
+		/// An account has completed an achievement.
+		AchievementCompleted(T::AccountId, AccountAchievement),
+
+		/// The reward of an achievement has been changed by the organizer.
+		AchievementRewardSet(AccountAchievement, Option<AchievementRewardOf<T>>),
+
+		/// An account has claimed the reward of a completed achievement.
+		AchievementClaimed(T::AccountId, AccountAchievement),
 	}
 
 	#[pallet::error]
@@ -244,6 +497,82 @@ pub mod pallet {
 
 		/// The specified mogwai sells for more than what the sender wants to pay.
 		MogwaiNotAffordable,
+
+		/// Mogwais owned by the same account can't battle each other.
+		MogwaiBattleSameOwner,
+
+		/// The mogwai has already issued a challenge.
+		ChallengeAlreadyExists,
+
+		/// No challenge has been issued by the mogwai.
+		ChallengeNotFound,
+
+		/// The mogwai is already waiting in the matchmaking queue.
+		MogwaiAlreadyQueued,
+
+		/// The mogwai isn't waiting in the matchmaking queue.
+		MogwaiNotQueued,
+
+		/// The matchmaking queue of the mogwai's bracket is full.
+		MatchQueueFull,
+
+		/// The mogwai hasn't reached the phase required for this action.
+		MogwaiPhaseTooLow,
+
+		/// The achievement reward grants more eggs than allowed.
+		TooManyRewardEggs,
+
+		/// The achievement hasn't been completed yet.
+		AchievementNotCompleted,
+
+		/// The achievement reward has already been claimed.
+		AchievementAlreadyClaimed,
+
+		/// No reward is configured for the achievement.
+		AchievementRewardNotFound,
+
+		/// The account already has as many mogwais on sale as its stash allows.
+		MaxStashSizeReached,
+
+		/// The account hasn't unlocked account naming.
+		AccountNamingLocked,
+
+		/// The sender is neither the owner of the mogwai nor approved to transfer it.
+		MogwaiNotApproved,
+
+		/// A mogwai can't be transferred to its current owner.
+		TransferToSelf,
+
+		/// The mogwai of another player isn't listed for breeding.
+		StudNotListed,
+
+		/// A stud listing must allow at least one use.
+		StudListingInvalid,
+
+		/// The mogwai is still resting from its last breeding.
+		BreedingOnCooldown,
+
+		/// The mogwai egg is already about to hatch.
+		MogwaiHatchPending,
+
+		/// Too many eggs are hatching in the blocks following the hatch delay.
+		HatchQueueFull,
+
+		/// The NFT collection of locked mogwais hasn't been set.
+		CollectionIdNotSet,
+
+		/// The mogwai isn't locked as an NFT.
+		MogwaiNotLocked,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let hatches = Self::resolve_hatches(n);
+			let (matches, brackets) = Self::match_mogwais(T::MaxMatchesPerBlock::get());
+			T::WeightInfo::resolve_hatches(hatches)
+				.saturating_add(T::WeightInfo::match_mogwais(matches, brackets))
+		}
 	}
 
 	#[pallet::call]
@@ -267,43 +596,23 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::update_config())]
 		pub fn update_config(
 			origin: OriginFor<T>,
-			index: u8,
-			value_opt: Option<u8>,
+			parameter: GameConfigType,
+			level: Option<u8>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			ensure!(
-				usize::from(index) < GameConfig::PARAM_COUNT,
-				Error::<T>::ConfigIndexOutOfRange
-			);
-
-			let mut game_config = GameConfig::new();
-
-			if let Some(config) = AccountConfig::<T>::get(&sender) {
-				game_config.parameters = config;
-			}
-
-			// TODO: add rules (min, max) for different configurations
-			let update_value: u8 = GameConfig::verify_update(
-				index,
-				game_config.parameters[usize::from(index)],
-				value_opt,
-			);
-			ensure!(update_value > 0, Error::<T>::ConfigUpdateInvalid);
-
-			let price = Pricing::config_update_price(index, update_value);
-			ensure!(price > 0, Error::<T>::PriceInvalid);
+			let mut game_config = AccountConfig::<T>::get(&sender).unwrap_or_default();
+			let price =
+				game_config.upgrade(parameter, level).ok_or(Error::<T>::ConfigUpdateInvalid)?;
 
 			let organizer = Self::organizer().ok_or(Error::<T>::NoOrganizer)?;
 			Self::pay_founder(sender.clone(), organizer, price.saturated_into())?;
 
-			game_config.parameters[usize::from(index)] = update_value;
-
 			// updating to the new configuration
-			AccountConfig::<T>::insert(&sender, game_config.parameters);
+			AccountConfig::<T>::insert(&sender, game_config);
 
 			// Emit an event.
-			Self::deposit_event(Event::AccountConfigChanged(sender, game_config.parameters));
+			Self::deposit_event(Event::AccountConfigChanged(sender, game_config));
 
 			Ok(())
 		}
@@ -321,6 +630,17 @@ pub mod pallet {
 				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
 			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
 
+			if !MogwaiPrices::<T>::contains_key(mogwai_id) {
+				let listed = Self::owners(&sender)
+					.iter()
+					.filter(|mogwai_id| MogwaiPrices::<T>::contains_key(mogwai_id))
+					.count() as u32;
+				ensure!(
+					listed < Self::config_value(sender.clone(), GameConfigType::MaxStashSize),
+					Error::<T>::MaxStashSizeReached
+				);
+			}
+
 			MogwaiPrices::<T>::insert(mogwai_id, new_price);
 			Self::deposit_event(Event::ForSale(sender, mogwai_id, new_price));
 
@@ -353,45 +673,7 @@ pub mod pallet {
 			// TODO Add for production!
 			//ensure!(sender == Self::organizer().unwrap(), Error::<T>::FounderAction);
 
-			// ensure that we have enough space
-			ensure!(Self::ensure_not_max_mogwais(sender.clone()), Error::<T>::MaxMogwaisInAccount);
-
-			let random_hash_1 = Self::generate_random_hash(b"create_mogwai", sender.clone());
-			let random_hash_2 = Self::generate_random_hash(b"extend_mogwai", sender.clone());
-
-			let (rarity, next_gen, max_rarity) = Generation::next_gen(
-				MogwaiGeneration::First,
-				RarityType::Common,
-				MogwaiGeneration::First,
-				RarityType::Common,
-				random_hash_1.as_ref(),
-			);
-
-			let block_number = <frame_system::Pallet<T>>::block_number();
-			let breed_type: BreedType = Self::calculate_breedtype(block_number);
-
-			let dx =
-				unsafe { &*(&random_hash_1.as_ref()[0..32] as *const [u8] as *const [u8; 32]) };
-			let dy =
-				unsafe { &*(&random_hash_2.as_ref()[0..32] as *const [u8] as *const [u8; 32]) };
-
-			let final_dna = Breeding::pairing(breed_type, dx, dy);
-
-			let new_mogwai = MogwaiStruct {
-				id: random_hash_1,
-				dna: final_dna,
-				genesis: block_number,
-				intrinsic: Zero::zero(),
-				generation: next_gen,
-				rarity: RarityType::from(((max_rarity as u8) << 4) + rarity as u8),
-				phase: PhaseType::Bred,
-				owner: sender.clone(),
-			};
-
-			Self::mint(&sender, random_hash_1, new_mogwai)?;
-
-			// Emit an event.
-			Self::deposit_event(Event::MogwaiCreated(sender, random_hash_1));
+			Self::create_egg(&sender)?;
 
 			Ok(())
 		}
@@ -437,15 +719,18 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Hatch a mogwai egg
+		/// Request a mogwai egg to hatch, it hatches automatically after `HatchDelay` blocks, or
+		/// at the first of the following blocks with room left for it.
 		#[pallet::call_index(7)]
 		#[pallet::weight(T::WeightInfo::hatch_mogwai())]
 		pub fn hatch_mogwai(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			let mut mogwai: MogwaiOf<T> =
+			let mogwai: MogwaiOf<T> =
 				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
 			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(mogwai.phase == PhaseType::Bred, Error::<T>::MogwaiNoHatch);
+			ensure!(!HatchRequests::<T>::contains_key(mogwai_id), Error::<T>::MogwaiHatchPending);
 
 			let block_number = <frame_system::Pallet<T>>::block_number();
 
@@ -455,21 +740,18 @@ pub mod pallet {
 				Error::<T>::MogwaiNoHatch
 			);
 
-			let block_hash = <frame_system::Pallet<T>>::block_hash(block_number);
-
-			let (dna, rarity) = Self::segment_and_bake(mogwai.clone(), block_hash);
-
-			mogwai.phase = PhaseType::Hatched;
-			mogwai.rarity = rarity;
-			mogwai.dna = dna;
-
-			Mogwais::<T>::insert(mogwai_id, mogwai);
-
-			// TODO: Do something with the result
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::EggHatcher, 1);
+			// queue the egg at the first block with room left, starting after the hatch delay
+			let earliest = block_number.saturating_add(T::HatchDelay::get().max(One::one()));
+			let hatch_at = (0..MAX_HATCH_BLOCKS_SEARCHED)
+				.map(|offset| earliest.saturating_add(offset.into()))
+				.find(|hatch_at| {
+					HatchQueue::<T>::try_mutate(hatch_at, |queue| queue.try_push(mogwai_id)).is_ok()
+				})
+				.ok_or(Error::<T>::HatchQueueFull)?;
+			HatchRequests::<T>::insert(mogwai_id, hatch_at);
 
 			// Emit an event.
-			Self::deposit_event(Event::MogwaiHatched(sender, mogwai_id));
+			Self::deposit_event(Event::MogwaiHatchRequested(sender, mogwai_id, hatch_at));
 
 			Ok(())
 		}
@@ -502,8 +784,7 @@ pub mod pallet {
 			// TODO check this function on return value
 			let _ = T::Currency::deposit_into_existing(&sender, intrinsic_to_deposit)?;
 
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Sacrificer, 1);
+			Self::update_achievement_for(&sender, AccountAchievement::Sacrificer, 1);
 
 			// Emit an event.
 			Self::deposit_event(Event::MogwaiSacrificed(sender, mogwai_id));
@@ -561,9 +842,9 @@ pub mod pallet {
 			}
 
 			Self::remove(sender.clone(), mogwai_id_1)?;
+			Self::gain_experience(mogwai_id_2, ExperienceSource::SacrificeInto);
 
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Sacrificer, 1);
+			Self::update_achievement_for(&sender, AccountAchievement::Sacrificer, 1);
 
 			// Emit an event.
 			Self::deposit_event(Event::MogwaiSacrificedInto(sender, mogwai_id_1, mogwai_id_2));
@@ -606,9 +887,8 @@ pub mod pallet {
 				MogwaiPrices::<T>::remove(mogwai_id);
 			}
 
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Buyer, 1);
-			let _ = Self::update_achievement_for(&mogwai.owner, AccountAchievement::Seller, 1);
+			Self::update_achievement_for(&sender, AccountAchievement::Buyer, 1);
+			Self::update_achievement_for(&mogwai.owner, AccountAchievement::Seller, 1);
 
 			// Emit an event.
 			Self::deposit_event(Event::MogwaiBought(sender, mogwai.owner, mogwai_id, mogwai_price));
@@ -646,8 +926,7 @@ pub mod pallet {
 
 			Mogwais::<T>::insert(mogwai_id, mogwai);
 
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Morpheus, 1);
+			Self::update_achievement_for(&sender, AccountAchievement::Morpheus, 1);
 
 			// Emit an event.
 			Self::deposit_event(Event::MogwaiMorphed(mogwai_id));
@@ -680,19 +959,36 @@ pub mod pallet {
 			ensure!(mogwai_1.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
 			ensure!(mogwai_2.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
 
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			for mogwai_id in [mogwai_id_1, mogwai_id_2] {
+				ensure!(
+					Self::breeding_cooldowns(mogwai_id).map_or(true, |until| until <= block_number),
+					Error::<T>::BreedingOnCooldown
+				);
+			}
+
+			// breeding with another player's mogwai requires its owner to have listed it
+			if mogwai_2.owner != sender {
+				Self::use_stud(&sender, mogwai_id_2, &mogwai_2.owner)?;
+			}
+
 			let parents = [mogwai_1.clone(), mogwai_2.clone()];
 
 			let mogwai_id = Self::generate_random_hash(b"breed_mogwai", sender.clone());
 
+			let pedigree_1 = Self::pedigree_of(mogwai_id_1);
+			let pedigree_2 = Self::pedigree_of(mogwai_id_2);
+			let kinship = Pedigree::kinship((mogwai_id_1, &pedigree_1), (mogwai_id_2, &pedigree_2));
+
 			let (rarity, next_gen, max_rarity) = Generation::next_gen(
 				parents[0].generation,
 				mogwai_1.rarity,
 				parents[1].generation,
 				mogwai_2.rarity,
+				kinship.inbreeding_penalty(),
 				mogwai_id.as_ref(),
 			);
 
-			let block_number = <frame_system::Pallet<T>>::block_number();
 			let breed_type: BreedType = Self::calculate_breedtype(block_number);
 
 			// add pairing price to mogwai intrinsic value TODO
@@ -716,15 +1012,24 @@ pub mod pallet {
 
 			// mint mogwai
 			Self::mint(&sender, mogwai_id, new_mogwai)?;
+			MogwaiPedigrees::<T>::insert(
+				mogwai_id,
+				Pedigree::bred([(mogwai_id_1, &pedigree_1), (mogwai_id_2, &pedigree_2)]),
+			);
+
+			let cooldown_until = block_number.saturating_add(T::BreedingCooldown::get());
+			BreedingCooldowns::<T>::insert(mogwai_id_1, cooldown_until);
+			BreedingCooldowns::<T>::insert(mogwai_id_2, cooldown_until);
+
+			Self::gain_experience(mogwai_id_1, ExperienceSource::Breeding);
+			Self::gain_experience(mogwai_id_2, ExperienceSource::Breeding);
 
 			if mogwai_rarity == RarityType::Mythical {
-				// TODO: Do something with the results
-				let _ = Self::update_achievement_for(&sender, AccountAchievement::LegendBreeder, 1);
+				Self::update_achievement_for(&sender, AccountAchievement::LegendBreeder, 1);
 			}
 
 			if mogwai_1.owner != mogwai_2.owner {
-				// TODO: Do something with the results
-				let _ = Self::update_achievement_for(&sender, AccountAchievement::Promiscuous, 1);
+				Self::update_achievement_for(&sender, AccountAchievement::Promiscuous, 1);
 			}
 
 			// Emit an event.
@@ -732,10 +1037,522 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Challenge the mogwai of an other player to a battle.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::challenge_mogwai())]
+		pub fn challenge_mogwai(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			opponent_mogwai_id: MogwaiIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!Challenges::<T>::contains_key(mogwai_id), Error::<T>::ChallengeAlreadyExists);
+			let (mogwai, opponent_mogwai) = Self::ensure_battle(mogwai_id, opponent_mogwai_id)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(opponent_mogwai.owner != sender, Error::<T>::MogwaiBattleSameOwner);
+
+			Challenges::<T>::insert(
+				mogwai_id,
+				BattleChallenge {
+					challenger: sender.clone(),
+					opponent_mogwai: opponent_mogwai_id,
+					created_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiChallenged(sender, mogwai_id, opponent_mogwai_id));
+
+			Ok(())
+		}
+
+		/// Withdraw a challenge issued by one of the sender's mogwais.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::cancel_challenge())]
+		pub fn cancel_challenge(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let challenge = Self::challenges(mogwai_id).ok_or(Error::<T>::ChallengeNotFound)?;
+			ensure!(challenge.challenger == sender, Error::<T>::MogwaiNotOwned);
+
+			Challenges::<T>::remove(mogwai_id);
+
+			// Emit an event.
+			Self::deposit_event(Event::ChallengeCancelled(sender, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Accept a challenge issued against one of the sender's mogwais and fight the battle.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::accept_challenge())]
+		pub fn accept_challenge(
+			origin: OriginFor<T>,
+			challenger_mogwai_id: MogwaiIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let challenge =
+				Self::challenges(challenger_mogwai_id).ok_or(Error::<T>::ChallengeNotFound)?;
+			let opponent_mogwai_id = challenge.opponent_mogwai;
+			let (mogwai, opponent_mogwai) =
+				Self::ensure_battle(challenger_mogwai_id, opponent_mogwai_id)?;
+			ensure!(opponent_mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(mogwai.owner != sender, Error::<T>::MogwaiBattleSameOwner);
+
+			let seed = Self::generate_random_hash(b"battle_mogwai", sender);
+			Self::battle(&mogwai, &opponent_mogwai, seed);
+			Challenges::<T>::remove(challenger_mogwai_id);
+
+			Ok(())
+		}
+
+		/// Queue a mogwai for matchmaking against mogwais of the same generation and rarity,
+		/// optionally wagering an amount that is reserved until the match is played. Only matured
+		/// mogwais can be wagered.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::enqueue_mogwai())]
+		pub fn enqueue_mogwai(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			wager: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
+			ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
+			ensure!(!QueuedMogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiAlreadyQueued);
+			ensure!(
+				wager.is_none() || mogwai.phase.is_at_least(PhaseType::Matured),
+				Error::<T>::MogwaiPhaseTooLow
+			);
+
+			let bracket = MatchBracket { generation: mogwai.generation, rarity: mogwai.rarity };
+			MatchQueue::<T>::try_mutate(bracket, |queue| {
+				queue.try_push(MatchQueueEntry { owner: sender.clone(), mogwai_id, wager })
+			})
+			.map_err(|_| Error::<T>::MatchQueueFull)?;
+			if let Some(amount) = wager {
+				T::Currency::reserve(&sender, amount)?;
+			}
+			QueuedMogwais::<T>::insert(mogwai_id, bracket);
+			PendingBrackets::<T>::insert(bracket, ());
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiQueued(sender, mogwai_id, bracket));
+
+			Ok(())
+		}
+
+		/// Take a mogwai out of the matchmaking queue, releasing its wager.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::dequeue_mogwai())]
+		pub fn dequeue_mogwai(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(QueuedMogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiNotQueued);
+
+			Self::dequeue(mogwai_id);
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiDequeued(sender, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Set or clear the reward of an achievement, organizer only.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_achievement_reward())]
+		pub fn set_achievement_reward(
+			origin: OriginFor<T>,
+			achievement: AccountAchievement,
+			reward: Option<AchievementRewardOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let organizer = Self::organizer().ok_or(Error::<T>::NoOrganizer)?;
+			ensure!(sender == organizer, Error::<T>::FounderAction);
+
+			if let Some(ref reward) = reward {
+				ensure!(reward.eggs <= MAX_REWARD_EGGS, Error::<T>::TooManyRewardEggs);
+			}
+
+			AchievementRewards::<T>::set(achievement, reward.clone());
+
+			// Emit an event.
+			Self::deposit_event(Event::AchievementRewardSet(achievement, reward));
+
+			Ok(())
+		}
+
+		/// Claim the reward of a completed achievement, once per account.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::claim_achievement())]
+		pub fn claim_achievement(
+			origin: OriginFor<T>,
+			achievement: AccountAchievement,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::account_achievements(&sender, achievement) ==
+					Some(AchievementState::Completed),
+				Error::<T>::AchievementNotCompleted
+			);
+			ensure!(
+				!ClaimedAchievements::<T>::contains_key(&sender, achievement),
+				Error::<T>::AchievementAlreadyClaimed
+			);
+			let reward = Self::achievement_rewards(achievement)
+				.ok_or(Error::<T>::AchievementRewardNotFound)?;
+
+			if !reward.tokens.is_zero() {
+				let organizer = Self::organizer().ok_or(Error::<T>::NoOrganizer)?;
+				T::Currency::transfer(
+					&organizer,
+					&sender,
+					reward.tokens,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			for _ in 0..reward.eggs {
+				Self::create_egg(&sender)?;
+			}
+
+			if let Some(parameter) = reward.config_upgrade {
+				Self::upgrade_config(&sender, parameter);
+			}
+
+			ClaimedAchievements::<T>::insert(&sender, achievement, ());
+
+			// Emit an event.
+			Self::deposit_event(Event::AchievementClaimed(sender, achievement));
+
+			Ok(())
+		}
+
+		/// Set the name of the sender, once account naming has been unlocked.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_account_name())]
+		pub fn set_account_name(origin: OriginFor<T>, name: AccountNameOf) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::config_value(sender.clone(), GameConfigType::AccountNaming) > 0,
+				Error::<T>::AccountNamingLocked
+			);
+
+			AccountNames::<T>::insert(&sender, &name);
+
+			// Emit an event.
+			Self::deposit_event(Event::AccountNameSet(sender, name));
+
+			Ok(())
+		}
+
+		/// Transfer a mogwai to another account, either by its owner or by the account approved
+		/// to transfer it. Mogwais on sale will be unlisted after transfer.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::transfer_mogwai())]
+		pub fn transfer_mogwai(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			mogwai_id: MogwaiIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(
+				mogwai.owner == sender || Self::mogwai_approvals(mogwai_id) == Some(sender.clone()),
+				Error::<T>::MogwaiNotApproved
+			);
+			ensure!(mogwai.owner != to, Error::<T>::TransferToSelf);
+
+			// ensure that we have enough space
+			ensure!(Self::ensure_not_max_mogwais(to.clone()), Error::<T>::MaxMogwaisInAccount);
+
+			if let Some(fee) = Self::transfer_fee() {
+				let organizer = Self::organizer().ok_or(Error::<T>::NoOrganizer)?;
+				Self::pay_founder(sender, organizer, fee)?;
+			}
+
+			Self::transfer_unchecked(mogwai.owner.clone(), to.clone(), mogwai_id)?;
+
+			if MogwaiPrices::<T>::contains_key(mogwai_id) {
+				MogwaiPrices::<T>::remove(mogwai_id);
+			}
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiTransfered(mogwai.owner, to, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Approve an account to transfer a mogwai on behalf of its owner, or clear the approval.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::approve_mogwai())]
+		pub fn approve_mogwai(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			operator: Option<T::AccountId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+
+			MogwaiApprovals::<T>::set(mogwai_id, operator.clone());
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiApproved(sender, mogwai_id, operator));
+
+			Ok(())
+		}
+
+		/// Set or clear the fee of player-initiated transfers, organizer only.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_transfer_fee())]
+		pub fn set_transfer_fee(origin: OriginFor<T>, fee: Option<BalanceOf<T>>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let organizer = Self::organizer().ok_or(Error::<T>::NoOrganizer)?;
+			ensure!(sender == organizer, Error::<T>::FounderAction);
+
+			TransferFee::<T>::set(fee);
+
+			// Emit an event.
+			Self::deposit_event(Event::TransferFeeSet(fee));
+
+			Ok(())
+		}
+
+		/// List a mogwai for breeding with other players' mogwais, for a price paid to the owner
+		/// and a limited number of uses. Listing an already listed mogwai replaces its listing.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::list_stud())]
+		pub fn list_stud(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			price: BalanceOf<T>,
+			uses: u16,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
+			ensure!(uses > 0, Error::<T>::StudListingInvalid);
+
+			let listing = StudListing { price, uses_left: uses };
+			StudListings::<T>::insert(mogwai_id, listing);
+
+			// Emit an event.
+			Self::deposit_event(Event::StudListed(sender, mogwai_id, listing));
+
+			Ok(())
+		}
+
+		/// Take a mogwai off the breeding listings.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::unlist_stud())]
+		pub fn unlist_stud(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(StudListings::<T>::contains_key(mogwai_id), Error::<T>::StudNotListed);
+
+			StudListings::<T>::remove(mogwai_id);
+
+			// Emit an event.
+			Self::deposit_event(Event::StudUnlisted(sender, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Set the externally created NFT collection that locked mogwais are minted into,
+		/// organizer only.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_collection_id())]
+		pub fn set_collection_id(
+			origin: OriginFor<T>,
+			collection_id: CollectionIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let organizer = Self::organizer().ok_or(Error::<T>::NoOrganizer)?;
+			ensure!(sender == organizer, Error::<T>::FounderAction);
+
+			CollectionId::<T>::put(&collection_id);
+
+			// Emit an event.
+			Self::deposit_event(Event::CollectionIdSet(collection_id));
+
+			Ok(())
+		}
+
+		/// Lock a hatched mogwai into an NFT, taking it out of the game until it is unlocked.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::lock_mogwai())]
+		pub fn lock_mogwai(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			ipfs_url: IpfsUrlOf,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collection_id = Self::collection_id().ok_or(Error::<T>::CollectionIdNotSet)?;
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
+			ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
+
+			Self::withdraw(&sender, mogwai_id)?;
+			T::NftHandler::store_as_nft(
+				sender.clone(),
+				collection_id,
+				mogwai_id,
+				mogwai,
+				ipfs_url.into_inner(),
+			)?;
+			LockedMogwais::<T>::insert(mogwai_id, ());
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiLocked(sender, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Unlock a mogwai from the NFT held by the sender, bringing it back into the game.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::unlock_mogwai())]
+		pub fn unlock_mogwai(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collection_id = Self::collection_id().ok_or(Error::<T>::CollectionIdNotSet)?;
+			ensure!(LockedMogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiNotLocked);
+
+			// ensure that we have enough space
+			ensure!(Self::ensure_not_max_mogwais(sender.clone()), Error::<T>::MaxMogwaisInAccount);
+
+			// the NFT might have changed hands while the mogwai was locked
+			let mut mogwai =
+				T::NftHandler::recover_from_nft(sender.clone(), collection_id, mogwai_id)?;
+			mogwai.owner = sender.clone();
+
+			Self::mint(&sender, mogwai_id, mogwai)?;
+			LockedMogwais::<T>::remove(mogwai_id);
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiUnlocked(sender, mogwai_id));
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Mint a freshly bred mogwai egg to the given account.
+	fn create_egg(owner: &T::AccountId) -> Result<MogwaiIdOf<T>, DispatchError> {
+		// ensure that we have enough space
+		ensure!(Self::ensure_not_max_mogwais(owner.clone()), Error::<T>::MaxMogwaisInAccount);
+
+		let random_hash_1 = Self::generate_random_hash(b"create_mogwai", owner.clone());
+		let random_hash_2 = Self::generate_random_hash(b"extend_mogwai", owner.clone());
+
+		let (rarity, next_gen, max_rarity) = Generation::next_gen(
+			MogwaiGeneration::First,
+			RarityType::Common,
+			MogwaiGeneration::First,
+			RarityType::Common,
+			Kinship::Unrelated.inbreeding_penalty(),
+			random_hash_1.as_ref(),
+		);
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let breed_type: BreedType = Self::calculate_breedtype(block_number);
+
+		let dx = unsafe { &*(&random_hash_1.as_ref()[0..32] as *const [u8] as *const [u8; 32]) };
+		let dy = unsafe { &*(&random_hash_2.as_ref()[0..32] as *const [u8] as *const [u8; 32]) };
+
+		let final_dna = Breeding::pairing(breed_type, dx, dy);
+
+		let new_mogwai = MogwaiStruct {
+			id: random_hash_1,
+			dna: final_dna,
+			genesis: block_number,
+			intrinsic: Zero::zero(),
+			generation: next_gen,
+			rarity: RarityType::from(((max_rarity as u8) << 4) + rarity as u8),
+			phase: PhaseType::Bred,
+			owner: owner.clone(),
+		};
+
+		Self::mint(owner, random_hash_1, new_mogwai)?;
+		MogwaiPedigrees::<T>::insert(random_hash_1, Pedigree::founder(random_hash_1));
+
+		// Emit an event.
+		Self::deposit_event(Event::MogwaiCreated(owner.clone(), random_hash_1));
+
+		Ok(random_hash_1)
+	}
+
+	/// The pedigree of a mogwai, mogwais minted before pedigrees were tracked being founders.
+	pub(crate) fn pedigree_of(mogwai_id: MogwaiIdOf<T>) -> Pedigree<MogwaiIdOf<T>> {
+		Self::mogwai_pedigrees(mogwai_id).unwrap_or_else(|| Pedigree::founder(mogwai_id))
+	}
+
+	/// The family tree of a mogwai, breadth first and up to `depth` generations of ancestors.
+	pub fn family_tree(mogwai_id: MogwaiIdOf<T>, depth: u8) -> Vec<FamilyMember<MogwaiIdOf<T>>> {
+		if !Mogwais::<T>::contains_key(mogwai_id) && !MogwaiPedigrees::<T>::contains_key(mogwai_id)
+		{
+			return Vec::new()
+		}
+
+		let depth = depth.min(MAX_FAMILY_TREE_DEPTH);
+		let mut tree = Vec::new();
+		let mut members = sp_std::vec![mogwai_id];
+		for member_depth in 0..=depth {
+			let mut parents = Vec::new();
+			for mogwai_id in members {
+				let pedigree = Self::pedigree_of(mogwai_id);
+				if member_depth < depth {
+					if let Some((parent_1, parent_2)) = pedigree.parents {
+						parents.extend([parent_1, parent_2]);
+					}
+				}
+				tree.push(FamilyMember { mogwai_id, depth: member_depth, pedigree });
+			}
+			members = parents;
+		}
+		tree
+	}
+
+	/// Upgrade an account configuration parameter by one step, if it can still be upgraded.
+	fn upgrade_config(account: &T::AccountId, parameter: GameConfigType) {
+		let mut game_config = AccountConfig::<T>::get(account).unwrap_or_default();
+		if game_config.upgrade(parameter, None).is_some() {
+			AccountConfig::<T>::insert(account, game_config);
+
+			// Emit an event.
+			Self::deposit_event(Event::AccountConfigChanged(account.clone(), game_config));
+		}
+	}
+
 	fn encode_and_update_nonce() -> Vec<u8> {
 		Nonce::<T>::mutate(|nonce| {
 			*nonce = nonce.wrapping_add(1);
@@ -790,18 +1607,274 @@ impl<T: Config> Pallet<T> {
 	}
 
 	///
-	pub(crate) fn config_value(who: T::AccountId, index: u8) -> u32 {
-		GameConfig::config_value(
-			index,
-			AccountConfig::<T>::get(&who)
-				.map(|config| config[index as usize])
-				.unwrap_or_default(),
-		)
+	pub(crate) fn config_value(who: T::AccountId, parameter: GameConfigType) -> u32 {
+		AccountConfig::<T>::get(&who).unwrap_or_default().value(parameter)
 	}
 
 	///
 	fn ensure_not_max_mogwais(who: T::AccountId) -> bool {
-		Self::owned_mogwais_count(&who) < Self::config_value(who, 1) as u64
+		Self::owned_mogwais_count(&who) <
+			Self::config_value(who, GameConfigType::MaxMogwaisInAccount) as u64
+	}
+
+	/// Both mogwais must exist, be hatched and not be on sale to battle.
+	fn ensure_battle(
+		mogwai_id: MogwaiIdOf<T>,
+		opponent_mogwai_id: MogwaiIdOf<T>,
+	) -> Result<(MogwaiOf<T>, MogwaiOf<T>), DispatchError> {
+		ensure!(mogwai_id != opponent_mogwai_id, Error::<T>::MogwaiSame);
+
+		let mogwai = Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+		let opponent_mogwai =
+			Self::mogwai(opponent_mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+
+		ensure!(mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
+		ensure!(opponent_mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
+
+		ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
+		ensure!(!MogwaiPrices::<T>::contains_key(opponent_mogwai_id), Error::<T>::MogwaiIsOnSale);
+
+		Ok((mogwai, opponent_mogwai))
+	}
+
+	/// Fight a battle between two mogwais, recording the result and updating the Elo ratings
+	/// of both mogwais and their owners.
+	fn battle(mogwai: &MogwaiOf<T>, opponent: &MogwaiOf<T>, seed: T::Hash) -> BattleSide {
+		let outcome = Battle::resolve(
+			CombatStats::new(&mogwai.dna, mogwai.rarity, mogwai.generation, mogwai.phase),
+			CombatStats::new(&opponent.dna, opponent.rarity, opponent.generation, opponent.phase),
+			seed.as_ref(),
+		);
+
+		let (winner, loser) = match outcome.winner {
+			BattleSide::Challenger => (mogwai, opponent),
+			BattleSide::Opponent => (opponent, mogwai),
+		};
+		BattleRecords::<T>::mutate(winner.id, |record| record.wins = record.wins.saturating_add(1));
+		BattleRecords::<T>::mutate(loser.id, |record| {
+			record.losses = record.losses.saturating_add(1)
+		});
+
+		let (winner_rating, loser_rating) =
+			Elo::rate(Self::mogwai_rating(winner.id), Self::mogwai_rating(loser.id));
+		MogwaiRatings::<T>::insert(winner.id, winner_rating);
+		MogwaiRatings::<T>::insert(loser.id, loser_rating);
+
+		if winner.owner != loser.owner {
+			let (winner_rating, loser_rating) =
+				Elo::rate(Self::account_rating(&winner.owner), Self::account_rating(&loser.owner));
+			AccountRatings::<T>::insert(&winner.owner, winner_rating);
+			AccountRatings::<T>::insert(&loser.owner, loser_rating);
+		}
+
+		Self::deposit_event(Event::MogwaiBattled(mogwai.id, opponent.id, winner.id, outcome.log));
+
+		Self::gain_experience(winner.id, ExperienceSource::BattleWon);
+		Self::gain_experience(loser.id, ExperienceSource::BattleLost);
+
+		outcome.winner
+	}
+
+	/// Award experience to a mogwai, promoting it through as many phases as its experience
+	/// allows. Its intrinsic value grows with each promotion, following the decreasing
+	/// `Pricing::intrinsic_return` of the phases.
+	fn gain_experience(mogwai_id: MogwaiIdOf<T>, source: ExperienceSource) {
+		let experience = MogwaiExperience::<T>::mutate(mogwai_id, |experience| {
+			*experience = experience.saturating_add(source.points());
+			*experience
+		});
+
+		let Some(mut mogwai) = Self::mogwai(mogwai_id) else { return };
+		let mut promoted = false;
+		while let Some(threshold) = mogwai.phase.promotion_threshold() {
+			if experience < threshold {
+				break
+			}
+
+			let next_phase = mogwai.phase.next();
+			let current_return: BalanceOf<T> =
+				Pricing::intrinsic_return(mogwai.phase).saturated_into();
+			let next_return: BalanceOf<T> = Pricing::intrinsic_return(next_phase).saturated_into();
+			mogwai.intrinsic = mogwai.intrinsic.saturating_mul(current_return) / next_return;
+			mogwai.phase = next_phase;
+			promoted = true;
+
+			Self::deposit_event(Event::MogwaiPromoted(mogwai_id, next_phase));
+		}
+
+		if promoted {
+			Mogwais::<T>::insert(mogwai_id, mogwai);
+		}
+	}
+
+	/// Pay the owner of a listed stud for breeding with it, consuming one of its uses.
+	fn use_stud(
+		breeder: &T::AccountId,
+		mogwai_id: MogwaiIdOf<T>,
+		owner: &T::AccountId,
+	) -> DispatchResult {
+		let mut listing = Self::stud_listings(mogwai_id).ok_or(Error::<T>::StudNotListed)?;
+
+		T::Currency::transfer(breeder, owner, listing.price, ExistenceRequirement::KeepAlive)?;
+
+		listing.uses_left = listing.uses_left.saturating_sub(1);
+		if listing.uses_left == 0 {
+			StudListings::<T>::remove(mogwai_id);
+		} else {
+			StudListings::<T>::insert(mogwai_id, listing);
+		}
+
+		// Emit an event.
+		Self::deposit_event(Event::StudUsed(breeder.clone(), mogwai_id, listing.price));
+
+		Ok(())
+	}
+
+	/// Hatch the eggs requested to hatch at block `n`, baking them with the hash of the block
+	/// preceding it. Returns the number of eggs hatched.
+	pub(crate) fn resolve_hatches(n: BlockNumberFor<T>) -> u32 {
+		let queue = HatchQueue::<T>::take(n);
+		if queue.is_empty() {
+			return 0
+		}
+
+		let block_hash = <frame_system::Pallet<T>>::block_hash(n.saturating_sub(One::one()));
+		let mut hatches = 0;
+
+		for mogwai_id in queue {
+			// requests are dropped along with their mogwai
+			if HatchRequests::<T>::get(mogwai_id) != Some(n) {
+				continue
+			}
+			HatchRequests::<T>::remove(mogwai_id);
+			let Some(mut mogwai) = Self::mogwai(mogwai_id) else { continue };
+
+			let (dna, rarity) = Self::segment_and_bake(mogwai.clone(), block_hash);
+
+			mogwai.phase = PhaseType::Hatched;
+			mogwai.rarity = rarity;
+			mogwai.dna = dna;
+
+			let owner = mogwai.owner.clone();
+			Mogwais::<T>::insert(mogwai_id, mogwai);
+
+			Self::update_achievement_for(&owner, AccountAchievement::EggHatcher, 1);
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiHatched(owner, mogwai_id));
+
+			hatches += 1;
+		}
+
+		hatches
+	}
+
+	/// Pair queued mogwais of the pending brackets by rating proximity and fight their matches,
+	/// up to `limit` matches and `MAX_BRACKETS_MATCHED_PER_BLOCK` brackets. Returns the number
+	/// of matches played and of brackets scanned.
+	pub(crate) fn match_mogwais(limit: u32) -> (u32, u32) {
+		let mut matches = 0;
+		let mut scanned = 0;
+		let brackets = PendingBrackets::<T>::iter_keys()
+			.take(MAX_BRACKETS_MATCHED_PER_BLOCK as usize)
+			.collect::<Vec<_>>();
+
+		for bracket in brackets {
+			if matches >= limit {
+				break
+			}
+			scanned += 1;
+
+			let mut queue = MatchQueue::<T>::get(bracket).into_inner();
+			queue.sort_by_key(|entry| Self::mogwai_rating(entry.mogwai_id));
+
+			let mut remaining = Vec::with_capacity(queue.len());
+			let mut entries = queue.into_iter().peekable();
+			while let Some(entry) = entries.next() {
+				let pairable = entries
+					.peek()
+					.map_or(false, |next| next.owner != entry.owner && next.wager == entry.wager);
+				if matches < limit && pairable {
+					let opponent = entries.next().expect("peeked above; qed");
+					Self::play_match(entry, opponent);
+					matches += 1;
+				} else {
+					remaining.push(entry);
+				}
+			}
+
+			if remaining.is_empty() {
+				MatchQueue::<T>::remove(bracket);
+			} else {
+				// Only entries of the previous queue remain, so they fit within its bound.
+				MatchQueue::<T>::insert(bracket, MatchQueueOf::<T>::truncate_from(remaining));
+			}
+
+			// A bracket cut short by the limit may still hold pairs, so it is scanned again.
+			if matches < limit {
+				PendingBrackets::<T>::remove(bracket);
+			}
+		}
+
+		(matches, scanned)
+	}
+
+	fn play_match(entry: MatchQueueEntryOf<T>, opponent_entry: MatchQueueEntryOf<T>) {
+		QueuedMogwais::<T>::remove(entry.mogwai_id);
+		QueuedMogwais::<T>::remove(opponent_entry.mogwai_id);
+
+		let (Some(mogwai), Some(opponent)) =
+			(Self::mogwai(entry.mogwai_id), Self::mogwai(opponent_entry.mogwai_id))
+		else {
+			Self::release_wager(&entry);
+			Self::release_wager(&opponent_entry);
+			return
+		};
+
+		Self::deposit_event(Event::MogwaisMatched(entry.mogwai_id, opponent_entry.mogwai_id));
+		let seed = Self::generate_random_hash(b"match_mogwai", entry.owner.clone());
+		let (winner, loser) = match Self::battle(&mogwai, &opponent, seed) {
+			BattleSide::Challenger => (entry, opponent_entry),
+			BattleSide::Opponent => (opponent_entry, entry),
+		};
+
+		// The winner takes the wager of the loser, both having wagered the same amount.
+		Self::release_wager(&winner);
+		if let Some(amount) = loser.wager {
+			let _ = T::Currency::repatriate_reserved(
+				&loser.owner,
+				&winner.owner,
+				amount,
+				BalanceStatus::Free,
+			);
+		}
+	}
+
+	fn release_wager(entry: &MatchQueueEntryOf<T>) {
+		if let Some(amount) = entry.wager {
+			T::Currency::unreserve(&entry.owner, amount);
+		}
+	}
+
+	/// Remove a mogwai from the matchmaking queue, if queued, releasing its wager.
+	fn dequeue(mogwai_id: MogwaiIdOf<T>) {
+		if let Some(bracket) = QueuedMogwais::<T>::take(mogwai_id) {
+			MatchQueue::<T>::mutate_exists(bracket, |maybe_queue| {
+				if let Some(queue) = maybe_queue {
+					if let Some(index) = queue.iter().position(|entry| entry.mogwai_id == mogwai_id)
+					{
+						Self::release_wager(&queue.remove(index));
+					}
+					// Removing an entry can bring two pairable entries next to each other.
+					if queue.is_empty() {
+						*maybe_queue = None;
+						PendingBrackets::<T>::remove(bracket);
+					} else {
+						PendingBrackets::<T>::insert(bracket, ());
+					}
+				}
+			});
+		}
 	}
 
 	/// Add mogwai to storage
@@ -828,22 +1901,38 @@ impl<T: Config> Pallet<T> {
 
 	///
 	fn remove(from: T::AccountId, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+		Self::withdraw(&from, mogwai_id)?;
+
+		BreedingCooldowns::<T>::remove(mogwai_id);
+		MogwaiExperience::<T>::remove(mogwai_id);
+
+		Ok(())
+	}
+
+	/// Take a mogwai out of the game along with everything its owner has set up for it, keeping
+	/// the progress that belongs to the mogwai itself.
+	fn withdraw(from: &T::AccountId, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
 		ensure!(Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiDoesntExists);
 
 		Mogwais::<T>::remove(mogwai_id);
 
-		Owners::<T>::mutate(&from, |id_set| {
+		Owners::<T>::mutate(from, |id_set| {
 			id_set.remove(&mogwai_id);
 		});
 
 		if MogwaiPrices::<T>::contains_key(mogwai_id) {
 			MogwaiPrices::<T>::remove(mogwai_id);
 		}
+		Challenges::<T>::remove(mogwai_id);
+		MogwaiApprovals::<T>::remove(mogwai_id);
+		StudListings::<T>::remove(mogwai_id);
+		HatchRequests::<T>::remove(mogwai_id);
+		Self::dequeue(mogwai_id);
 
 		AllMogwaisCount::<T>::mutate(|count| {
 			*count = count.saturating_sub(1);
 		});
-		OwnedMogwaisCount::<T>::mutate(&from, |count| {
+		OwnedMogwaisCount::<T>::mutate(from, |count| {
 			*count = count.saturating_sub(1);
 		});
 
@@ -857,6 +1946,9 @@ impl<T: Config> Pallet<T> {
 		to: T::AccountId,
 		mogwai_id: MogwaiIdOf<T>,
 	) -> DispatchResult {
+		// queued wagers are reserved from the previous owner
+		Self::dequeue(mogwai_id);
+
 		// Update the OwnedMogwaisCount for `from` and `to`
 		OwnedMogwaisCount::<T>::mutate(&from, |count| {
 			*count = count.saturating_sub(1);
@@ -881,6 +1973,11 @@ impl<T: Config> Pallet<T> {
 			}
 		})?;
 
+		// challenges, approvals and stud listings are issued on behalf of the previous owner
+		Challenges::<T>::remove(mogwai_id);
+		MogwaiApprovals::<T>::remove(mogwai_id);
+		StudListings::<T>::remove(mogwai_id);
+
 		Ok(())
 	}
 
@@ -915,19 +2012,26 @@ impl<T: Config> Pallet<T> {
 		account: &T::AccountId,
 		achievement: AccountAchievement,
 		update_amount: u16,
-	) -> bool {
-		AccountAchievements::<T>::mutate(account, achievement, |maybe_value| match maybe_value {
-			None => {
-				*maybe_value =
-					Some(AchievementState::new(achievement.target_for()).update(update_amount));
-				false
-			},
-			Some(AchievementState::Completed) => false,
-			Some(value) => {
-				let updated_value = value.update(update_amount);
-				*maybe_value = Some(updated_value);
-				updated_value == AchievementState::Completed
-			},
-		})
+	) {
+		let completed =
+			AccountAchievements::<T>::mutate(
+				account,
+				achievement,
+				|maybe_value| match maybe_value {
+					Some(AchievementState::Completed) => false,
+					_ => {
+						let updated_value = maybe_value
+							.unwrap_or_else(|| AchievementState::new(achievement.target_for()))
+							.update(update_amount);
+						*maybe_value = Some(updated_value);
+						updated_value == AchievementState::Completed
+					},
+				},
+			);
+
+		if completed {
+			// Emit an event.
+			Self::deposit_event(Event::AchievementCompleted(account.clone(), achievement));
+		}
 	}
 }
@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+pub mod v2;
+
 use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
 
 // The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
-#[allow(dead_code)]
 const LOG_TARGET: &str = "runtime::ajuna-battle-mogs";
@@ -0,0 +1,80 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The account configuration up to v1, one upgrade level per `GameConfigType` index.
+pub type GameConfigV1 = [u8; 10];
+
+fn migrate_to_v2(old: GameConfigV1) -> GameConfig {
+	let level = |parameter: GameConfigType| {
+		let rule = parameter.rule();
+		old[parameter as usize].clamp(rule.min, rule.max)
+	};
+
+	GameConfig {
+		activated: level(GameConfigType::Activated),
+		max_mogwais: level(GameConfigType::MaxMogwaisInAccount),
+		max_stash_size: level(GameConfigType::MaxStashSize),
+		account_naming: level(GameConfigType::AccountNaming),
+	}
+}
+
+pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current_version = Pallet::<T>::current_storage_version();
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		if onchain_version == 1 && current_version == 2 {
+			let mut translated = 0_u64;
+			AccountConfig::<T>::translate::<GameConfigV1, _>(|_, old_config| {
+				translated.saturating_inc();
+				Some(migrate_to_v2(old_config))
+			});
+			log::info!(target: LOG_TARGET, "Updated {} AccountConfig from v1 to v2", translated);
+
+			current_version.put::<Pallet<T>>();
+			log::info!(target: LOG_TARGET, "Upgraded storage to version {:?}", current_version);
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		} else {
+			log::info!(
+				target: LOG_TARGET,
+				"Migration for v2 did not execute."
+			);
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		Ok((AccountConfig::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		let current_version = Pallet::<T>::current_storage_version();
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		ensure!(current_version == 2 && onchain_version == 2, "must upgrade to v2");
+
+		let prev_count = u32::decode(&mut state.as_slice())
+			.map_err(|_| sp_runtime::DispatchError::Other("failed to decode state"))?;
+		let post_count = AccountConfig::<T>::iter_values().count() as u32;
+		ensure!(prev_count == post_count, "the account config count must stay the same");
+
+		Ok(())
+	}
+}
@@ -17,18 +17,24 @@
 use crate::{self as pallet_battle_mogs};
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64, OnFinalize, OnInitialize},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, OnFinalize, OnInitialize},
+	PalletId,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::TestSignature,
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 	BuildStorage,
 };
 
 pub type MockBlock = frame_system::mocking::MockBlock<Test>;
-pub type MockAccountId = u32;
+pub type MockSignature = TestSignature;
+pub type MockAccountPublic = <MockSignature as Verify>::Signer;
+pub type MockAccountId = <MockAccountPublic as IdentifyAccount>::AccountId;
 pub type MockBalance = u64;
 pub type MockNonce = u64;
+pub type MockCollectionId = u32;
 pub type MockMogwaiId = <Test as frame_system::Config>::Hash;
 
 pub const ALICE: MockAccountId = 1;
@@ -41,6 +47,8 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Randomness: pallet_insecure_randomness_collective_flip,
 		Balances: pallet_balances,
+		Nft: pallet_nfts,
+		NftTransfer: pallet_ajuna_nft_transfer,
 		BattleMogs: pallet_battle_mogs,
 	}
 );
@@ -93,10 +101,101 @@ impl pallet_balances::Config for Test {
 
 impl pallet_insecure_randomness_collective_flip::Config for Test {}
 
+parameter_types! {
+	pub const CollectionDeposit: MockBalance = 1;
+	pub const ItemDeposit: MockBalance = 1;
+	pub const StringLimit: u32 = 128;
+	pub const KeyLimit: u32 = 32;
+	pub const ValueLimit: u32 = 256;
+	pub const MetadataDepositBase: MockBalance = 1;
+	pub const AttributeDepositBase: MockBalance = 1;
+	pub const DepositPerByte: MockBalance = 1;
+	pub const ApprovalsLimit: u32 = 1;
+	pub const ItemAttributesApprovalsLimit: u32 = 10;
+	pub const MaxTips: u32 = 1;
+	pub const MaxDeadlineDuration: u32 = 1;
+	pub const MaxAttributesPerCall: u32 = 10;
+	pub ConfigFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct Helper;
+#[cfg(feature = "runtime-benchmarks")]
+impl<CollectionId: From<u16>, ItemId: From<[u8; 32]>>
+	pallet_nfts::BenchmarkHelper<CollectionId, ItemId> for Helper
+{
+	fn collection(i: u16) -> CollectionId {
+		i.into()
+	}
+	fn item(i: u16) -> ItemId {
+		let mut id = [0_u8; 32];
+		let bytes = i.to_be_bytes();
+		id[0] = bytes[0];
+		id[1] = bytes[1];
+		id.into()
+	}
+}
+
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = MockCollectionId;
+	type ItemId = H256;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<MockAccountId>;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<MockAccountId>>;
+	type Locker = NftTransfer;
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type StringLimit = StringLimit;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type ApprovalsLimit = ApprovalsLimit;
+	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
+	type MaxTips = MaxTips;
+	type MaxDeadlineDuration = MaxDeadlineDuration;
+	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type Features = ConfigFeatures;
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockAccountPublic;
+	pallet_nfts::runtime_benchmarks_enabled! {
+		type Helper = Helper;
+	}
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const NftTransferPalletId: PalletId = PalletId(*b"aj/nfttr");
+}
+
+impl pallet_ajuna_nft_transfer::Config for Test {
+	type PalletId = NftTransferPalletId;
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = MockCollectionId;
+	type ItemId = H256;
+	type ItemConfig = pallet_nfts::ItemConfig;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type NftHelper = Nft;
+}
+
+parameter_types! {
+	pub const BreedingCooldown: u64 = 10;
+	pub const HatchDelay: u64 = 2;
+}
+
 impl pallet_battle_mogs::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type Randomness = Randomness;
+	type MaxMatchesPerBlock = ConstU32<4>;
+	type BreedingCooldown = BreedingCooldown;
+	type HatchDelay = HatchDelay;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type NftHandler = NftTransfer;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,74 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::types::{MogwaiGeneration, MogwaiStruct, PhaseType, RarityType};
+use frame_support::{traits::Get, BoundedVec};
+use pallet_ajuna_nft_transfer::traits::{NFTAttribute, NftConvertible};
+use parity_scale_codec::Codec;
+use scale_info::prelude::format;
+use sp_std::{fmt::Debug, prelude::*};
+
+impl<KL, VL, Hash, BlockNumber, Balance, AccountId> NftConvertible<KL, VL>
+	for MogwaiStruct<Hash, BlockNumber, Balance, MogwaiGeneration, RarityType, PhaseType, AccountId>
+where
+	KL: Get<u32>,
+	VL: Get<u32>,
+	Hash: Codec,
+	BlockNumber: Codec,
+	Balance: Codec + Debug,
+	AccountId: Codec,
+{
+	const ITEM_CODE: &'static [u8] = b"MOGWAI";
+	const IPFS_URL_CODE: &'static [u8] = b"IPFS_URL";
+
+	fn get_attribute_codes() -> Vec<NFTAttribute<KL>> {
+		vec![
+			BoundedVec::try_from(b"DNA".to_vec()).unwrap(),
+			BoundedVec::try_from(b"RARITY".to_vec()).unwrap(),
+			BoundedVec::try_from(b"GENERATION".to_vec()).unwrap(),
+			BoundedVec::try_from(b"PHASE".to_vec()).unwrap(),
+			BoundedVec::try_from(b"INTRINSIC".to_vec()).unwrap(),
+		]
+	}
+
+	fn get_encoded_attributes(&self) -> Vec<(NFTAttribute<KL>, NFTAttribute<VL>)> {
+		vec![
+			(
+				BoundedVec::try_from(b"DNA".to_vec()).unwrap(),
+				BoundedVec::try_from(format!("0x{}", hex::encode(self.dna.concat())).into_bytes())
+					.unwrap(),
+			),
+			(
+				BoundedVec::try_from(b"RARITY".to_vec()).unwrap(),
+				BoundedVec::try_from(format!("{:?}", self.rarity).to_uppercase().into_bytes())
+					.unwrap(),
+			),
+			(
+				BoundedVec::try_from(b"GENERATION".to_vec()).unwrap(),
+				BoundedVec::try_from(format!("{}", self.generation as u8).into_bytes()).unwrap(),
+			),
+			(
+				BoundedVec::try_from(b"PHASE".to_vec()).unwrap(),
+				BoundedVec::try_from(format!("{:?}", self.phase).to_uppercase().into_bytes())
+					.unwrap(),
+			),
+			(
+				BoundedVec::try_from(b"INTRINSIC".to_vec()).unwrap(),
+				BoundedVec::try_from(format!("{:?}", self.intrinsic).into_bytes()).unwrap(),
+			),
+		]
+	}
+}
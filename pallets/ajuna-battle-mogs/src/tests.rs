@@ -15,10 +15,11 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	mock, mock::*, AccountAchievement, AchievementState, Error, Event, GameEventType, MogwaiPrices,
-	Mogwais, PhaseType, RarityType,
+	mock, mock::*, AccountAchievement, AchievementState, Error, Event, GameConfig, GameConfigType,
+	GameEventType, MogwaiPrices, Mogwais, PhaseType, RarityType,
 };
 use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
 
 #[cfg(test)]
 mod update_config {
@@ -29,17 +30,38 @@ mod update_config {
 		ExtBuilder.build().execute_with(|| {
 			assert_eq!(BattleMogs::account_config(ALICE), None);
 
-			assert_ok!(BattleMogs::update_config(RuntimeOrigin::signed(ALICE), 1, Some(1)));
+			assert_ok!(BattleMogs::update_config(
+				RuntimeOrigin::signed(ALICE),
+				GameConfigType::MaxMogwaisInAccount,
+				Some(1)
+			));
 			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
-				crate::Event::AccountConfigChanged(ALICE, [0, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+				crate::Event::AccountConfigChanged(
+					ALICE,
+					GameConfig { max_mogwais: 1, ..Default::default() },
+				),
+			));
+			assert_ok!(BattleMogs::update_config(
+				RuntimeOrigin::signed(ALICE),
+				GameConfigType::MaxMogwaisInAccount,
+				Some(2)
 			));
-			assert_ok!(BattleMogs::update_config(RuntimeOrigin::signed(ALICE), 1, Some(2)));
 			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
-				crate::Event::AccountConfigChanged(ALICE, [0, 2, 0, 0, 0, 0, 0, 0, 0, 0]),
+				crate::Event::AccountConfigChanged(
+					ALICE,
+					GameConfig { max_mogwais: 2, ..Default::default() },
+				),
+			));
+			assert_ok!(BattleMogs::update_config(
+				RuntimeOrigin::signed(ALICE),
+				GameConfigType::MaxMogwaisInAccount,
+				Some(3)
 			));
-			assert_ok!(BattleMogs::update_config(RuntimeOrigin::signed(ALICE), 1, Some(3)));
 			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
-				crate::Event::AccountConfigChanged(ALICE, [0, 3, 0, 0, 0, 0, 0, 0, 0, 0]),
+				crate::Event::AccountConfigChanged(
+					ALICE,
+					GameConfig { max_mogwais: 3, ..Default::default() },
+				),
 			));
 		});
 	}
@@ -50,21 +72,82 @@ mod update_config {
 			assert_eq!(BattleMogs::account_config(ALICE), None);
 
 			assert_noop!(
-				BattleMogs::update_config(RuntimeOrigin::signed(ALICE), 1, Some(6)),
+				BattleMogs::update_config(
+					RuntimeOrigin::signed(ALICE),
+					GameConfigType::MaxMogwaisInAccount,
+					Some(6)
+				),
 				Error::<Test>::ConfigUpdateInvalid
 			);
 
 			assert_noop!(
-				BattleMogs::update_config(RuntimeOrigin::signed(ALICE), 3, Some(1)),
+				BattleMogs::update_config(
+					RuntimeOrigin::signed(ALICE),
+					GameConfigType::Activated,
+					Some(1)
+				),
 				Error::<Test>::ConfigUpdateInvalid
 			);
 
 			assert_noop!(
-				BattleMogs::update_config(RuntimeOrigin::signed(ALICE), 6, Some(4)),
+				BattleMogs::update_config(
+					RuntimeOrigin::signed(ALICE),
+					GameConfigType::AccountNaming,
+					Some(2)
+				),
 				Error::<Test>::ConfigUpdateInvalid
 			);
 		});
 	}
+
+	#[test]
+	fn stash_size_limits_mogwais_on_sale() {
+		ExtBuilder.build().execute_with(|| {
+			let stash_size = BattleMogs::config_value(BOB, GameConfigType::MaxStashSize);
+			assert_eq!(stash_size, 2);
+
+			let mogwai_ids = (0..=stash_size).map(|_| create_mogwai(BOB)).collect::<Vec<_>>();
+			for mogwai_id in &mogwai_ids[..stash_size as usize] {
+				assert_ok!(BattleMogs::set_price(RuntimeOrigin::signed(BOB), *mogwai_id, 1_000));
+			}
+			assert_ok!(BattleMogs::set_price(RuntimeOrigin::signed(BOB), mogwai_ids[0], 2_000));
+			assert_noop!(
+				BattleMogs::set_price(RuntimeOrigin::signed(BOB), mogwai_ids[2], 1_000),
+				Error::<Test>::MaxStashSizeReached
+			);
+
+			assert_ok!(BattleMogs::update_config(
+				RuntimeOrigin::signed(BOB),
+				GameConfigType::MaxStashSize,
+				None
+			));
+			assert_eq!(BattleMogs::config_value(BOB, GameConfigType::MaxStashSize), 4);
+			assert_ok!(BattleMogs::set_price(RuntimeOrigin::signed(BOB), mogwai_ids[2], 1_000));
+		});
+	}
+
+	#[test]
+	fn account_naming_must_be_unlocked() {
+		ExtBuilder.build().execute_with(|| {
+			let name: crate::AccountNameOf = b"Mogwai Tamer".to_vec().try_into().unwrap();
+
+			assert_noop!(
+				BattleMogs::set_account_name(RuntimeOrigin::signed(BOB), name.clone()),
+				Error::<Test>::AccountNamingLocked
+			);
+
+			assert_ok!(BattleMogs::update_config(
+				RuntimeOrigin::signed(BOB),
+				GameConfigType::AccountNaming,
+				Some(1)
+			));
+			assert_ok!(BattleMogs::set_account_name(RuntimeOrigin::signed(BOB), name.clone()));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::AccountNameSet(BOB, name.clone()),
+			));
+			assert_eq!(BattleMogs::account_name(BOB), Some(name));
+		});
+	}
 }
 
 fn create_mogwai(owner: MockAccountId) -> MockMogwaiId {
@@ -78,6 +161,14 @@ fn create_mogwai(owner: MockAccountId) -> MockMogwaiId {
 	panic!("Expected MogwaiCreated event");
 }
 
+fn create_hatched_mogwai(owner: MockAccountId) -> MockMogwaiId {
+	let mogwai_id = create_mogwai(owner);
+	Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+		maybe_mogwai.as_mut().unwrap().phase = PhaseType::Hatched;
+	});
+	mogwai_id
+}
+
 fn put_mogwai_on_sale(owner: MockAccountId, mogwai_id: MockMogwaiId, price: u64) {
 	BattleMogs::set_price(RuntimeOrigin::signed(owner), mogwai_id, price)
 		.expect("Failed setting mogwai price!");
@@ -192,7 +283,7 @@ mod create_mogwai {
 	fn create_mogwai_cannot_go_over_limit() {
 		ExtBuilder.build().execute_with(|| {
 			let account = ALICE;
-			let mogwai_limit = BattleMogs::config_value(ALICE, 1);
+			let mogwai_limit = BattleMogs::config_value(ALICE, GameConfigType::MaxMogwaisInAccount);
 
 			for _ in 0..mogwai_limit {
 				assert_ok!(BattleMogs::create_mogwai(RuntimeOrigin::signed(account)));
@@ -203,9 +294,14 @@ mod create_mogwai {
 				Error::<Test>::MaxMogwaisInAccount
 			);
 
-			assert_ok!(BattleMogs::update_config(RuntimeOrigin::signed(ALICE), 1, Some(1)));
+			assert_ok!(BattleMogs::update_config(
+				RuntimeOrigin::signed(ALICE),
+				GameConfigType::MaxMogwaisInAccount,
+				Some(1)
+			));
 
-			let new_mogwai_limit = BattleMogs::config_value(ALICE, 1);
+			let new_mogwai_limit =
+				BattleMogs::config_value(ALICE, GameConfigType::MaxMogwaisInAccount);
 
 			for _ in mogwai_limit..new_mogwai_limit {
 				assert_ok!(BattleMogs::create_mogwai(RuntimeOrigin::signed(account)));
@@ -301,7 +397,8 @@ mod transfer {
 		let founder = ALICE;
 		ExtBuilder.build().execute_with(|| {
 			let target = BOB;
-			let mogwai_limit = BattleMogs::config_value(target, 1);
+			let mogwai_limit =
+				BattleMogs::config_value(target, GameConfigType::MaxMogwaisInAccount);
 
 			for _ in 0..mogwai_limit {
 				let _ = create_mogwai(target);
@@ -334,6 +431,7 @@ mod transfer {
 #[cfg(test)]
 mod hatch_mogwai {
 	use super::*;
+	use crate::{HatchQueue, HatchQueueOf, MAX_HATCHES_PER_BLOCK, MAX_HATCH_BLOCKS_SEARCHED};
 
 	#[test]
 	fn hatch_mogwai_successfully() {
@@ -349,9 +447,27 @@ mod hatch_mogwai {
 			);
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			let hatch_at = System::block_number() + HatchDelay::get();
+			assert_eq!(BattleMogs::hatch_requests(mogwai_id), Some(hatch_at));
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::MogwaiHatchRequested(
+					account, mogwai_id, hatch_at
+				))
+			);
+
+			run_to_block(hatch_at - 1);
+			let mogwai = BattleMogs::mogwai(mogwai_id).expect("Should have found mogwai");
+			assert_eq!(mogwai.phase, PhaseType::Bred);
 
+			run_to_block(hatch_at);
 			let mogwai = BattleMogs::mogwai(mogwai_id).expect("Should have found mogwai");
 			assert_eq!(mogwai.phase, PhaseType::Hatched);
+			assert_eq!(BattleMogs::hatch_requests(mogwai_id), None);
+			assert!(BattleMogs::hatch_queue(hatch_at).is_empty());
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(Event::MogwaiHatched(
+				account, mogwai_id,
+			)));
 
 			assert_eq!(
 				BattleMogs::account_achievements(account, AccountAchievement::EggHatcher),
@@ -399,6 +515,144 @@ mod hatch_mogwai {
 			});
 		});
 	}
+
+	#[test]
+	fn hatch_mogwai_cannot_request_twice() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let mogwai_id = create_mogwai(account);
+
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+
+			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			assert_noop!(
+				BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id),
+				Error::<Test>::MogwaiHatchPending
+			);
+
+			run_to_block(System::block_number() + HatchDelay::get());
+			assert_noop!(
+				BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id),
+				Error::<Test>::MogwaiNoHatch
+			);
+		});
+	}
+
+	fn fill_hatch_queue(block_number: u64) {
+		let queue = vec![H256::zero(); MAX_HATCHES_PER_BLOCK as usize];
+		HatchQueue::<Test>::insert(block_number, HatchQueueOf::<Test>::truncate_from(queue));
+	}
+
+	#[test]
+	fn hatch_mogwai_moves_to_the_next_block_with_room() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let mogwai_id = create_mogwai(account);
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+
+			let earliest = System::block_number() + HatchDelay::get();
+			fill_hatch_queue(earliest);
+			fill_hatch_queue(earliest + 1);
+
+			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			let hatch_at = earliest + 2;
+			assert_eq!(BattleMogs::hatch_requests(mogwai_id), Some(hatch_at));
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::MogwaiHatchRequested(
+					account, mogwai_id, hatch_at
+				))
+			);
+
+			run_to_block(hatch_at - 1);
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().phase, PhaseType::Bred);
+
+			run_to_block(hatch_at);
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().phase, PhaseType::Hatched);
+		});
+	}
+
+	#[test]
+	fn hatch_mogwai_fails_when_no_block_has_room() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let mogwai_id = create_mogwai(account);
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+
+			let earliest = System::block_number() + HatchDelay::get();
+			for offset in 0..MAX_HATCH_BLOCKS_SEARCHED as u64 {
+				fill_hatch_queue(earliest + offset);
+			}
+
+			assert_noop!(
+				BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id),
+				Error::<Test>::HatchQueueFull
+			);
+		});
+	}
+
+	#[test]
+	fn resolve_hatches_skips_requests_for_other_blocks() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let mogwai_id = create_mogwai(account);
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+
+			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			let hatch_at = System::block_number() + HatchDelay::get();
+			let stale_at = hatch_at - 1;
+			HatchQueue::<Test>::mutate(stale_at, |queue| queue.try_push(mogwai_id).unwrap());
+
+			run_to_block(stale_at);
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().phase, PhaseType::Bred);
+			assert_eq!(BattleMogs::hatch_requests(mogwai_id), Some(hatch_at));
+
+			run_to_block(hatch_at);
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().phase, PhaseType::Hatched);
+			assert_eq!(BattleMogs::hatch_requests(mogwai_id), None);
+		});
+	}
+
+	#[test]
+	fn hatch_mogwai_outcome_depends_on_a_later_block() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let mogwai_id_1 = create_mogwai(account);
+			let mogwai_id_2 = create_mogwai(account);
+			let egg = BattleMogs::mogwai(mogwai_id_1).unwrap();
+			Mogwais::<Test>::mutate(mogwai_id_2, |maybe_mogwai| {
+				let mogwai = maybe_mogwai.as_mut().unwrap();
+				mogwai.dna = egg.dna;
+				mogwai.rarity = egg.rarity;
+			});
+
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+
+			for (mogwai_id, seed) in [(mogwai_id_1, 0x11), (mogwai_id_2, 0x22)] {
+				assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+				let hatch_at = System::block_number() + HatchDelay::get();
+				// the hash of the block before resolution is only known after the request
+				frame_system::BlockHash::<Test>::insert(hatch_at - 1, H256::repeat_byte(seed));
+				run_to_block(hatch_at);
+			}
+
+			let mogwai_1 = BattleMogs::mogwai(mogwai_id_1).unwrap();
+			let mogwai_2 = BattleMogs::mogwai(mogwai_id_2).unwrap();
+			assert_eq!(mogwai_1.phase, PhaseType::Hatched);
+			assert_eq!(mogwai_2.phase, PhaseType::Hatched);
+			assert_ne!(mogwai_1.dna, mogwai_2.dna);
+		});
+	}
 }
 
 #[cfg(test)]
@@ -416,6 +670,7 @@ mod sacrifice {
 			run_to_block(System::block_number() + time_till_hatch);
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(account), mogwai_id));
 
@@ -510,6 +765,7 @@ mod sacrifice {
 				run_to_block(System::block_number() + time_till_hatch);
 
 				assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+				run_to_block(System::block_number() + HatchDelay::get());
 
 				assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(account), mogwai_id));
 			});
@@ -533,6 +789,7 @@ mod sacrifice_into {
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_1));
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_2));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			// We need to up the rarity in order to be allowed to sacrifice
 			Mogwais::<Test>::mutate(mogwai_id_1, |maybe_mogwai| {
@@ -581,6 +838,7 @@ mod sacrifice_into {
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_1));
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_2));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			Mogwais::<Test>::mutate(mogwai_id_1, |maybe_mogwai| {
 				if let Some(ref mut mogwai) = maybe_mogwai {
@@ -697,6 +955,7 @@ mod sacrifice_into {
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_1));
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_2));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			Mogwais::<Test>::mutate(mogwai_id_1, |maybe_mogwai| {
 				if let Some(ref mut mogwai) = maybe_mogwai {
@@ -749,6 +1008,7 @@ mod sacrifice_into {
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_1));
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_2));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			// We need to up the rarity in order to be allowed to sacrifice
 			Mogwais::<Test>::mutate(mogwai_id_1, |maybe_mogwai| {
@@ -864,7 +1124,7 @@ mod buy_mogwai {
 		ExtBuilder.build().execute_with(|| {
 			let account = BOB;
 			let buyer = ALICE;
-			let mogwai_limit = BattleMogs::config_value(buyer, 1);
+			let mogwai_limit = BattleMogs::config_value(buyer, GameConfigType::MaxMogwaisInAccount);
 			let mogwai_id = create_mogwai(account);
 			let sell_price = 1;
 
@@ -902,6 +1162,7 @@ mod morph_mogwai {
 			run_to_block(System::block_number() + time_till_hatch);
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			assert_ok!(BattleMogs::morph_mogwai(RuntimeOrigin::signed(account), mogwai_id));
 
@@ -954,6 +1215,7 @@ mod morph_mogwai {
 			run_to_block(System::block_number() + time_till_hatch);
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			put_mogwai_on_sale(account, mogwai_id, 1000);
 
@@ -981,6 +1243,7 @@ mod morph_mogwai {
 			run_to_block(System::block_number() + time_till_hatch);
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			assert_ok!(BattleMogs::morph_mogwai(RuntimeOrigin::signed(account), mogwai_id));
 		});
@@ -1004,6 +1267,7 @@ mod breed_mogwai {
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_1));
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_2));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			assert_ok!(BattleMogs::breed_mogwai(
 				RuntimeOrigin::signed(account),
@@ -1059,7 +1323,8 @@ mod breed_mogwai {
 	fn breed_mogwai_not_allowed_if_account_reached_mogwai_limit() {
 		ExtBuilder.build().execute_with(|| {
 			let account = BOB;
-			let mogwai_limit = BattleMogs::config_value(account, 1);
+			let mogwai_limit =
+				BattleMogs::config_value(account, GameConfigType::MaxMogwaisInAccount);
 			let other = CHARLIE;
 			let mogwai_id_1 = create_mogwai(account);
 			let mogwai_id_2 = create_mogwai(other);
@@ -1088,6 +1353,7 @@ mod breed_mogwai {
 
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_1));
 			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(account), mogwai_id_2));
+			run_to_block(System::block_number() + HatchDelay::get());
 
 			Mogwais::<Test>::mutate(mogwai_id_1, |maybe_mogwai| {
 				if let Some(ref mut mogwai) = maybe_mogwai {
@@ -1109,3 +1375,1411 @@ mod breed_mogwai {
 		});
 	}
 }
+
+mod battle {
+	use super::*;
+	use crate::{BattleRecord, Challenges};
+
+	fn create_hatched_mogwais() -> (MockMogwaiId, MockMogwaiId) {
+		let mogwai_id_1 = create_mogwai(ALICE);
+		let mogwai_id_2 = create_mogwai(BOB);
+
+		let time_till_hatch = GameEventType::time_till(GameEventType::Hatch) as u64;
+		run_to_block(System::block_number() + time_till_hatch);
+
+		assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id_1));
+		assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(BOB), mogwai_id_2));
+		run_to_block(System::block_number() + HatchDelay::get());
+
+		(mogwai_id_1, mogwai_id_2)
+	}
+
+	#[test]
+	fn challenge_mogwai_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let (mogwai_id_1, mogwai_id_2) = create_hatched_mogwais();
+
+			assert_ok!(BattleMogs::challenge_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::MogwaiChallenged(ALICE, mogwai_id_1, mogwai_id_2),
+			));
+
+			let challenge = BattleMogs::challenges(mogwai_id_1).unwrap();
+			assert_eq!(challenge.challenger, ALICE);
+			assert_eq!(challenge.opponent_mogwai, mogwai_id_2);
+			assert_eq!(challenge.created_at, System::block_number());
+		});
+	}
+
+	#[test]
+	fn challenge_mogwai_rejects_invalid_battles() {
+		ExtBuilder.build().execute_with(|| {
+			let (mogwai_id_1, mogwai_id_2) = create_hatched_mogwais();
+			let unhatched_id = create_mogwai(BOB);
+			let own_mogwai_id = create_mogwai(ALICE);
+
+			assert_noop!(
+				BattleMogs::challenge_mogwai(
+					RuntimeOrigin::signed(ALICE),
+					mogwai_id_1,
+					mogwai_id_1
+				),
+				Error::<Test>::MogwaiSame
+			);
+			assert_noop!(
+				BattleMogs::challenge_mogwai(RuntimeOrigin::signed(BOB), mogwai_id_1, mogwai_id_2),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_noop!(
+				BattleMogs::challenge_mogwai(
+					RuntimeOrigin::signed(ALICE),
+					mogwai_id_1,
+					unhatched_id
+				),
+				Error::<Test>::MogwaiNoHatch
+			);
+			assert_noop!(
+				BattleMogs::challenge_mogwai(
+					RuntimeOrigin::signed(ALICE),
+					own_mogwai_id,
+					mogwai_id_1
+				),
+				Error::<Test>::MogwaiNoHatch
+			);
+
+			put_mogwai_on_sale(BOB, mogwai_id_2, 1_000);
+			assert_noop!(
+				BattleMogs::challenge_mogwai(
+					RuntimeOrigin::signed(ALICE),
+					mogwai_id_1,
+					mogwai_id_2
+				),
+				Error::<Test>::MogwaiIsOnSale
+			);
+		});
+	}
+
+	#[test]
+	fn challenge_mogwai_rejects_own_mogwais_and_duplicates() {
+		ExtBuilder.build().execute_with(|| {
+			let (mogwai_id_1, mogwai_id_2) = create_hatched_mogwais();
+			let mogwai_id_3 = create_mogwai(ALICE);
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id_3));
+			run_to_block(System::block_number() + HatchDelay::get());
+
+			assert_noop!(
+				BattleMogs::challenge_mogwai(
+					RuntimeOrigin::signed(ALICE),
+					mogwai_id_1,
+					mogwai_id_3
+				),
+				Error::<Test>::MogwaiBattleSameOwner
+			);
+
+			assert_ok!(BattleMogs::challenge_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+			assert_noop!(
+				BattleMogs::challenge_mogwai(
+					RuntimeOrigin::signed(ALICE),
+					mogwai_id_1,
+					mogwai_id_2
+				),
+				Error::<Test>::ChallengeAlreadyExists
+			);
+		});
+	}
+
+	#[test]
+	fn cancel_challenge_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let (mogwai_id_1, mogwai_id_2) = create_hatched_mogwais();
+			assert_ok!(BattleMogs::challenge_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+
+			assert_noop!(
+				BattleMogs::cancel_challenge(RuntimeOrigin::signed(BOB), mogwai_id_1),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_ok!(BattleMogs::cancel_challenge(RuntimeOrigin::signed(ALICE), mogwai_id_1));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::ChallengeCancelled(ALICE, mogwai_id_1),
+			));
+			assert_eq!(BattleMogs::challenges(mogwai_id_1), None);
+
+			assert_noop!(
+				BattleMogs::cancel_challenge(RuntimeOrigin::signed(ALICE), mogwai_id_1),
+				Error::<Test>::ChallengeNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn accept_challenge_fights_battle() {
+		ExtBuilder.build().execute_with(|| {
+			let (mogwai_id_1, mogwai_id_2) = create_hatched_mogwais();
+			assert_ok!(BattleMogs::challenge_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+
+			assert_ok!(BattleMogs::accept_challenge(RuntimeOrigin::signed(BOB), mogwai_id_1));
+			let (winner_id, log) = match last_event() {
+				mock::RuntimeEvent::BattleMogs(crate::Event::MogwaiBattled(
+					challenger_id,
+					opponent_id,
+					winner_id,
+					log,
+				)) => {
+					assert_eq!(challenger_id, mogwai_id_1);
+					assert_eq!(opponent_id, mogwai_id_2);
+					(winner_id, log)
+				},
+				_ => panic!("Expected MogwaiBattled event"),
+			};
+			assert!(!log.is_empty());
+
+			let loser_id = if winner_id == mogwai_id_1 { mogwai_id_2 } else { mogwai_id_1 };
+			assert_eq!(BattleMogs::battle_records(winner_id), BattleRecord { wins: 1, losses: 0 });
+			assert_eq!(BattleMogs::battle_records(loser_id), BattleRecord { wins: 0, losses: 1 });
+			assert!(!Challenges::<Test>::contains_key(mogwai_id_1));
+		});
+	}
+
+	#[test]
+	fn accept_challenge_only_by_opponent() {
+		ExtBuilder.build().execute_with(|| {
+			let (mogwai_id_1, mogwai_id_2) = create_hatched_mogwais();
+
+			assert_noop!(
+				BattleMogs::accept_challenge(RuntimeOrigin::signed(BOB), mogwai_id_1),
+				Error::<Test>::ChallengeNotFound
+			);
+
+			assert_ok!(BattleMogs::challenge_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+			assert_noop!(
+				BattleMogs::accept_challenge(RuntimeOrigin::signed(CHARLIE), mogwai_id_1),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_noop!(
+				BattleMogs::accept_challenge(RuntimeOrigin::signed(ALICE), mogwai_id_1),
+				Error::<Test>::MogwaiNotOwned
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_withdraws_challenge() {
+		ExtBuilder.build().execute_with(|| {
+			let (mogwai_id_1, mogwai_id_2) = create_hatched_mogwais();
+			assert_ok!(BattleMogs::challenge_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+
+			assert_ok!(BattleMogs::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, mogwai_id_1));
+			assert_noop!(
+				BattleMogs::accept_challenge(RuntimeOrigin::signed(BOB), mogwai_id_1),
+				Error::<Test>::ChallengeNotFound
+			);
+		});
+	}
+}
+
+mod matchmaking {
+	use super::*;
+	use crate::{
+		BattleRecord, MatchBracket, MatchQueue, MogwaiGeneration, MogwaiRatings, PendingBrackets,
+		QueuedMogwais, INITIAL_RATING, MAX_BRACKETS_MATCHED_PER_BLOCK,
+	};
+	use frame_support::traits::{Currency, ReservableCurrency};
+
+	const BRACKET: MatchBracket =
+		MatchBracket { generation: MogwaiGeneration::First, rarity: RarityType::Common };
+
+	fn create_ready_mogwai(owner: MockAccountId) -> MockMogwaiId {
+		let mogwai_id = create_mogwai(owner);
+		Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+			let mogwai = maybe_mogwai.as_mut().unwrap();
+			mogwai.phase = PhaseType::Matured;
+			mogwai.generation = BRACKET.generation;
+			mogwai.rarity = BRACKET.rarity;
+		});
+		mogwai_id
+	}
+
+	fn create_queued_mogwai(owner: MockAccountId, wager: Option<MockBalance>) -> MockMogwaiId {
+		let mogwai_id = create_ready_mogwai(owner);
+		assert_ok!(BattleMogs::enqueue_mogwai(RuntimeOrigin::signed(owner), mogwai_id, wager));
+		mogwai_id
+	}
+
+	fn matched_pairs() -> Vec<(MockMogwaiId, MockMogwaiId)> {
+		System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				mock::RuntimeEvent::BattleMogs(Event::MogwaisMatched(mogwai_1, mogwai_2)) =>
+					Some((mogwai_1, mogwai_2)),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn enqueue_mogwai_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_ready_mogwai(ALICE);
+
+			assert_ok!(BattleMogs::enqueue_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id,
+				Some(1_000)
+			));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::MogwaiQueued(
+				ALICE, mogwai_id, BRACKET,
+			)));
+
+			assert_eq!(QueuedMogwais::<Test>::get(mogwai_id), Some(BRACKET));
+			assert_eq!(MatchQueue::<Test>::get(BRACKET)[0].mogwai_id, mogwai_id);
+			assert_eq!(Balances::reserved_balance(ALICE), 1_000);
+		});
+	}
+
+	#[test]
+	fn enqueue_mogwai_rejects_invalid_mogwais() {
+		ExtBuilder.build().execute_with(|| {
+			let unhatched_id = create_mogwai(ALICE);
+			let mogwai_id = create_queued_mogwai(ALICE, None);
+			let hatched_id = create_ready_mogwai(ALICE);
+			Mogwais::<Test>::mutate(hatched_id, |maybe_mogwai| {
+				maybe_mogwai.as_mut().unwrap().phase = PhaseType::Hatched;
+			});
+
+			assert_noop!(
+				BattleMogs::enqueue_mogwai(RuntimeOrigin::signed(ALICE), unhatched_id, None),
+				Error::<Test>::MogwaiNoHatch
+			);
+			assert_noop!(
+				BattleMogs::enqueue_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, None),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_noop!(
+				BattleMogs::enqueue_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id, None),
+				Error::<Test>::MogwaiAlreadyQueued
+			);
+			assert_noop!(
+				BattleMogs::enqueue_mogwai(RuntimeOrigin::signed(ALICE), hatched_id, Some(1_000)),
+				Error::<Test>::MogwaiPhaseTooLow
+			);
+		});
+	}
+
+	#[test]
+	fn dequeue_mogwai_releases_wager() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_queued_mogwai(ALICE, Some(1_000));
+
+			assert_noop!(
+				BattleMogs::dequeue_mogwai(RuntimeOrigin::signed(BOB), mogwai_id),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_ok!(BattleMogs::dequeue_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::MogwaiDequeued(
+				ALICE, mogwai_id,
+			)));
+
+			assert_eq!(QueuedMogwais::<Test>::get(mogwai_id), None);
+			assert!(!MatchQueue::<Test>::contains_key(BRACKET));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_noop!(
+				BattleMogs::dequeue_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id),
+				Error::<Test>::MogwaiNotQueued
+			);
+		});
+	}
+
+	#[test]
+	fn queued_mogwais_are_matched_on_initialize() {
+		ExtBuilder.build().execute_with(|| {
+			let wager = 1_000;
+			let mogwai_id_1 = create_queued_mogwai(ALICE, Some(wager));
+			let mogwai_id_2 = create_queued_mogwai(BOB, Some(wager));
+			let initial_balance = Balances::total_balance(&ALICE);
+			assert_eq!(initial_balance, Balances::total_balance(&BOB));
+
+			run_to_block(System::block_number() + 1);
+			assert_eq!(matched_pairs(), vec![(mogwai_id_1, mogwai_id_2)]);
+			assert!(!MatchQueue::<Test>::contains_key(BRACKET));
+			assert_eq!(QueuedMogwais::<Test>::get(mogwai_id_1), None);
+			assert_eq!(QueuedMogwais::<Test>::get(mogwai_id_2), None);
+
+			let (winner, loser, winner_id, loser_id) =
+				if BattleMogs::battle_records(mogwai_id_1).wins == 1 {
+					(ALICE, BOB, mogwai_id_1, mogwai_id_2)
+				} else {
+					(BOB, ALICE, mogwai_id_2, mogwai_id_1)
+				};
+			assert_eq!(BattleMogs::battle_records(winner_id), BattleRecord { wins: 1, losses: 0 });
+			assert_eq!(BattleMogs::battle_records(loser_id), BattleRecord { wins: 0, losses: 1 });
+
+			assert_eq!(BattleMogs::mogwai_rating(winner_id), INITIAL_RATING + 16);
+			assert_eq!(BattleMogs::mogwai_rating(loser_id), INITIAL_RATING - 16);
+			assert_eq!(BattleMogs::account_rating(winner), INITIAL_RATING + 16);
+			assert_eq!(BattleMogs::account_rating(loser), INITIAL_RATING - 16);
+
+			assert_eq!(Balances::reserved_balance(winner), 0);
+			assert_eq!(Balances::reserved_balance(loser), 0);
+			assert_eq!(Balances::total_balance(&winner), initial_balance + wager);
+			assert_eq!(Balances::total_balance(&loser), initial_balance - wager);
+		});
+	}
+
+	#[test]
+	fn mogwais_are_paired_by_rating_proximity() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_a = create_queued_mogwai(ALICE, None);
+			let mogwai_b = create_queued_mogwai(BOB, None);
+			let mogwai_c = create_queued_mogwai(BOB, None);
+			let mogwai_d = create_queued_mogwai(CHARLIE, None);
+			MogwaiRatings::<Test>::insert(mogwai_a, 1_000);
+			MogwaiRatings::<Test>::insert(mogwai_b, 1_500);
+			MogwaiRatings::<Test>::insert(mogwai_c, 1_010);
+			MogwaiRatings::<Test>::insert(mogwai_d, 1_490);
+
+			run_to_block(System::block_number() + 1);
+			assert_eq!(matched_pairs(), vec![(mogwai_a, mogwai_c), (mogwai_d, mogwai_b)]);
+		});
+	}
+
+	#[test]
+	fn mogwais_are_only_matched_against_other_players_with_the_same_wager() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_queued_mogwai(ALICE, None);
+			let mogwai_id_2 = create_queued_mogwai(ALICE, None);
+			let mogwai_id_3 = create_queued_mogwai(BOB, Some(1_000));
+
+			run_to_block(System::block_number() + 1);
+			assert!(matched_pairs().is_empty());
+			assert_eq!(MatchQueue::<Test>::get(BRACKET).len(), 3);
+			assert!(!PendingBrackets::<Test>::contains_key(BRACKET));
+
+			let mogwai_id_4 = create_queued_mogwai(CHARLIE, Some(1_000));
+			run_to_block(System::block_number() + 1);
+			assert_eq!(matched_pairs(), vec![(mogwai_id_3, mogwai_id_4)]);
+			assert_eq!(QueuedMogwais::<Test>::get(mogwai_id_1), Some(BRACKET));
+			assert_eq!(QueuedMogwais::<Test>::get(mogwai_id_2), Some(BRACKET));
+		});
+	}
+
+	#[test]
+	fn matches_per_block_are_limited() {
+		ExtBuilder.build().execute_with(|| {
+			for _ in 0..5 {
+				create_queued_mogwai(ALICE, None);
+				create_queued_mogwai(BOB, None);
+			}
+
+			run_to_block(System::block_number() + 1);
+			assert_eq!(matched_pairs().len(), 4);
+			assert_eq!(MatchQueue::<Test>::get(BRACKET).len(), 2);
+
+			run_to_block(System::block_number() + 1);
+			assert!(!MatchQueue::<Test>::contains_key(BRACKET));
+		});
+	}
+
+	#[test]
+	fn unchanged_brackets_are_not_scanned() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_queued_mogwai(ALICE, None);
+			create_queued_mogwai(ALICE, Some(1_000));
+			create_queued_mogwai(BOB, Some(2_000));
+			assert!(PendingBrackets::<Test>::contains_key(BRACKET));
+
+			assert_eq!(BattleMogs::match_mogwais(4), (0, 1));
+			assert!(!PendingBrackets::<Test>::contains_key(BRACKET));
+			assert_eq!(BattleMogs::match_mogwais(4), (0, 0));
+
+			assert_ok!(BattleMogs::dequeue_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id_1));
+			assert!(PendingBrackets::<Test>::contains_key(BRACKET));
+			assert_eq!(BattleMogs::match_mogwais(4), (0, 1));
+			assert_eq!(MatchQueue::<Test>::get(BRACKET).len(), 2);
+		});
+	}
+
+	#[test]
+	fn brackets_scanned_per_block_are_limited() {
+		ExtBuilder.build().execute_with(|| {
+			let brackets = MAX_BRACKETS_MATCHED_PER_BLOCK + 1;
+			for generation in 1..=brackets {
+				let mogwai_id = create_queued_mogwai(ALICE, None);
+				assert_ok!(BattleMogs::dequeue_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id));
+				Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+					maybe_mogwai.as_mut().unwrap().generation =
+						MogwaiGeneration::coerce_from(generation as u16);
+				});
+				assert_ok!(BattleMogs::enqueue_mogwai(
+					RuntimeOrigin::signed(ALICE),
+					mogwai_id,
+					None
+				));
+			}
+			assert_eq!(PendingBrackets::<Test>::iter().count(), brackets as usize);
+
+			assert_eq!(BattleMogs::match_mogwais(4), (0, MAX_BRACKETS_MATCHED_PER_BLOCK));
+			assert_eq!(BattleMogs::match_mogwais(4), (0, 1));
+			assert_eq!(BattleMogs::match_mogwais(4), (0, 0));
+		});
+	}
+
+	#[test]
+	fn transfer_dequeues_mogwai() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_queued_mogwai(ALICE, Some(1_000));
+
+			assert_ok!(BattleMogs::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, mogwai_id));
+			assert_eq!(QueuedMogwais::<Test>::get(mogwai_id), None);
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+		});
+	}
+}
+
+mod experience {
+	use super::*;
+	use crate::{ExperienceSource, MogwaiExperience};
+
+	fn promotions() -> Vec<(MockMogwaiId, PhaseType)> {
+		System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				mock::RuntimeEvent::BattleMogs(Event::MogwaiPromoted(mogwai_id, phase)) =>
+					Some((mogwai_id, phase)),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn battles_award_experience() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(ALICE);
+			let mogwai_id_2 = create_hatched_mogwai(BOB);
+
+			assert_ok!(BattleMogs::challenge_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+			assert_ok!(BattleMogs::accept_challenge(RuntimeOrigin::signed(BOB), mogwai_id_1));
+
+			let (winner_id, loser_id) = if BattleMogs::battle_records(mogwai_id_1).wins == 1 {
+				(mogwai_id_1, mogwai_id_2)
+			} else {
+				(mogwai_id_2, mogwai_id_1)
+			};
+			assert_eq!(
+				BattleMogs::mogwai_experience(winner_id),
+				ExperienceSource::BattleWon.points()
+			);
+			assert_eq!(
+				BattleMogs::mogwai_experience(loser_id),
+				ExperienceSource::BattleLost.points()
+			);
+			assert!(promotions().is_empty());
+		});
+	}
+
+	#[test]
+	fn breeding_awards_experience_to_both_parents() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(BOB);
+			let mogwai_id_2 = create_hatched_mogwai(BOB);
+
+			assert_ok!(BattleMogs::breed_mogwai(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+
+			let points = ExperienceSource::Breeding.points();
+			assert_eq!(BattleMogs::mogwai_experience(mogwai_id_1), points);
+			assert_eq!(BattleMogs::mogwai_experience(mogwai_id_2), points);
+		});
+	}
+
+	#[test]
+	fn sacrifice_into_awards_experience_and_promotes() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(CHARLIE);
+			let mogwai_id_2 = create_hatched_mogwai(CHARLIE);
+			let intrinsic = 5_000;
+			for (mogwai_id, mogwai_intrinsic) in [(mogwai_id_1, 0), (mogwai_id_2, intrinsic)] {
+				Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+					let mogwai = maybe_mogwai.as_mut().unwrap();
+					mogwai.rarity = RarityType::Epic;
+					mogwai.intrinsic = mogwai_intrinsic;
+				});
+			}
+			MogwaiExperience::<Test>::insert(mogwai_id_2, 60);
+
+			assert_ok!(BattleMogs::sacrifice_into(
+				RuntimeOrigin::signed(CHARLIE),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+
+			let mogwai = BattleMogs::mogwai(mogwai_id_2).unwrap();
+			assert_eq!(BattleMogs::mogwai_experience(mogwai_id_2), 110);
+			assert_eq!(BattleMogs::mogwai_experience(mogwai_id_1), 0);
+			assert_eq!(mogwai.phase, PhaseType::Matured);
+			assert_eq!(mogwai.intrinsic, intrinsic * 5 / 3);
+			assert_eq!(promotions(), vec![(mogwai_id_2, PhaseType::Matured)]);
+		});
+	}
+
+	#[test]
+	fn promotion_can_skip_phases_and_stops_at_exalted() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_hatched_mogwai(ALICE);
+			let intrinsic = 3_000;
+			Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+				maybe_mogwai.as_mut().unwrap().intrinsic = intrinsic;
+			});
+			MogwaiExperience::<Test>::insert(mogwai_id, 5_000);
+
+			BattleMogs::gain_experience(mogwai_id, ExperienceSource::BattleLost);
+
+			let mogwai = BattleMogs::mogwai(mogwai_id).unwrap();
+			assert_eq!(mogwai.phase, PhaseType::Exalted);
+			assert_eq!(mogwai.intrinsic, intrinsic * 5);
+			assert_eq!(
+				promotions(),
+				vec![
+					(mogwai_id, PhaseType::Matured),
+					(mogwai_id, PhaseType::Mastered),
+					(mogwai_id, PhaseType::Exalted)
+				]
+			);
+
+			BattleMogs::gain_experience(mogwai_id, ExperienceSource::BattleWon);
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().phase, PhaseType::Exalted);
+			assert_eq!(promotions().len(), 3);
+		});
+	}
+
+	#[test]
+	fn experience_is_removed_with_the_mogwai() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_hatched_mogwai(ALICE);
+			MogwaiExperience::<Test>::insert(mogwai_id, 50);
+
+			assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(ALICE), mogwai_id));
+			assert!(!MogwaiExperience::<Test>::contains_key(mogwai_id));
+		});
+	}
+}
+
+mod achievement_rewards {
+	use super::*;
+	use crate::{AccountAchievements, AchievementReward, ClaimedAchievements};
+
+	const REWARD: AchievementReward<MockBalance> = AchievementReward {
+		tokens: 1_000,
+		eggs: 2,
+		config_upgrade: Some(GameConfigType::MaxMogwaisInAccount),
+	};
+
+	fn complete_achievement(account: MockAccountId, achievement: AccountAchievement) {
+		AccountAchievements::<Test>::insert(account, achievement, AchievementState::Completed);
+	}
+
+	#[test]
+	fn set_achievement_reward_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let achievement = AccountAchievement::Buyer;
+
+			assert_ok!(BattleMogs::set_achievement_reward(
+				RuntimeOrigin::signed(ALICE),
+				achievement,
+				Some(REWARD)
+			));
+			assert_eq!(BattleMogs::achievement_rewards(achievement), Some(REWARD));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::AchievementRewardSet(
+				achievement,
+				Some(REWARD),
+			)));
+
+			assert_ok!(BattleMogs::set_achievement_reward(
+				RuntimeOrigin::signed(ALICE),
+				achievement,
+				None
+			));
+			assert_eq!(BattleMogs::achievement_rewards(achievement), None);
+		});
+	}
+
+	#[test]
+	fn set_achievement_reward_rejects_invalid_rewards() {
+		ExtBuilder.build().execute_with(|| {
+			let achievement = AccountAchievement::Buyer;
+
+			assert_noop!(
+				BattleMogs::set_achievement_reward(
+					RuntimeOrigin::signed(BOB),
+					achievement,
+					Some(REWARD)
+				),
+				Error::<Test>::FounderAction
+			);
+			assert_noop!(
+				BattleMogs::set_achievement_reward(
+					RuntimeOrigin::signed(ALICE),
+					achievement,
+					Some(AchievementReward { eggs: 4, ..REWARD })
+				),
+				Error::<Test>::TooManyRewardEggs
+			);
+		});
+	}
+
+	#[test]
+	fn claim_achievement_grants_reward() {
+		ExtBuilder.build().execute_with(|| {
+			let achievement = AccountAchievement::Buyer;
+			assert_ok!(BattleMogs::set_achievement_reward(
+				RuntimeOrigin::signed(ALICE),
+				achievement,
+				Some(REWARD)
+			));
+			complete_achievement(BOB, achievement);
+			let organizer_balance = Balances::free_balance(ALICE);
+			let balance = Balances::free_balance(BOB);
+
+			assert_ok!(BattleMogs::claim_achievement(RuntimeOrigin::signed(BOB), achievement));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::AchievementClaimed(
+				BOB,
+				achievement,
+			)));
+
+			assert_eq!(Balances::free_balance(BOB), balance + REWARD.tokens);
+			assert_eq!(Balances::free_balance(ALICE), organizer_balance - REWARD.tokens);
+			assert_eq!(BattleMogs::owned_mogwais_count(BOB), 2);
+			assert_eq!(BattleMogs::config_value(BOB, GameConfigType::MaxMogwaisInAccount), 12);
+			assert!(ClaimedAchievements::<Test>::contains_key(BOB, achievement));
+
+			assert_noop!(
+				BattleMogs::claim_achievement(RuntimeOrigin::signed(BOB), achievement),
+				Error::<Test>::AchievementAlreadyClaimed
+			);
+		});
+	}
+
+	#[test]
+	fn claim_achievement_rejects_unclaimable_achievements() {
+		ExtBuilder.build().execute_with(|| {
+			let achievement = AccountAchievement::Buyer;
+
+			assert_noop!(
+				BattleMogs::claim_achievement(RuntimeOrigin::signed(BOB), achievement),
+				Error::<Test>::AchievementNotCompleted
+			);
+
+			complete_achievement(BOB, achievement);
+			assert_noop!(
+				BattleMogs::claim_achievement(RuntimeOrigin::signed(BOB), achievement),
+				Error::<Test>::AchievementRewardNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn completing_an_achievement_emits_event() {
+		ExtBuilder.build().execute_with(|| {
+			BattleMogs::update_achievement_for(&BOB, AccountAchievement::LegendBreeder, 1);
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::AchievementCompleted(
+				BOB,
+				AccountAchievement::LegendBreeder,
+			)));
+			assert_eq!(
+				BattleMogs::account_achievements(BOB, AccountAchievement::LegendBreeder),
+				Some(AchievementState::Completed)
+			);
+
+			System::reset_events();
+			BattleMogs::update_achievement_for(&BOB, AccountAchievement::LegendBreeder, 1);
+			assert!(System::events().is_empty());
+		});
+	}
+}
+
+mod migration {
+	use super::*;
+	use crate::{
+		migration::v2::{GameConfigV1, MigrateToV2},
+		AccountConfig, Pallet,
+	};
+	use frame_support::{
+		migration::{get_storage_value, put_storage_value},
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		Blake2_128Concat, StorageHasher,
+	};
+	use parity_scale_codec::{Decode, Encode};
+
+	fn put_account_config<V: Encode>(account: MockAccountId, config: V) {
+		put_storage_value(
+			b"BattleMogs",
+			b"AccountConfig",
+			&Blake2_128Concat::hash(&account.encode()),
+			config,
+		);
+	}
+
+	fn raw_account_config<V: Decode>(account: MockAccountId) -> Option<V> {
+		get_storage_value(
+			b"BattleMogs",
+			b"AccountConfig",
+			&Blake2_128Concat::hash(&account.encode()),
+		)
+	}
+
+	#[test]
+	fn migrate_account_config_from_v1_to_v2() {
+		ExtBuilder.build().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+
+			let configs: [(MockAccountId, GameConfigV1); 2] =
+				[(ALICE, [0, 2, 0, 0, 0, 0, 0, 0, 0, 0]), (BOB, [1, 9, 1, 1, 0, 0, 0, 0, 0, 0])];
+			for (account, config) in configs {
+				put_account_config(account, config);
+			}
+
+			let _ = MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(2));
+			assert_eq!(
+				AccountConfig::<Test>::get(ALICE),
+				Some(GameConfig {
+					activated: 0,
+					max_mogwais: 2,
+					max_stash_size: 0,
+					account_naming: 0
+				})
+			);
+			assert_eq!(
+				AccountConfig::<Test>::get(BOB),
+				Some(GameConfig {
+					activated: 0,
+					max_mogwais: 3,
+					max_stash_size: 1,
+					account_naming: 1
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn migrate_account_config_clamps_levels_to_their_rules() {
+		ExtBuilder.build().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+			put_account_config::<GameConfigV1>(ALICE, [1, 255, 4, 200, 9, 9, 9, 9, 9, 9]);
+			put_account_config::<GameConfigV1>(BOB, [0, 3, 3, 1, 0, 0, 0, 0, 0, 0]);
+
+			let _ = MigrateToV2::<Test>::on_runtime_upgrade();
+
+			// Levels above the maximum of their rule are lowered to it, including the activation
+			// flag which can't be upgraded, while the bytes of parameters no longer in use are
+			// dropped.
+			let expected =
+				GameConfig { activated: 0, max_mogwais: 3, max_stash_size: 3, account_naming: 1 };
+			assert_eq!(raw_account_config::<[u8; 4]>(ALICE), Some([0, 3, 3, 1]));
+			assert_eq!(AccountConfig::<Test>::get(ALICE), Some(expected));
+			assert_eq!(AccountConfig::<Test>::get(BOB), Some(expected));
+
+			let config = AccountConfig::<Test>::get(ALICE).unwrap();
+			assert_eq!(config.value(GameConfigType::MaxMogwaisInAccount), 24);
+			assert_eq!(config.value(GameConfigType::MaxStashSize), 16);
+			assert_eq!(config.value(GameConfigType::AccountNaming), 1);
+		});
+	}
+
+	#[test]
+	fn migrate_account_config_only_runs_from_v1() {
+		ExtBuilder.build().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<Test>>();
+			let config =
+				GameConfig { activated: 0, max_mogwais: 2, max_stash_size: 1, account_naming: 0 };
+			AccountConfig::<Test>::insert(ALICE, config);
+
+			let _ = MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(2));
+			assert_eq!(AccountConfig::<Test>::get(ALICE), Some(config));
+		});
+	}
+}
+
+mod transfer_mogwai {
+	use super::*;
+	use crate::MogwaiApprovals;
+
+	#[test]
+	fn transfer_mogwai_by_owner() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(BOB);
+			put_mogwai_on_sale(BOB, mogwai_id, 1_000);
+
+			assert_ok!(BattleMogs::transfer_mogwai(RuntimeOrigin::signed(BOB), CHARLIE, mogwai_id));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::MogwaiTransfered(
+				BOB, CHARLIE, mogwai_id,
+			)));
+
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().owner, CHARLIE);
+			assert_eq!(BattleMogs::owned_mogwais_count(BOB), 0);
+			assert_eq!(BattleMogs::owned_mogwais_count(CHARLIE), 1);
+			assert!(BattleMogs::owners(CHARLIE).contains(&mogwai_id));
+			assert_eq!(BattleMogs::mogwai_prices(mogwai_id), None);
+
+			assert_noop!(
+				BattleMogs::transfer_mogwai(RuntimeOrigin::signed(CHARLIE), CHARLIE, mogwai_id),
+				Error::<Test>::TransferToSelf
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_mogwai_by_approved_operator() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(BOB);
+
+			assert_noop!(
+				BattleMogs::transfer_mogwai(RuntimeOrigin::signed(ALICE), CHARLIE, mogwai_id),
+				Error::<Test>::MogwaiNotApproved
+			);
+			assert_noop!(
+				BattleMogs::approve_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id, Some(ALICE)),
+				Error::<Test>::MogwaiNotOwned
+			);
+
+			assert_ok!(BattleMogs::approve_mogwai(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				Some(ALICE)
+			));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::MogwaiApproved(
+				BOB,
+				mogwai_id,
+				Some(ALICE),
+			)));
+
+			assert_ok!(BattleMogs::transfer_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				CHARLIE,
+				mogwai_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::MogwaiTransfered(
+				BOB, CHARLIE, mogwai_id,
+			)));
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().owner, CHARLIE);
+			assert!(!MogwaiApprovals::<Test>::contains_key(mogwai_id));
+
+			assert_noop!(
+				BattleMogs::transfer_mogwai(RuntimeOrigin::signed(ALICE), BOB, mogwai_id),
+				Error::<Test>::MogwaiNotApproved
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_mogwai_pays_transfer_fee() {
+		ExtBuilder.build().execute_with(|| {
+			let fee = 1_000;
+			assert_noop!(
+				BattleMogs::set_transfer_fee(RuntimeOrigin::signed(BOB), Some(fee)),
+				Error::<Test>::FounderAction
+			);
+			assert_ok!(BattleMogs::set_transfer_fee(RuntimeOrigin::signed(ALICE), Some(fee)));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::TransferFeeSet(Some(
+				fee,
+			))));
+
+			let mogwai_id = create_mogwai(BOB);
+			let organizer_balance = Balances::free_balance(ALICE);
+			let balance = Balances::free_balance(BOB);
+
+			assert_ok!(BattleMogs::transfer_mogwai(RuntimeOrigin::signed(BOB), CHARLIE, mogwai_id));
+			assert_eq!(Balances::free_balance(BOB), balance - fee);
+			assert_eq!(Balances::free_balance(ALICE), organizer_balance + fee);
+		});
+	}
+
+	#[test]
+	fn transfer_mogwai_cannot_transfer_above_limit() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_limit =
+				BattleMogs::config_value(CHARLIE, GameConfigType::MaxMogwaisInAccount);
+			for _ in 0..mogwai_limit {
+				let _ = create_mogwai(CHARLIE);
+			}
+			let mogwai_id = create_mogwai(BOB);
+
+			assert_noop!(
+				BattleMogs::transfer_mogwai(RuntimeOrigin::signed(BOB), CHARLIE, mogwai_id),
+				Error::<Test>::MaxMogwaisInAccount
+			);
+		});
+	}
+}
+
+mod pedigree {
+	use super::*;
+	use crate::{FamilyMember, Kinship, MogwaiPedigrees, Pedigree};
+
+	fn hatch(mogwai_id: MockMogwaiId) {
+		Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+			maybe_mogwai.as_mut().unwrap().phase = PhaseType::Hatched;
+		});
+	}
+
+	fn breed(
+		owner: MockAccountId,
+		mogwai_id_1: MockMogwaiId,
+		mogwai_id_2: MockMogwaiId,
+	) -> MockMogwaiId {
+		assert_ok!(BattleMogs::breed_mogwai(
+			RuntimeOrigin::signed(owner),
+			mogwai_id_1,
+			mogwai_id_2
+		));
+		match last_event() {
+			mock::RuntimeEvent::BattleMogs(Event::MogwaiBred(mogwai_id)) => mogwai_id,
+			_ => panic!("Expected MogwaiBred event"),
+		}
+	}
+
+	#[test]
+	fn bred_mogwais_remember_their_parents() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(BOB);
+			let mogwai_id_2 = create_hatched_mogwai(BOB);
+			assert_eq!(
+				BattleMogs::mogwai_pedigrees(mogwai_id_1),
+				Some(Pedigree::founder(mogwai_id_1))
+			);
+
+			let child_id = breed(BOB, mogwai_id_1, mogwai_id_2);
+			assert_eq!(
+				BattleMogs::mogwai_pedigrees(child_id),
+				Some(Pedigree {
+					parents: Some((mogwai_id_1, mogwai_id_2)),
+					lineage: 1,
+					dynasty: mogwai_id_1,
+				})
+			);
+
+			hatch(child_id);
+			run_to_block(System::block_number() + BreedingCooldown::get());
+			let grandchild_id = breed(BOB, mogwai_id_2, child_id);
+			assert_eq!(
+				BattleMogs::mogwai_pedigrees(grandchild_id),
+				Some(Pedigree {
+					parents: Some((mogwai_id_2, child_id)),
+					lineage: 2,
+					dynasty: mogwai_id_2,
+				})
+			);
+
+			// pedigrees outlive their mogwais
+			assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(BOB), child_id));
+			assert!(MogwaiPedigrees::<Test>::contains_key(child_id));
+		});
+	}
+
+	#[test]
+	fn kinship_between_relatives() {
+		let (founder_1, founder_2, founder_3) = (1, 2, 3);
+		let pedigree_1 = Pedigree::founder(founder_1);
+		let pedigree_2 = Pedigree::founder(founder_2);
+		let pedigree_3 = Pedigree::founder(founder_3);
+		let (child_1, child_2, cousin) = (4, 5, 6);
+		let child_pedigree_1 = Pedigree::bred([(founder_1, &pedigree_1), (founder_2, &pedigree_2)]);
+		let child_pedigree_2 = Pedigree::bred([(founder_3, &pedigree_3), (founder_2, &pedigree_2)]);
+		let cousin_pedigree =
+			Pedigree::bred([(child_1, &child_pedigree_1), (founder_3, &pedigree_3)]);
+
+		assert_eq!(
+			Pedigree::kinship((founder_1, &pedigree_1), (founder_2, &pedigree_2)),
+			Kinship::Unrelated
+		);
+		assert_eq!(
+			Pedigree::kinship((founder_1, &pedigree_1), (child_1, &child_pedigree_1)),
+			Kinship::ParentChild
+		);
+		assert_eq!(
+			Pedigree::kinship((child_1, &child_pedigree_1), (child_2, &child_pedigree_2)),
+			Kinship::Sibling
+		);
+		assert_eq!(
+			Pedigree::kinship((founder_1, &pedigree_1), (cousin, &cousin_pedigree)),
+			Kinship::Dynasty
+		);
+		assert_eq!(Kinship::Sibling.inbreeding_penalty(), 2);
+	}
+
+	#[test]
+	fn family_tree_is_bounded_by_depth() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(BOB);
+			let mogwai_id_2 = create_hatched_mogwai(BOB);
+			let child_id = breed(BOB, mogwai_id_1, mogwai_id_2);
+			let child_pedigree = BattleMogs::mogwai_pedigrees(child_id).unwrap();
+
+			assert_eq!(
+				BattleMogs::family_tree(child_id, 0),
+				vec![FamilyMember { mogwai_id: child_id, depth: 0, pedigree: child_pedigree }]
+			);
+			assert_eq!(
+				BattleMogs::family_tree(child_id, u8::MAX),
+				vec![
+					FamilyMember { mogwai_id: child_id, depth: 0, pedigree: child_pedigree },
+					FamilyMember {
+						mogwai_id: mogwai_id_1,
+						depth: 1,
+						pedigree: Pedigree::founder(mogwai_id_1)
+					},
+					FamilyMember {
+						mogwai_id: mogwai_id_2,
+						depth: 1,
+						pedigree: Pedigree::founder(mogwai_id_2)
+					},
+				]
+			);
+			assert!(BattleMogs::family_tree(MockMogwaiId::default(), 4).is_empty());
+		});
+	}
+}
+
+mod stud_listings {
+	use super::*;
+	use crate::{StudListing, StudListings};
+
+	#[test]
+	fn list_and_unlist_stud() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_hatched_mogwai(BOB);
+			let listing = StudListing { price: 1_000, uses_left: 2 };
+
+			assert_ok!(BattleMogs::list_stud(RuntimeOrigin::signed(BOB), mogwai_id, 1_000, 2));
+			assert_eq!(BattleMogs::stud_listings(mogwai_id), Some(listing));
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::StudListed(BOB, mogwai_id, listing))
+			);
+
+			assert_ok!(BattleMogs::unlist_stud(RuntimeOrigin::signed(BOB), mogwai_id));
+			assert_eq!(BattleMogs::stud_listings(mogwai_id), None);
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::StudUnlisted(BOB, mogwai_id))
+			);
+		});
+	}
+
+	#[test]
+	fn list_stud_rejects_invalid_listings() {
+		ExtBuilder.build().execute_with(|| {
+			let unhatched_id = create_mogwai(BOB);
+			let mogwai_id = create_hatched_mogwai(BOB);
+
+			assert_noop!(
+				BattleMogs::list_stud(RuntimeOrigin::signed(CHARLIE), mogwai_id, 1_000, 1),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_noop!(
+				BattleMogs::list_stud(RuntimeOrigin::signed(BOB), unhatched_id, 1_000, 1),
+				Error::<Test>::MogwaiNoHatch
+			);
+			assert_noop!(
+				BattleMogs::list_stud(RuntimeOrigin::signed(BOB), mogwai_id, 1_000, 0),
+				Error::<Test>::StudListingInvalid
+			);
+			assert_noop!(
+				BattleMogs::unlist_stud(RuntimeOrigin::signed(BOB), mogwai_id),
+				Error::<Test>::StudNotListed
+			);
+		});
+	}
+
+	#[test]
+	fn breeding_with_unlisted_foreign_mogwai_fails() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(BOB);
+			let mogwai_id_2 = create_hatched_mogwai(CHARLIE);
+
+			assert_noop!(
+				BattleMogs::breed_mogwai(RuntimeOrigin::signed(BOB), mogwai_id_1, mogwai_id_2),
+				Error::<Test>::StudNotListed
+			);
+		});
+	}
+
+	#[test]
+	fn breeding_with_listed_stud_pays_its_owner() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(BOB);
+			let mogwai_id_2 = create_hatched_mogwai(CHARLIE);
+			let price = 1_000;
+			assert_ok!(BattleMogs::list_stud(
+				RuntimeOrigin::signed(CHARLIE),
+				mogwai_id_2,
+				price,
+				2
+			));
+
+			let balance_before = Balances::free_balance(CHARLIE);
+			assert_ok!(BattleMogs::breed_mogwai(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+			assert_eq!(Balances::free_balance(CHARLIE), balance_before + price);
+			assert_eq!(
+				BattleMogs::stud_listings(mogwai_id_2),
+				Some(StudListing { price, uses_left: 1 })
+			);
+			assert_eq!(BattleMogs::owners(BOB).len(), 2);
+			assert_eq!(BattleMogs::owners(CHARLIE).len(), 1);
+
+			run_to_block(System::block_number() + BreedingCooldown::get());
+			assert_ok!(BattleMogs::breed_mogwai(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+			assert_eq!(BattleMogs::stud_listings(mogwai_id_2), None);
+			assert!(!StudListings::<Test>::contains_key(mogwai_id_2));
+		});
+	}
+
+	#[test]
+	fn breeding_respects_cooldown() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_hatched_mogwai(BOB);
+			let mogwai_id_2 = create_hatched_mogwai(BOB);
+			let mogwai_id_3 = create_hatched_mogwai(BOB);
+
+			assert_ok!(BattleMogs::breed_mogwai(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id_1,
+				mogwai_id_2
+			));
+			let ready_at = System::block_number() + BreedingCooldown::get();
+			assert_eq!(BattleMogs::breeding_cooldowns(mogwai_id_2), Some(ready_at));
+
+			assert_noop!(
+				BattleMogs::breed_mogwai(RuntimeOrigin::signed(BOB), mogwai_id_3, mogwai_id_2),
+				Error::<Test>::BreedingOnCooldown
+			);
+
+			run_to_block(ready_at);
+			assert_ok!(BattleMogs::breed_mogwai(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id_3,
+				mogwai_id_2
+			));
+		});
+	}
+
+	#[test]
+	fn transfer_clears_stud_listing() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_hatched_mogwai(BOB);
+			assert_ok!(BattleMogs::list_stud(RuntimeOrigin::signed(BOB), mogwai_id, 1_000, 1));
+
+			assert_ok!(BattleMogs::transfer_mogwai(RuntimeOrigin::signed(BOB), CHARLIE, mogwai_id));
+			assert_eq!(BattleMogs::stud_listings(mogwai_id), None);
+		});
+	}
+}
+
+mod nft_transfer {
+	use super::*;
+	use crate::{CollectionId, IpfsUrlOf, LockedMogwais, MogwaiExperience, MogwaiOf, Owners};
+	use frame_support::traits::tokens::nonfungibles_v2::{Create, Inspect};
+	use pallet_ajuna_nft_transfer::traits::NftConvertible;
+	use parity_scale_codec::Encode;
+
+	fn create_collection() -> MockCollectionId {
+		let collection_id =
+			Nft::create_collection(&ALICE, &ALICE, &pallet_nfts::CollectionConfig::default())
+				.expect("Collection created");
+		assert_ok!(BattleMogs::set_collection_id(RuntimeOrigin::signed(ALICE), collection_id));
+		collection_id
+	}
+
+	fn ipfs_url() -> IpfsUrlOf {
+		IpfsUrlOf::try_from(b"ipfs://mogwai".to_vec()).unwrap()
+	}
+
+	#[test]
+	fn set_collection_id_works() {
+		ExtBuilder.build().execute_with(|| {
+			assert_noop!(
+				BattleMogs::set_collection_id(RuntimeOrigin::signed(BOB), 333),
+				Error::<Test>::FounderAction
+			);
+
+			assert_ok!(BattleMogs::set_collection_id(RuntimeOrigin::signed(ALICE), 369));
+			assert_eq!(CollectionId::<Test>::get(), Some(369));
+			assert_eq!(last_event(), mock::RuntimeEvent::BattleMogs(Event::CollectionIdSet(369)));
+		});
+	}
+
+	#[test]
+	fn lock_mogwai_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let collection_id = create_collection();
+			let mogwai_id = create_hatched_mogwai(BOB);
+			let mogwai = BattleMogs::mogwai(mogwai_id).unwrap();
+			MogwaiExperience::<Test>::insert(mogwai_id, 42);
+
+			assert_ok!(BattleMogs::lock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, ipfs_url()));
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::MogwaiLocked(BOB, mogwai_id))
+			);
+			assert!(LockedMogwais::<Test>::contains_key(mogwai_id));
+			assert_eq!(BattleMogs::mogwai(mogwai_id), None);
+			assert!(!Owners::<Test>::get(BOB).contains(&mogwai_id));
+			assert_eq!(BattleMogs::owned_mogwais_count(BOB), 0);
+			assert_eq!(BattleMogs::all_mogwais_count(), 0);
+			assert_eq!(BattleMogs::mogwai_experience(mogwai_id), 42);
+
+			assert_eq!(Nft::owner(collection_id, mogwai_id), Some(BOB));
+			assert_eq!(
+				<Nft as Inspect<MockAccountId>>::system_attribute(
+					&collection_id,
+					&mogwai_id,
+					<MogwaiOf<Test> as NftConvertible<KeyLimit, ValueLimit>>::ITEM_CODE,
+				)
+				.unwrap(),
+				mogwai.encode(),
+			);
+			for (attribute_code, attribute) in
+				<MogwaiOf<Test> as NftConvertible<KeyLimit, ValueLimit>>::get_attribute_codes()
+					.iter()
+					.map(|attr| attr.as_slice())
+					.zip([
+						format!("0x{}", hex::encode(mogwai.dna.concat())).into_bytes(),
+						format!("{:?}", mogwai.rarity).to_uppercase().into_bytes(),
+						format!("{}", mogwai.generation as u8).into_bytes(),
+						b"HATCHED".to_vec(),
+						format!("{}", mogwai.intrinsic).into_bytes(),
+					]) {
+				assert_eq!(
+					<Nft as Inspect<MockAccountId>>::system_attribute(
+						&collection_id,
+						&mogwai_id,
+						attribute_code,
+					)
+					.unwrap(),
+					attribute
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn lock_mogwai_rejects_invalid_mogwais() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_hatched_mogwai(BOB);
+			assert_noop!(
+				BattleMogs::lock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, ipfs_url()),
+				Error::<Test>::CollectionIdNotSet
+			);
+
+			create_collection();
+			let egg_id = create_mogwai(BOB);
+			assert_noop!(
+				BattleMogs::lock_mogwai(RuntimeOrigin::signed(CHARLIE), mogwai_id, ipfs_url()),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_noop!(
+				BattleMogs::lock_mogwai(RuntimeOrigin::signed(BOB), egg_id, ipfs_url()),
+				Error::<Test>::MogwaiNoHatch
+			);
+
+			put_mogwai_on_sale(BOB, mogwai_id, 1_000);
+			assert_noop!(
+				BattleMogs::lock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, ipfs_url()),
+				Error::<Test>::MogwaiIsOnSale
+			);
+		});
+	}
+
+	#[test]
+	fn unlock_mogwai_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let collection_id = create_collection();
+			let mogwai_id = create_hatched_mogwai(BOB);
+			let mogwai = BattleMogs::mogwai(mogwai_id).unwrap();
+			assert_ok!(BattleMogs::lock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, ipfs_url()));
+
+			assert_ok!(BattleMogs::unlock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id));
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::MogwaiUnlocked(BOB, mogwai_id))
+			);
+			assert!(!LockedMogwais::<Test>::contains_key(mogwai_id));
+			assert_eq!(BattleMogs::mogwai(mogwai_id), Some(mogwai));
+			assert!(Owners::<Test>::get(BOB).contains(&mogwai_id));
+			assert_eq!(BattleMogs::owned_mogwais_count(BOB), 1);
+			assert_eq!(Nft::owner(collection_id, mogwai_id), None);
+
+			assert_noop!(
+				BattleMogs::unlock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id),
+				Error::<Test>::MogwaiNotLocked
+			);
+		});
+	}
+
+	#[test]
+	fn unlock_mogwai_goes_to_the_nft_holder() {
+		ExtBuilder.build().execute_with(|| {
+			let collection_id = create_collection();
+			let mogwai_id = create_hatched_mogwai(BOB);
+			assert_ok!(BattleMogs::lock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, ipfs_url()));
+			assert_ok!(Nft::transfer(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				mogwai_id,
+				CHARLIE
+			));
+
+			assert_noop!(
+				BattleMogs::unlock_mogwai(RuntimeOrigin::signed(BOB), mogwai_id),
+				pallet_nfts::Error::<Test>::NoPermission
+			);
+			assert_ok!(BattleMogs::unlock_mogwai(RuntimeOrigin::signed(CHARLIE), mogwai_id));
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().owner, CHARLIE);
+			assert!(Owners::<Test>::get(CHARLIE).contains(&mogwai_id));
+		});
+	}
+}
@@ -166,6 +166,53 @@ impl Default for PhaseType {
 	}
 }
 
+impl PhaseType {
+	/// The experience a mogwai needs to be promoted out of this phase, if it can be promoted.
+	pub fn promotion_threshold(&self) -> Option<ExperiencePoints> {
+		match self {
+			PhaseType::Hatched => Some(100),
+			PhaseType::Matured => Some(400),
+			PhaseType::Mastered => Some(1_000),
+			PhaseType::None | PhaseType::Bred | PhaseType::Exalted => None,
+		}
+	}
+
+	pub fn next(&self) -> Self {
+		match self {
+			PhaseType::None => PhaseType::Bred,
+			PhaseType::Bred => PhaseType::Hatched,
+			PhaseType::Hatched => PhaseType::Matured,
+			PhaseType::Matured => PhaseType::Mastered,
+			PhaseType::Mastered | PhaseType::Exalted => PhaseType::Exalted,
+		}
+	}
+
+	pub fn is_at_least(&self, phase: PhaseType) -> bool {
+		*self as u8 >= phase as u8
+	}
+}
+
+pub type ExperiencePoints = u32;
+
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum ExperienceSource {
+	BattleWon = 0,
+	BattleLost = 1,
+	Breeding = 2,
+	SacrificeInto = 3,
+}
+
+impl ExperienceSource {
+	pub fn points(&self) -> ExperiencePoints {
+		match self {
+			ExperienceSource::BattleWon => 30,
+			ExperienceSource::BattleLost => 10,
+			ExperienceSource::Breeding => 20,
+			ExperienceSource::SacrificeInto => 50,
+		}
+	}
+}
+
 #[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum AchievementState {
 	InProgress { current: u16, target: u16 },
@@ -218,6 +265,129 @@ impl AccountAchievement {
 	}
 }
 
+/// The ancestry of a mogwai. Founders, the mogwais created rather than bred, have no parents and
+/// start their own dynasty.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct Pedigree<MogwaiId> {
+	pub parents: Option<(MogwaiId, MogwaiId)>,
+	/// Generations of breeding since the founder of the dynasty.
+	pub lineage: u16,
+	/// The founder of the dynasty.
+	pub dynasty: MogwaiId,
+}
+
+impl<MogwaiId: Copy + PartialEq> Pedigree<MogwaiId> {
+	pub fn founder(mogwai_id: MogwaiId) -> Self {
+		Self { parents: None, lineage: 0, dynasty: mogwai_id }
+	}
+
+	/// The pedigree of a mogwai bred from two parents, continuing the dynasty of the first one.
+	pub fn bred(parents: [(MogwaiId, &Self); 2]) -> Self {
+		let [(parent_1, pedigree_1), (parent_2, pedigree_2)] = parents;
+		Self {
+			parents: Some((parent_1, parent_2)),
+			lineage: pedigree_1.lineage.max(pedigree_2.lineage).saturating_add(1),
+			dynasty: pedigree_1.dynasty,
+		}
+	}
+
+	fn parents(&self) -> [Option<MogwaiId>; 2] {
+		match self.parents {
+			Some((parent_1, parent_2)) => [Some(parent_1), Some(parent_2)],
+			None => [None, None],
+		}
+	}
+
+	/// How closely two mogwais are related.
+	pub fn kinship(mogwai_1: (MogwaiId, &Self), mogwai_2: (MogwaiId, &Self)) -> Kinship {
+		let ((id_1, pedigree_1), (id_2, pedigree_2)) = (mogwai_1, mogwai_2);
+		let (parents_1, parents_2) = (pedigree_1.parents(), pedigree_2.parents());
+
+		if parents_1.contains(&Some(id_2)) || parents_2.contains(&Some(id_1)) {
+			Kinship::ParentChild
+		} else if parents_1.iter().flatten().any(|parent| parents_2.contains(&Some(*parent))) {
+			Kinship::Sibling
+		} else if pedigree_1.dynasty == pedigree_2.dynasty {
+			Kinship::Dynasty
+		} else {
+			Kinship::Unrelated
+		}
+	}
+}
+
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum Kinship {
+	Unrelated = 0,
+	Dynasty = 1,
+	Sibling = 2,
+	ParentChild = 3,
+}
+
+impl Kinship {
+	/// The generations and rarity levels lost when breeding relatives.
+	pub fn inbreeding_penalty(&self) -> u8 {
+		match self {
+			Kinship::Unrelated => 0,
+			Kinship::Dynasty => 1,
+			Kinship::Sibling | Kinship::ParentChild => 2,
+		}
+	}
+}
+
+/// A member of a mogwai's family tree, `depth` generations above the mogwai itself.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo)]
+pub struct FamilyMember<MogwaiId> {
+	pub mogwai_id: MogwaiId,
+	pub depth: u8,
+	pub pedigree: Pedigree<MogwaiId>,
+}
+
+/// A mogwai offered for breeding by its owner, for a price and a limited number of uses.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct StudListing<Balance> {
+	pub price: Balance,
+	pub uses_left: u16,
+}
+
+/// What an account receives when claiming a completed achievement.
+#[derive(Encode, Decode, Debug, Default, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct AchievementReward<Balance> {
+	/// Tokens transferred from the organizer.
+	pub tokens: Balance,
+	/// Free mogwai eggs minted to the account.
+	pub eggs: u8,
+	/// An account configuration parameter upgraded free of charge.
+	pub config_upgrade: Option<GameConfigType>,
+}
+
+/// A pending duel issued by the owner of a mogwai against another player's mogwai.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct BattleChallenge<AccountId, MogwaiId, BlockNumber> {
+	pub challenger: AccountId,
+	pub opponent_mogwai: MogwaiId,
+	pub created_at: BlockNumber,
+}
+
+#[derive(Encode, Decode, Debug, Default, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct BattleRecord {
+	pub wins: u32,
+	pub losses: u32,
+}
+
+/// Mogwais only get matched against mogwais of the same generation and rarity.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct MatchBracket {
+	pub generation: MogwaiGeneration,
+	pub rarity: RarityType,
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct MatchQueueEntry<AccountId, MogwaiId, Balance> {
+	pub owner: AccountId,
+	pub mogwai_id: MogwaiId,
+	pub wager: Option<Balance>,
+}
+
 pub type Balance = u128;
 pub const MILLIMOGS: Balance = 1_000_000_000;
 pub const DMOGS: Balance = 1_000 * MILLIMOGS;
@@ -236,22 +406,6 @@ impl Default for FeeType {
 
 pub struct Pricing;
 impl Pricing {
-	pub fn config_update_price(index: u8, value: u8) -> Balance {
-		match index {
-			1 => Self::config_max_mogwais(value),
-			_ => 0,
-		}
-	}
-
-	fn config_max_mogwais(value: u8) -> Balance {
-		match value {
-			1 => 5 * DMOGS,
-			2 => 10 * DMOGS,
-			3 => 20 * DMOGS,
-			_ => 0,
-		}
-	}
-
 	pub fn fee_price(fee: FeeType) -> Balance {
 		match fee {
 			FeeType::Default => MILLIMOGS,
@@ -288,7 +442,7 @@ impl Pricing {
 	}
 }
 
-#[derive(Encode, Decode, Clone, PartialEq)]
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum GameConfigType {
 	Activated = 0,
 	MaxMogwaisInAccount = 1,
@@ -302,51 +456,94 @@ impl Default for GameConfigType {
 	}
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq)]
+impl GameConfigType {
+	pub fn rule(&self) -> ConfigRule {
+		match self {
+			GameConfigType::Activated => ConfigRule { min: 0, max: 0, upgrade_prices: &[] },
+			GameConfigType::MaxMogwaisInAccount =>
+				ConfigRule { min: 0, max: 3, upgrade_prices: &[5 * DMOGS, 10 * DMOGS, 20 * DMOGS] },
+			GameConfigType::MaxStashSize =>
+				ConfigRule { min: 0, max: 3, upgrade_prices: &[2 * DMOGS, 5 * DMOGS, 10 * DMOGS] },
+			GameConfigType::AccountNaming =>
+				ConfigRule { min: 0, max: 1, upgrade_prices: &[DMOGS] },
+		}
+	}
+
+	/// The effective value of the parameter at a given level.
+	pub fn value(&self, level: u8) -> u32 {
+		match self {
+			GameConfigType::Activated | GameConfigType::AccountNaming => level as u32,
+			// 6, 12, 18 and 24 mogwais
+			GameConfigType::MaxMogwaisInAccount => 6 * (level as u32 + 1),
+			// 2, 4, 8 and 16 mogwais on sale at once
+			GameConfigType::MaxStashSize => 2 << level,
+		}
+	}
+}
+
+/// The bounds of an account configuration parameter and the price of each upgrade step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigRule {
+	pub min: u8,
+	pub max: u8,
+	/// The price of reaching each level above `min`.
+	pub upgrade_prices: &'static [Balance],
+}
+
+impl ConfigRule {
+	/// The price of upgrading to a level, if that level is within bounds.
+	pub fn upgrade_price(&self, level: u8) -> Option<Balance> {
+		if level <= self.min || level > self.max {
+			return None
+		}
+		self.upgrade_prices.get(usize::from(level - self.min - 1)).copied()
+	}
+}
+
+/// The configuration of an account, each parameter holding its current upgrade level.
+#[derive(Encode, Decode, Debug, Default, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
 pub struct GameConfig {
-	pub parameters: [u8; GameConfig::PARAM_COUNT],
+	pub activated: u8,
+	pub max_mogwais: u8,
+	pub max_stash_size: u8,
+	pub account_naming: u8,
 }
 
 impl GameConfig {
-	pub const PARAM_COUNT: usize = 10;
-
-	pub fn new() -> Self {
-		GameConfig { parameters: [0; GameConfig::PARAM_COUNT] }
-	}
-
-	pub fn config_value(index: u8, value: u8) -> u32 {
-		let result: u32;
-		match index {
-			// MaxMogwaisInAccount
-			1 => match value {
-				0 => result = 6,
-				1 => result = 12,
-				2 => result = 18,
-				3 => result = 24,
-				_ => result = 0,
-			},
-			_ => result = 0,
+	pub fn level(&self, parameter: GameConfigType) -> u8 {
+		match parameter {
+			GameConfigType::Activated => self.activated,
+			GameConfigType::MaxMogwaisInAccount => self.max_mogwais,
+			GameConfigType::MaxStashSize => self.max_stash_size,
+			GameConfigType::AccountNaming => self.account_naming,
 		}
-		result
-	}
-
-	pub fn verify_update(index: u8, value: u8, update_value_opt: Option<u8>) -> u8 {
-		let mut result: u8;
-		match index {
-			// MaxMogwaisInAccount
-			1 => match value {
-				0 => result = 1,
-				1 => result = 2,
-				2 => result = 3,
-				_ => result = 0,
-			},
-			_ => result = 0,
+	}
+
+	pub fn value(&self, parameter: GameConfigType) -> u32 {
+		parameter.value(self.level(parameter))
+	}
+
+	/// Upgrade a parameter by one level, returning the price of the upgrade.
+	///
+	/// When `expected_level` is given, the upgrade is only applied if it reaches that level.
+	pub fn upgrade(
+		&mut self,
+		parameter: GameConfigType,
+		expected_level: Option<u8>,
+	) -> Option<Balance> {
+		let level = self.level(parameter).checked_add(1)?;
+		if matches!(expected_level, Some(expected) if expected != level) {
+			return None
 		}
-		// don't allow bad requests
-		if update_value_opt.is_some() && result != update_value_opt.unwrap() {
-			result = 0;
+
+		let price = parameter.rule().upgrade_price(level)?;
+		match parameter {
+			GameConfigType::Activated => self.activated = level,
+			GameConfigType::MaxMogwaisInAccount => self.max_mogwais = level,
+			GameConfigType::MaxStashSize => self.max_stash_size = level,
+			GameConfigType::AccountNaming => self.account_naming = level,
 		}
-		result
+		Some(price)
 	}
 }
 
@@ -57,6 +57,24 @@ pub trait WeightInfo {
 	fn buy_mogwai() -> Weight;
 	fn morph_mogwai() -> Weight;
 	fn breed_mogwai() -> Weight;
+	fn challenge_mogwai() -> Weight;
+	fn cancel_challenge() -> Weight;
+	fn accept_challenge() -> Weight;
+	fn enqueue_mogwai() -> Weight;
+	fn dequeue_mogwai() -> Weight;
+	fn match_mogwais(n: u32, b: u32, ) -> Weight;
+	fn set_achievement_reward() -> Weight;
+	fn claim_achievement() -> Weight;
+	fn set_account_name() -> Weight;
+	fn transfer_mogwai() -> Weight;
+	fn approve_mogwai() -> Weight;
+	fn set_transfer_fee() -> Weight;
+	fn list_stud() -> Weight;
+	fn unlist_stud() -> Weight;
+	fn resolve_hatches(n: u32, ) -> Weight;
+	fn set_collection_id() -> Weight;
+	fn lock_mogwai() -> Weight;
+	fn unlock_mogwai() -> Weight;
 }
 
 /// Weights for pallet_battle_mogs using the Substrate node and recommended hardware.
@@ -75,10 +93,12 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: BattleMogs Mogwais (r:1 w:0)
-	// Storage: BattleMogs MogwaiPrices (r:0 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:25 w:1)
+	// Storage: BattleMogs Owners (r:1 w:0)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	fn set_price() -> Weight {
-		Weight::from_parts(52_145_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(148_309_000, 0)
+			.saturating_add(T::DbWeight::get().reads(28_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: BattleMogs Mogwais (r:1 w:0)
@@ -95,10 +115,11 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: BattleMogs Mogwais (r:1 w:1)
 	// Storage: BattleMogs Owners (r:1 w:1)
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs MogwaiPedigrees (r:0 w:1)
 	fn create_mogwai() -> Weight {
-		Weight::from_parts(116_283_000, 0)
+		Weight::from_parts(118_921_000, 0)
 			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	// Storage: BattleMogs Organizer (r:1 w:0)
 	// Storage: BattleMogs Mogwais (r:1 w:1)
@@ -122,12 +143,12 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	// Storage: BattleMogs Mogwais (r:1 w:1)
-	// Storage: System BlockHash (r:1 w:0)
-	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs HatchRequests (r:1 w:1)
+	// Storage: BattleMogs HatchQueue (r:16 w:1)
 	fn hatch_mogwai() -> Weight {
-		Weight::from_parts(69_707_000, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		Weight::from_parts(58_913_000, 0)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: BattleMogs Mogwais (r:1 w:1)
@@ -144,13 +165,20 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: BattleMogs Mogwais (r:2 w:2)
 	// Storage: BattleMogs MogwaiPrices (r:2 w:0)
 	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:1)
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
 	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs MogwaiExperience (r:1 w:2)
 	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	// Storage: BattleMogs Challenges (r:0 w:1)
+	// Storage: BattleMogs MogwaiApprovals (r:0 w:1)
+	// Storage: BattleMogs StudListings (r:0 w:1)
+	// Storage: BattleMogs HatchRequests (r:0 w:1)
+	// Storage: BattleMogs BreedingCooldowns (r:0 w:1)
 	fn sacrifice_into() -> Weight {
 		Weight::from_parts(126_634_000, 0)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	// Storage: BattleMogs MogwaiPrices (r:1 w:1)
 	// Storage: BattleMogs Mogwais (r:1 w:1)
@@ -171,17 +199,233 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	// Storage: BattleMogs Mogwais (r:3 w:2)
+	// Storage: BattleMogs Mogwais (r:3 w:3)
 	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
 	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
 	// Storage: BattleMogs Nonce (r:1 w:1)
 	// Storage: BattleMogs Owners (r:1 w:1)
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs MogwaiPedigrees (r:2 w:1)
+	// Storage: BattleMogs BreedingCooldowns (r:2 w:2)
+	// Storage: BattleMogs StudListings (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs MogwaiExperience (r:2 w:2)
 	fn breed_mogwai() -> Weight {
-		Weight::from_parts(276_005_000, 0)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		Weight::from_parts(341_087_000, 0)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	// Storage: BattleMogs Challenges (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:2 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:2 w:0)
+	fn challenge_mogwai() -> Weight {
+		Weight::from_parts(58_412_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Challenges (r:1 w:1)
+	fn cancel_challenge() -> Weight {
+		Weight::from_parts(31_907_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Challenges (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:2 w:2)
+	// Storage: BattleMogs MogwaiPrices (r:2 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs BattleRecords (r:2 w:2)
+	// Storage: BattleMogs MogwaiRatings (r:2 w:2)
+	// Storage: BattleMogs AccountRatings (r:2 w:2)
+	// Storage: BattleMogs MogwaiExperience (r:2 w:2)
+	fn accept_challenge() -> Weight {
+		Weight::from_parts(142_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:1)
+	// Storage: BattleMogs MatchQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn enqueue_mogwai() -> Weight {
+		Weight::from_parts(64_318_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:1)
+	// Storage: BattleMogs MatchQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn dequeue_mogwai() -> Weight {
+		Weight::from_parts(55_760_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: BattleMogs PendingBrackets (r:4 w:4)
+	// Storage: BattleMogs MatchQueue (r:4 w:4)
+	// Storage: BattleMogs MogwaiRatings (r:256 w:16)
+	// Storage: BattleMogs QueuedMogwais (r:0 w:2)
+	// Storage: BattleMogs Mogwais (r:2 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs BattleRecords (r:2 w:2)
+	// Storage: BattleMogs AccountRatings (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	/// The range of component `n` is `[0, 8]`.
+	/// The range of component `b` is `[1, 4]`.
+	fn match_mogwais(n: u32, b: u32, ) -> Weight {
+		Weight::from_parts(12_486_000, 0)
+			// Standard Error: 61_204
+			.saturating_add(Weight::from_parts(168_935_000, 0).saturating_mul(n.into()))
+			// Standard Error: 124_517
+			.saturating_add(Weight::from_parts(214_380_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((66_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((12_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: BattleMogs AchievementRewards (r:0 w:1)
+	fn set_achievement_reward() -> Weight {
+		Weight::from_parts(38_107_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs AccountAchievements (r:1 w:0)
+	// Storage: BattleMogs ClaimedAchievements (r:1 w:1)
+	// Storage: BattleMogs AchievementRewards (r:1 w:0)
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountConfig (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:3 w:3)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	fn claim_achievement() -> Weight {
+		Weight::from_parts(430_512_000, 0)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: BattleMogs AccountNames (r:0 w:1)
+	fn set_account_name() -> Weight {
+		Weight::from_parts(31_774_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs MogwaiApprovals (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: BattleMogs TransferFee (r:1 w:0)
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:0)
+	// Storage: BattleMogs Owners (r:2 w:2)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs Challenges (r:0 w:1)
+	fn transfer_mogwai() -> Weight {
+		Weight::from_parts(126_933_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs MogwaiApprovals (r:0 w:1)
+	fn approve_mogwai() -> Weight {
+		Weight::from_parts(41_352_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: BattleMogs TransferFee (r:0 w:1)
+	fn set_transfer_fee() -> Weight {
+		Weight::from_parts(33_468_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs StudListings (r:0 w:1)
+	fn list_stud() -> Weight {
+		Weight::from_parts(42_816_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs StudListings (r:1 w:1)
+	fn unlist_stud() -> Weight {
+		Weight::from_parts(44_209_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs HatchQueue (r:1 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: BattleMogs HatchRequests (r:64 w:64)
+	// Storage: BattleMogs Mogwais (r:64 w:64)
+	// Storage: BattleMogs AccountAchievements (r:64 w:64)
+	/// The range of component `n` is `[0, 64]`.
+	fn resolve_hatches(n: u32, ) -> Weight {
+		Weight::from_parts(6_112_000, 0)
+			// Standard Error: 12_873
+			.saturating_add(Weight::from_parts(38_524_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: BattleMogs CollectionId (r:0 w:1)
+	fn set_collection_id() -> Weight {
+		Weight::from_parts(36_842_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs CollectionId (r:1 w:0)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:0)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs LockedMogwais (r:0 w:1)
+	// Storage: BattleMogs Challenges (r:0 w:1)
+	// Storage: BattleMogs MogwaiApprovals (r:0 w:1)
+	// Storage: BattleMogs StudListings (r:0 w:1)
+	// Storage: BattleMogs HatchRequests (r:0 w:1)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:1 w:1)
+	// Storage: Nft Attribute (r:7 w:7)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: NftTransfer NftStatuses (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn lock_mogwai() -> Weight {
+		Weight::from_parts(312_604_000, 0)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
+	}
+	// Storage: BattleMogs CollectionId (r:1 w:0)
+	// Storage: BattleMogs LockedMogwais (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: NftTransfer NftStatuses (r:1 w:1)
+	// Storage: Nft Attribute (r:7 w:7)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft ItemConfigOf (r:0 w:1)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unlock_mogwai() -> Weight {
+		Weight::from_parts(289_171_000, 0)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
 	}
 }
 
@@ -200,10 +444,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: BattleMogs Mogwais (r:1 w:0)
-	// Storage: BattleMogs MogwaiPrices (r:0 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:25 w:1)
+	// Storage: BattleMogs Owners (r:1 w:0)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	fn set_price() -> Weight {
-		Weight::from_parts(52_145_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		Weight::from_parts(148_309_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(28_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: BattleMogs Mogwais (r:1 w:0)
@@ -220,10 +466,11 @@ impl WeightInfo for () {
 	// Storage: BattleMogs Mogwais (r:1 w:1)
 	// Storage: BattleMogs Owners (r:1 w:1)
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs MogwaiPedigrees (r:0 w:1)
 	fn create_mogwai() -> Weight {
-		Weight::from_parts(116_283_000, 0)
+		Weight::from_parts(118_921_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	// Storage: BattleMogs Organizer (r:1 w:0)
 	// Storage: BattleMogs Mogwais (r:1 w:1)
@@ -247,12 +494,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
-	// Storage: BattleMogs Mogwais (r:1 w:1)
-	// Storage: System BlockHash (r:1 w:0)
-	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs HatchRequests (r:1 w:1)
+	// Storage: BattleMogs HatchQueue (r:16 w:1)
 	fn hatch_mogwai() -> Weight {
-		Weight::from_parts(69_707_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		Weight::from_parts(58_913_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: BattleMogs Mogwais (r:1 w:1)
@@ -269,13 +516,20 @@ impl WeightInfo for () {
 	// Storage: BattleMogs Mogwais (r:2 w:2)
 	// Storage: BattleMogs MogwaiPrices (r:2 w:0)
 	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:1)
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
 	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs MogwaiExperience (r:1 w:2)
 	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	// Storage: BattleMogs Challenges (r:0 w:1)
+	// Storage: BattleMogs MogwaiApprovals (r:0 w:1)
+	// Storage: BattleMogs StudListings (r:0 w:1)
+	// Storage: BattleMogs HatchRequests (r:0 w:1)
+	// Storage: BattleMogs BreedingCooldowns (r:0 w:1)
 	fn sacrifice_into() -> Weight {
 		Weight::from_parts(126_634_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	// Storage: BattleMogs MogwaiPrices (r:1 w:1)
 	// Storage: BattleMogs Mogwais (r:1 w:1)
@@ -296,16 +550,232 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	// Storage: BattleMogs Mogwais (r:3 w:2)
+	// Storage: BattleMogs Mogwais (r:3 w:3)
 	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
 	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
 	// Storage: BattleMogs Nonce (r:1 w:1)
 	// Storage: BattleMogs Owners (r:1 w:1)
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs MogwaiPedigrees (r:2 w:1)
+	// Storage: BattleMogs BreedingCooldowns (r:2 w:2)
+	// Storage: BattleMogs StudListings (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs MogwaiExperience (r:2 w:2)
 	fn breed_mogwai() -> Weight {
-		Weight::from_parts(276_005_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		Weight::from_parts(341_087_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	// Storage: BattleMogs Challenges (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:2 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:2 w:0)
+	fn challenge_mogwai() -> Weight {
+		Weight::from_parts(58_412_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Challenges (r:1 w:1)
+	fn cancel_challenge() -> Weight {
+		Weight::from_parts(31_907_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Challenges (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:2 w:2)
+	// Storage: BattleMogs MogwaiPrices (r:2 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs BattleRecords (r:2 w:2)
+	// Storage: BattleMogs MogwaiRatings (r:2 w:2)
+	// Storage: BattleMogs AccountRatings (r:2 w:2)
+	// Storage: BattleMogs MogwaiExperience (r:2 w:2)
+	fn accept_challenge() -> Weight {
+		Weight::from_parts(142_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:1)
+	// Storage: BattleMogs MatchQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn enqueue_mogwai() -> Weight {
+		Weight::from_parts(64_318_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:1)
+	// Storage: BattleMogs MatchQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn dequeue_mogwai() -> Weight {
+		Weight::from_parts(55_760_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: BattleMogs PendingBrackets (r:4 w:4)
+	// Storage: BattleMogs MatchQueue (r:4 w:4)
+	// Storage: BattleMogs MogwaiRatings (r:256 w:16)
+	// Storage: BattleMogs QueuedMogwais (r:0 w:2)
+	// Storage: BattleMogs Mogwais (r:2 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs BattleRecords (r:2 w:2)
+	// Storage: BattleMogs AccountRatings (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	/// The range of component `n` is `[0, 8]`.
+	/// The range of component `b` is `[1, 4]`.
+	fn match_mogwais(n: u32, b: u32, ) -> Weight {
+		Weight::from_parts(12_486_000, 0)
+			// Standard Error: 61_204
+			.saturating_add(Weight::from_parts(168_935_000, 0).saturating_mul(n.into()))
+			// Standard Error: 124_517
+			.saturating_add(Weight::from_parts(214_380_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((66_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes((12_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: BattleMogs AchievementRewards (r:0 w:1)
+	fn set_achievement_reward() -> Weight {
+		Weight::from_parts(38_107_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs AccountAchievements (r:1 w:0)
+	// Storage: BattleMogs ClaimedAchievements (r:1 w:1)
+	// Storage: BattleMogs AchievementRewards (r:1 w:0)
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountConfig (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:3 w:3)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	fn claim_achievement() -> Weight {
+		Weight::from_parts(430_512_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: BattleMogs AccountNames (r:0 w:1)
+	fn set_account_name() -> Weight {
+		Weight::from_parts(31_774_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs MogwaiApprovals (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: BattleMogs TransferFee (r:1 w:0)
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:0)
+	// Storage: BattleMogs Owners (r:2 w:2)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs Challenges (r:0 w:1)
+	fn transfer_mogwai() -> Weight {
+		Weight::from_parts(126_933_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs MogwaiApprovals (r:0 w:1)
+	fn approve_mogwai() -> Weight {
+		Weight::from_parts(41_352_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: BattleMogs TransferFee (r:0 w:1)
+	fn set_transfer_fee() -> Weight {
+		Weight::from_parts(33_468_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs StudListings (r:0 w:1)
+	fn list_stud() -> Weight {
+		Weight::from_parts(42_816_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs StudListings (r:1 w:1)
+	fn unlist_stud() -> Weight {
+		Weight::from_parts(44_209_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs HatchQueue (r:1 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: BattleMogs HatchRequests (r:64 w:64)
+	// Storage: BattleMogs Mogwais (r:64 w:64)
+	// Storage: BattleMogs AccountAchievements (r:64 w:64)
+	/// The range of component `n` is `[0, 64]`.
+	fn resolve_hatches(n: u32, ) -> Weight {
+		Weight::from_parts(6_112_000, 0)
+			// Standard Error: 12_873
+			.saturating_add(Weight::from_parts(38_524_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: BattleMogs CollectionId (r:0 w:1)
+	fn set_collection_id() -> Weight {
+		Weight::from_parts(36_842_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs CollectionId (r:1 w:0)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs QueuedMogwais (r:1 w:0)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs LockedMogwais (r:0 w:1)
+	// Storage: BattleMogs Challenges (r:0 w:1)
+	// Storage: BattleMogs MogwaiApprovals (r:0 w:1)
+	// Storage: BattleMogs StudListings (r:0 w:1)
+	// Storage: BattleMogs HatchRequests (r:0 w:1)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:1 w:1)
+	// Storage: Nft Attribute (r:7 w:7)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: NftTransfer NftStatuses (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn lock_mogwai() -> Weight {
+		Weight::from_parts(312_604_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
+	}
+	// Storage: BattleMogs CollectionId (r:1 w:0)
+	// Storage: BattleMogs LockedMogwais (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: NftTransfer NftStatuses (r:1 w:1)
+	// Storage: Nft Attribute (r:7 w:7)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft ItemConfigOf (r:0 w:1)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unlock_mogwai() -> Weight {
+		Weight::from_parts(289_171_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
 	}
 }
@@ -76,7 +76,8 @@ use staging_xcm::latest::prelude::BodyId;
 use staging_xcm_executor::XcmExecutor;
 
 use ajuna_primitives::{
-	AccountId, AccountPublic, Balance, BlockNumber, CollectionId, Hash, Header, Nonce, Signature,
+	AccountId, AccountPublic, AssetId, Balance, BlockNumber, CollectionId, Hash, Header, Nonce,
+	Signature,
 };
 use pallet_nfts::{AttributeNamespace, Call as NftsCall};
 
@@ -101,7 +102,10 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_ajuna_awesome_avatars::sponsored_payment::ChargeSponsoredTransactionPayment<
+		Runtime,
+		SponsoredCalls,
+	>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -121,7 +125,7 @@ pub type Executive = frame_executive::Executive<
 	Migrations,
 >;
 
-type Migrations = (pallet_ajuna_awesome_avatars::migration::v6::MigrateToV6<Runtime>,);
+type Migrations = (pallet_ajuna_awesome_avatars::migration::v7::MigrateToV7<Runtime>,);
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
 /// node's balance type.
@@ -680,6 +684,36 @@ impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
 parameter_types! {
 	pub const AwesomeAvatarsPalletId: PalletId = PalletId(*b"aj/aaatr");
+	pub const AwesomeAvatarsArchiveGracePeriod: BlockNumber = 30 * DAYS;
+}
+
+pub struct IdentityJudgement;
+impl pallet_ajuna_awesome_avatars::types::IdentityVerifier<AccountId> for IdentityJudgement {
+	fn is_verified(who: &AccountId) -> bool {
+		Identity::identity(who).map_or(false, |registration| {
+			registration.judgements.iter().any(|(_, judgement)| {
+				matches!(
+					judgement,
+					pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+				)
+			})
+		})
+	}
+}
+
+/// Gameplay calls whose fees can be paid by the sponsor of the current season.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SponsoredCalls;
+impl Contains<RuntimeCall> for SponsoredCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::AwesomeAvatars(
+				pallet_ajuna_awesome_avatars::Call::mint { .. } |
+					pallet_ajuna_awesome_avatars::Call::forge { .. }
+			)
+		)
+	}
 }
 
 impl pallet_ajuna_awesome_avatars::Config for Runtime {
@@ -690,6 +724,11 @@ impl pallet_ajuna_awesome_avatars::Config for Runtime {
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
 	type NftHandler = NftTransfer;
+	type IdentityVerifier = IdentityJudgement;
+	type AssetId = AssetId;
+	type Assets = ();
+	type SteppedMigration = ();
+	type ArchiveGracePeriod = AwesomeAvatarsArchiveGracePeriod;
 	type WeightInfo = pallet_ajuna_awesome_avatars::weights::AjunaWeight<Runtime>;
 }
 
@@ -985,6 +1024,14 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ajuna_awesome_avatars_runtime_api::AwesomeAvatarsApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn trophies_of(
+			account: AccountId,
+		) -> Vec<(Hash, pallet_ajuna_awesome_avatars::types::Trophy<BlockNumber>)> {
+			AwesomeAvatars::trophies_of(&account)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
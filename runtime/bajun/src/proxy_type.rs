@@ -41,6 +41,14 @@ pub enum ProxyType {
 	Staking,
 	IdentityJudgement,
 	CancelProxy,
+	/// Allows playing Awesome Avatars and listing avatars for sale, without access to balances.
+	/// Minting fees are still paid by the proxied account, but buying avatars is not allowed.
+	Gaming,
+	/// Allows playing Awesome Avatars without trading avatars or access to balances.
+	GamingNoTrade,
+	/// Allows playing Awesome Avatars including buying avatars, which spends the proxied
+	/// account's free balance.
+	GamingTrade,
 }
 
 impl Default for ProxyType {
@@ -88,6 +96,30 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::CancelProxy => {
 				matches!(c, RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }))
 			},
+			ProxyType::Gaming => matches!(
+				c,
+				RuntimeCall::AwesomeAvatars(
+					pallet_ajuna_awesome_avatars::Call::mint { .. } |
+						pallet_ajuna_awesome_avatars::Call::forge { .. } |
+						pallet_ajuna_awesome_avatars::Call::upgrade_storage { .. } |
+						pallet_ajuna_awesome_avatars::Call::set_price { .. } |
+						pallet_ajuna_awesome_avatars::Call::remove_price { .. }
+				) | RuntimeCall::Utility(..)
+			),
+			ProxyType::GamingNoTrade => matches!(
+				c,
+				RuntimeCall::AwesomeAvatars(
+					pallet_ajuna_awesome_avatars::Call::mint { .. } |
+						pallet_ajuna_awesome_avatars::Call::forge { .. } |
+						pallet_ajuna_awesome_avatars::Call::upgrade_storage { .. }
+				) | RuntimeCall::Utility(..)
+			),
+			ProxyType::GamingTrade =>
+				ProxyType::Gaming.filter(c) ||
+					matches!(
+						c,
+						RuntimeCall::AwesomeAvatars(pallet_ajuna_awesome_avatars::Call::buy { .. })
+					),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -95,8 +127,95 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			// Non-transfer proxies can't play Awesome Avatars.
+			(
+				ProxyType::NonTransfer,
+				ProxyType::Gaming | ProxyType::GamingNoTrade | ProxyType::GamingTrade,
+			) => false,
 			(ProxyType::NonTransfer, _) => true,
+			(ProxyType::GamingTrade, ProxyType::Gaming | ProxyType::GamingNoTrade) => true,
+			(ProxyType::Gaming, ProxyType::GamingNoTrade) => true,
 			_ => false,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{AccountId, Runtime};
+	use pallet_ajuna_awesome_avatars::{
+		types::{MintOption, MintPackSize, MintPayment, PackType},
+		Call as AvatarsCall,
+	};
+
+	fn gaming_calls() -> (Vec<RuntimeCall>, Vec<RuntimeCall>, RuntimeCall) {
+		let avatar_id = Default::default();
+		let playing = vec![
+			RuntimeCall::AwesomeAvatars(AvatarsCall::mint {
+				mint_option: MintOption {
+					payment: MintPayment::Normal,
+					pack_type: PackType::Material,
+					pack_size: MintPackSize::One,
+				},
+			}),
+			RuntimeCall::AwesomeAvatars(AvatarsCall::forge {
+				leader: avatar_id,
+				sacrifices: vec![avatar_id],
+			}),
+			RuntimeCall::AwesomeAvatars(AvatarsCall::upgrade_storage {
+				beneficiary: None,
+				in_season: None,
+			}),
+		];
+		let listing = vec![
+			RuntimeCall::AwesomeAvatars(AvatarsCall::set_price { avatar_id, price: 1 }),
+			RuntimeCall::AwesomeAvatars(AvatarsCall::remove_price { avatar_id }),
+		];
+		let buying = RuntimeCall::AwesomeAvatars(AvatarsCall::buy { avatar_id });
+		(playing, listing, buying)
+	}
+
+	fn balance_transfer() -> RuntimeCall {
+		RuntimeCall::Balances(pallet_balances::Call::<Runtime>::transfer_keep_alive {
+			dest: AccountId::new([1; 32]).into(),
+			value: 1,
+		})
+	}
+
+	#[test]
+	fn gaming_proxies_filter_calls() {
+		let (playing, listing, buying) = gaming_calls();
+
+		for call in &playing {
+			assert!(ProxyType::GamingNoTrade.filter(call));
+			assert!(ProxyType::Gaming.filter(call));
+			assert!(ProxyType::GamingTrade.filter(call));
+			assert!(!ProxyType::NonTransfer.filter(call));
+		}
+		for call in &listing {
+			assert!(!ProxyType::GamingNoTrade.filter(call));
+			assert!(ProxyType::Gaming.filter(call));
+			assert!(ProxyType::GamingTrade.filter(call));
+		}
+
+		assert!(!ProxyType::GamingNoTrade.filter(&buying));
+		assert!(!ProxyType::Gaming.filter(&buying));
+		assert!(ProxyType::GamingTrade.filter(&buying));
+
+		let transfer = balance_transfer();
+		for proxy_type in [ProxyType::GamingNoTrade, ProxyType::Gaming, ProxyType::GamingTrade] {
+			assert!(!proxy_type.filter(&transfer));
+		}
+	}
+
+	#[test]
+	fn gaming_proxies_are_ordered_by_superset() {
+		assert!(ProxyType::GamingTrade.is_superset(&ProxyType::Gaming));
+		assert!(ProxyType::GamingTrade.is_superset(&ProxyType::GamingNoTrade));
+		assert!(ProxyType::Gaming.is_superset(&ProxyType::GamingNoTrade));
+		assert!(!ProxyType::Gaming.is_superset(&ProxyType::GamingTrade));
+		assert!(!ProxyType::GamingNoTrade.is_superset(&ProxyType::Gaming));
+		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::GamingTrade));
+	}
+}
@@ -61,6 +61,7 @@ use sp_version::RuntimeVersion;
 mod consts;
 mod gov;
 mod impls;
+mod migrations;
 mod types;
 
 use ajuna_primitives::{
@@ -464,6 +465,21 @@ impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
 parameter_types! {
 	pub const AwesomeAvatarsPalletId: PalletId = PalletId(*b"aj/aaatr");
+	pub const AwesomeAvatarsArchiveGracePeriod: BlockNumber = 30 * DAYS;
+}
+
+pub struct IdentityJudgement;
+impl pallet_ajuna_awesome_avatars::types::IdentityVerifier<AccountId> for IdentityJudgement {
+	fn is_verified(who: &AccountId) -> bool {
+		Identity::identity(who).map_or(false, |registration| {
+			registration.judgements.iter().any(|(_, judgement)| {
+				matches!(
+					judgement,
+					pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+				)
+			})
+		})
+	}
 }
 
 impl pallet_ajuna_awesome_avatars::Config for Runtime {
@@ -472,8 +488,13 @@ impl pallet_ajuna_awesome_avatars::Config for Runtime {
 	type Currency = Balances;
 	type Randomness = Randomness;
 	type KeyLimit = KeyLimit;
-	type ValueLimit = ValueLimit;
+	type ValueLimit = NftTransferValueLimit;
 	type NftHandler = NftTransfer;
+	type IdentityVerifier = IdentityJudgement;
+	type AssetId = AssetId;
+	type Assets = pallet_ajuna_awesome_avatars::types::FungiblesPayment<Assets>;
+	type SteppedMigration = ();
+	type ArchiveGracePeriod = AwesomeAvatarsArchiveGracePeriod;
 	type WeightInfo = ();
 }
 
@@ -545,6 +566,9 @@ impl<const N: u32> Get<u32> for ParameterGet<N> {
 
 pub type KeyLimit = ParameterGet<32>;
 pub type ValueLimit = ParameterGet<64>;
+// Attribute values of the items stored as NFTs through `NftTransfer`, which every pallet using it
+// shares. Large enough to hold a whole mogwai, encoded as a single attribute of its NFT.
+pub type NftTransferValueLimit = ParameterGet<256>;
 
 impl pallet_nfts::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -561,7 +585,7 @@ impl pallet_nfts::Config for Runtime {
 	type DepositPerByte = DepositPerByte;
 	type StringLimit = StringLimit;
 	type KeyLimit = KeyLimit;
-	type ValueLimit = ValueLimit;
+	type ValueLimit = NftTransferValueLimit;
 	type ApprovalsLimit = ApprovalsLimit;
 	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
 	type MaxTips = MaxTips;
@@ -586,7 +610,7 @@ impl pallet_ajuna_nft_transfer::Config for Runtime {
 	type ItemId = Hash;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type KeyLimit = KeyLimit;
-	type ValueLimit = ValueLimit;
+	type ValueLimit = NftTransferValueLimit;
 	type NftHelper = Nft;
 }
 
@@ -617,10 +641,19 @@ impl pallet_ajuna_nft_staking::Config for Runtime {
 	type WeightInfo = ();
 }
 
+// The collection mogwais are locked into is created and registered by the
+// `CreateMogwaiCollection` migration. The organizer can still replace it with
+// `BattleMogs::set_collection_id`.
 impl pallet_ajuna_battle_mogs::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type Randomness = Randomness;
+	type MaxMatchesPerBlock = frame_support::traits::ConstU32<8>;
+	type BreedingCooldown = frame_support::traits::ConstU32<{ 4 * HOURS }>;
+	type HatchDelay = frame_support::traits::ConstU32<2>;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = NftTransferValueLimit;
+	type NftHandler = NftTransfer;
 	type WeightInfo = ();
 }
 
@@ -692,7 +725,11 @@ pub type Executive = frame_executive::Executive<
 	Migrations,
 >;
 
-type Migrations = (pallet_ajuna_awesome_avatars::migration::v6::MigrateToV6<Runtime>,);
+type Migrations = (
+	pallet_ajuna_awesome_avatars::migration::v7::MigrateToV7<Runtime>,
+	pallet_ajuna_battle_mogs::migration::v2::MigrateToV2<Runtime>,
+	migrations::CreateMogwaiCollection,
+);
 
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
@@ -869,6 +906,33 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ajuna_awesome_avatars_runtime_api::AwesomeAvatarsApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn trophies_of(
+			account: AccountId,
+		) -> Vec<(Hash, pallet_ajuna_awesome_avatars::types::Trophy<BlockNumber>)> {
+			AwesomeAvatars::trophies_of(&account)
+		}
+	}
+
+	impl pallet_ajuna_battle_mogs_runtime_api::BattleMogsApi<Block, AccountId, Hash> for Runtime {
+		fn account_rating(account: AccountId) -> pallet_ajuna_battle_mogs::Rating {
+			BattleMogs::account_rating(account)
+		}
+
+		fn mogwai_rating(
+			mogwai_id: Hash,
+		) -> (pallet_ajuna_battle_mogs::Rating, pallet_ajuna_battle_mogs::BattleRecord) {
+			(BattleMogs::mogwai_rating(mogwai_id), BattleMogs::battle_records(mogwai_id))
+		}
+
+		fn family_tree(
+			mogwai_id: Hash,
+			depth: u8,
+		) -> Vec<pallet_ajuna_battle_mogs::FamilyMember<Hash>> {
+			BattleMogs::family_tree(mogwai_id, depth)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -0,0 +1,52 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{Nft, NftTransferPalletId, Runtime, RuntimeOrigin};
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::{traits::AccountIdConversion, MultiAddress};
+
+/// Create the NFT collection that mogwais are locked into and register it in `BattleMogs`. The
+/// collection is owned by the `NftTransfer` pallet account, which stores the locked mogwais.
+pub struct CreateMogwaiCollection;
+impl OnRuntimeUpgrade for CreateMogwaiCollection {
+	fn on_runtime_upgrade() -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		if pallet_ajuna_battle_mogs::CollectionId::<Runtime>::exists() {
+			log::info!("Mogwai collection already registered, skipping its creation.");
+			return db_weight.reads(1)
+		}
+
+		let owner = NftTransferPalletId::get().into_account_truncating();
+		let collection_id = pallet_nfts::NextCollectionId::<Runtime>::get().unwrap_or_default();
+		let config = pallet_nfts::CollectionConfig {
+			settings: pallet_nfts::CollectionSettings::all_enabled(),
+			max_supply: None,
+			mint_settings: pallet_nfts::MintSettings::default(),
+		};
+		match Nft::force_create(RuntimeOrigin::root(), MultiAddress::Id(owner), config) {
+			Ok(_) => {
+				pallet_ajuna_battle_mogs::CollectionId::<Runtime>::put(collection_id);
+				log::info!("Created and registered mogwai collection {}.", collection_id);
+			},
+			Err(error) => log::error!("Failed to create the mogwai collection: {:?}", error),
+		}
+
+		db_weight.reads_writes(2, 6)
+	}
+}